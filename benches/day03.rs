@@ -0,0 +1,64 @@
+use aoc2025::day03::Day03;
+use aoc2025::solver::{Answer, solve_part2};
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+
+/// Generates `banks` pseudo-random battery banks of 100 digits each.
+fn generate_banks(banks: usize) -> String {
+    let mut state: u64 = 0x9e37_79b9_7f4a_7c15;
+    let mut lines: Vec<String> = Vec::with_capacity(banks);
+    for _ in 0..banks {
+        let line: String = (0..100)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                char::from(b'1' + (state % 9) as u8)
+            })
+            .collect();
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// The previous string-building implementation of part 2, kept as a baseline.
+fn string_building_part2(input: &str) -> Answer {
+    let mut result: i64 = 0;
+    for bank in input.split("\n") {
+        let mut joltage: String = "".to_string();
+        let mut start_index: usize = 0;
+        for i in 1..=12 {
+            let end_index: usize = bank.len() - 12 + i;
+            let slice: &str = &bank[start_index..end_index];
+            let mut index = 0;
+            let mut value = 0;
+            for j in 0..slice.len() {
+                let digit_value: i32 = slice[j..(j + 1)].parse().unwrap();
+                if value < digit_value {
+                    value = digit_value;
+                    index = j;
+                }
+            }
+            joltage = joltage.to_owned() + &slice[index..=index];
+            start_index = start_index + index + 1;
+        }
+        result += joltage.parse::<i64>().unwrap();
+    }
+    result.into()
+}
+
+fn bench_part2(c: &mut Criterion) {
+    let input: String = generate_banks(1000);
+    assert_eq!(string_building_part2(&input), solve_part2::<Day03>(&input));
+
+    let mut group = c.benchmark_group("day03_part2");
+    group.bench_function("string_building", |b| {
+        b.iter(|| string_building_part2(black_box(&input)))
+    });
+    group.bench_function("numeric", |b| {
+        b.iter(|| solve_part2::<Day03>(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_part2);
+criterion_main!(benches);
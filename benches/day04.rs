@@ -0,0 +1,125 @@
+use aoc2025::day04::Day04;
+use aoc2025::solver::{Answer, solve_part1, solve_part2};
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+
+/// Generates a pseudo-random square grid of `size` x `size` cells where
+/// roughly 60% of the cells contain a roll.
+fn generate_grid(size: usize) -> String {
+    let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+    let mut lines: Vec<String> = Vec::with_capacity(size);
+    for _ in 0..size {
+        let line: String = (0..size)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                if state % 10 < 6 { '@' } else { '.' }
+            })
+            .collect();
+        lines.push(line);
+    }
+    lines.join("\n")
+}
+
+/// Parses the grid into the padded `Vec<Vec<bool>>` used by the per-cell
+/// baselines, so that every inner cell has eight neighbours.
+fn padded_grid(input: &str) -> Vec<Vec<bool>> {
+    let mut grid: Vec<Vec<bool>> = input
+        .lines()
+        .map(|line| line.chars().map(|c| c == '@').collect())
+        .collect();
+    let width = grid[0].len();
+    grid.insert(0, vec![false; width]);
+    grid.push(vec![false; width]);
+    for row in grid.iter_mut() {
+        row.insert(0, false);
+        row.push(false);
+    }
+    grid
+}
+
+/// The previous per-cell implementation of part 1, kept as a baseline.
+fn per_cell_part1(input: &str) -> Answer {
+    let grid: Vec<Vec<bool>> = padded_grid(input);
+
+    let mut result: i32 = 0;
+    for h in 1..(grid.len() - 1) {
+        for w in 1..(grid[0].len() - 1) {
+            if grid[h][w] && count_rolls_around_position(&grid, h, w) < 4 {
+                result += 1;
+            }
+        }
+    }
+    result.into()
+}
+
+/// The previous per-cell implementation of part 2, kept as a baseline. It
+/// rescans the whole grid until a pass removes nothing.
+fn per_cell_part2(input: &str) -> Answer {
+    let mut grid: Vec<Vec<bool>> = padded_grid(input);
+
+    let mut result: i32 = 0;
+    loop {
+        let mut removed = false;
+        for h in 1..(grid.len() - 1) {
+            for w in 1..(grid[0].len() - 1) {
+                if grid[h][w] && count_rolls_around_position(&grid, h, w) < 4 {
+                    result += 1;
+                    grid[h][w] = false;
+                    removed = true;
+                }
+            }
+        }
+        if !removed {
+            break;
+        }
+    }
+    result.into()
+}
+
+/// The previous eight-way `if` chain used by the per-cell baseline.
+fn count_rolls_around_position(grid: &[Vec<bool>], h: usize, w: usize) -> i32 {
+    let mut count: i32 = 0;
+    for (dh, dw) in [
+        (0, 0),
+        (0, 1),
+        (0, 2),
+        (1, 0),
+        (1, 2),
+        (2, 0),
+        (2, 1),
+        (2, 2),
+    ] {
+        if grid[h + dh - 1][w + dw - 1] {
+            count += 1;
+        }
+    }
+    count
+}
+
+fn bench_part1(c: &mut Criterion) {
+    let input: String = generate_grid(1000);
+    assert_eq!(per_cell_part1(&input), solve_part1::<Day04>(&input));
+
+    let mut group = c.benchmark_group("day04_part1");
+    group.bench_function("per_cell", |b| b.iter(|| per_cell_part1(black_box(&input))));
+    group.bench_function("bitgrid", |b| {
+        b.iter(|| solve_part1::<Day04>(black_box(&input)))
+    });
+    group.finish();
+}
+
+fn bench_part2(c: &mut Criterion) {
+    let input: String = generate_grid(1000);
+    assert_eq!(per_cell_part2(&input), solve_part2::<Day04>(&input));
+
+    let mut group = c.benchmark_group("day04_part2");
+    group.bench_function("per_cell", |b| b.iter(|| per_cell_part2(black_box(&input))));
+    group.bench_function("bitgrid", |b| {
+        b.iter(|| solve_part2::<Day04>(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_part1, bench_part2);
+criterion_main!(benches);
@@ -0,0 +1,95 @@
+use aoc2025::day06::Day06;
+use aoc2025::solver::{Answer, solve_part2};
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+
+/// Generates a worksheet with `problems` columns of four four-digit numbers
+/// each, followed by the operator row.
+fn generate_worksheet(problems: usize) -> String {
+    let mut state: u64 = 0xd1b5_4a32_d192_ed03;
+    let mut next = || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    let mut rows: Vec<String> = vec![String::new(); 5];
+    for problem in 0..problems {
+        for row in rows.iter_mut().take(4) {
+            row.push_str(&(1_000 + next() % 9_000).to_string());
+        }
+        rows[4].push_str(if next() % 2 == 0 { "*   " } else { "+   " });
+        if problem + 1 < problems {
+            for row in rows.iter_mut() {
+                row.push(' ');
+            }
+        }
+    }
+    rows.join("\n")
+}
+
+/// The previous string-building `perform_calculation` of part 2, kept as a baseline.
+fn string_building_calculation(column: &[String]) -> i64 {
+    let multiply: bool = column.last().unwrap().trim() == "*";
+
+    let mut numbers: Vec<String> = Vec::new();
+    for _ in 0..column.first().unwrap().len() {
+        numbers.push("".to_string());
+    }
+    for line in column[0..(column.len() - 1)].iter() {
+        for i in 0..line.len() {
+            numbers[i] = numbers[i].to_owned() + &line[i..=i];
+        }
+    }
+
+    let mut result: i64 = numbers[0].trim().parse().unwrap();
+    for number in numbers[1..].iter() {
+        let parsed: i64 = number.trim().parse().unwrap();
+        if multiply {
+            result *= parsed;
+        } else {
+            result += parsed;
+        }
+    }
+    result
+}
+
+/// Runs part 2 with the string-building calculation, kept as a baseline.
+fn string_building_part2(input: &str) -> Answer {
+    let lines: Vec<&str> = input.lines().collect();
+    let operators: &str = lines.last().unwrap();
+    let starts: Vec<usize> = operators
+        .bytes()
+        .enumerate()
+        .filter(|(_, byte)| *byte != b' ')
+        .map(|(i, _)| i)
+        .collect();
+
+    let mut result: i64 = 0;
+    for (i, start) in starts.iter().enumerate() {
+        let end: usize = starts.get(i + 1).map_or(operators.len(), |next| next - 1);
+        let column: Vec<String> = lines
+            .iter()
+            .map(|line| line[*start..end].to_string())
+            .collect();
+        result += string_building_calculation(&column);
+    }
+    result.into()
+}
+
+fn bench_part2(c: &mut Criterion) {
+    let input: String = generate_worksheet(1000);
+    assert_eq!(string_building_part2(&input), solve_part2::<Day06>(&input));
+
+    let mut group = c.benchmark_group("day06_part2");
+    group.bench_function("string_building", |b| {
+        b.iter(|| string_building_part2(black_box(&input)))
+    });
+    group.bench_function("numeric", |b| {
+        b.iter(|| solve_part2::<Day06>(black_box(&input)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_part2);
+criterion_main!(benches);
@@ -0,0 +1,95 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// The directories scanned for inputs to embed, relative to the crate root,
+/// in the order of preference, unless `AOC_EMBED_DIR` names another one.
+const INPUT_DIRS: [&str; 2] = ["inputs/2025", "inputs"];
+
+/// Generates `$OUT_DIR/embedded_inputs.rs`, the table of inputs compiled into
+/// the crate with the `embed-inputs` feature (see `src/embedded.rs`), and
+/// passes the build settings on to `src/environment.rs`.
+///
+/// The table holds one `(file name, include_str!(path))` entry per
+/// `dayXX.txt` and `dayXX_partN.txt` file found; without the feature it is empty.
+fn main() {
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-env-changed=AOC_EMBED_DIR");
+
+    let rustc = env::var_os("RUSTC").unwrap_or_else(|| "rustc".into());
+    let rustc_version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .unwrap_or_default();
+    println!("cargo:rustc-env=AOC_RUSTC_VERSION={}", rustc_version.trim());
+    for (name, var) in [("AOC_PROFILE", "PROFILE"), ("AOC_OPT_LEVEL", "OPT_LEVEL")] {
+        println!(
+            "cargo:rustc-env={}={}",
+            name,
+            env::var(var).unwrap_or_default()
+        );
+    }
+
+    let mut inputs: Vec<(String, PathBuf)> = Vec::new();
+    if env::var_os("CARGO_FEATURE_EMBED_INPUTS").is_some() {
+        let root = PathBuf::from(env::var_os("CARGO_MANIFEST_DIR").expect("set by cargo"));
+        let dirs: Vec<PathBuf> = match env::var_os("AOC_EMBED_DIR") {
+            Some(dir) => vec![root.join(dir)],
+            None => INPUT_DIRS.iter().map(|dir| root.join(dir)).collect(),
+        };
+        for dir in dirs {
+            println!("cargo:rerun-if-changed={}", dir.display());
+            for (name, path) in input_files(&dir) {
+                if !inputs.iter().any(|(embedded, _)| *embedded == name) {
+                    inputs.push((name, path));
+                }
+            }
+        }
+        inputs.sort();
+    }
+
+    let mut table = String::from("&[\n");
+    for (name, path) in &inputs {
+        table.push_str(&format!("    ({:?}, include_str!({:?})),\n", name, path));
+    }
+    table.push_str("]\n");
+
+    let out = PathBuf::from(env::var_os("OUT_DIR").expect("set by cargo"));
+    fs::write(out.join("embedded_inputs.rs"), table).expect("OUT_DIR is writable");
+}
+
+/// Returns the name and path of every input file directly in `dir`.
+fn input_files(dir: &Path) -> Vec<(String, PathBuf)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(Result::ok)
+        .filter_map(|entry| {
+            let name = entry.file_name().into_string().ok()?;
+            is_input_name(&name).then(|| (name, entry.path()))
+        })
+        .collect()
+}
+
+/// Returns whether `name` looks like `day03.txt` or `day03_part2.txt`.
+fn is_input_name(name: &str) -> bool {
+    let Some(rest) = name
+        .strip_prefix("day")
+        .and_then(|rest| rest.strip_suffix(".txt"))
+    else {
+        return false;
+    };
+    let (day, part) = rest.split_at(rest.len().min(2));
+    let digits = |text: &str, len: usize| {
+        text.len() == len && text.bytes().all(|byte| byte.is_ascii_digit())
+    };
+    digits(day, 2)
+        && (part.is_empty()
+            || part
+                .strip_prefix("_part")
+                .is_some_and(|part| digits(part, 1)))
+}
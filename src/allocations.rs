@@ -0,0 +1,120 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+/// The heap allocations made while solving a puzzle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// The number of allocations, counting every reallocation as a new one.
+    pub allocations: u64,
+    /// The total number of bytes requested by these allocations.
+    pub bytes: u64,
+}
+
+thread_local! {
+    /// The allocations made on this thread so far.
+    ///
+    /// Constant initialization without a destructor keeps the allocator from
+    /// allocating itself when the counter is accessed for the first time.
+    static COUNTS: Cell<AllocStats> = const {
+        Cell::new(AllocStats {
+            allocations: 0,
+            bytes: 0,
+        })
+    };
+}
+
+/// A global allocator forwarding to [`System`] while counting allocations per thread.
+///
+/// Installed as the global allocator only with the `alloc-stats` feature, so
+/// regular runs do not pay for the bookkeeping:
+///
+/// ```text
+/// cargo run --release --features alloc-stats --bin aoc -- run -d 2 -p 1
+/// ```
+pub struct CountingAllocator;
+
+impl CountingAllocator {
+    /// Records one allocation of `size` bytes on the current thread.
+    fn count(size: usize) {
+        // Fails only while the thread is being torn down, where nothing is measured anymore.
+        let _ = COUNTS.try_with(|counts| {
+            let mut stats = counts.get();
+            stats.allocations += 1;
+            stats.bytes += size as u64;
+            counts.set(stats);
+        });
+    }
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        CountingAllocator::count(layout.size());
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        CountingAllocator::count(layout.size());
+        unsafe { System.alloc_zeroed(layout) }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        CountingAllocator::count(new_size);
+        unsafe { System.realloc(ptr, layout, new_size) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+/// Returns whether allocations are counted, i.e. the `alloc-stats` feature is enabled.
+pub fn enabled() -> bool {
+    cfg!(feature = "alloc-stats")
+}
+
+/// Executes `run` and counts the heap allocations it makes on the current thread.
+///
+/// # Returns
+/// The result of `run`, together with its allocations if [`enabled`], or `None` otherwise.
+pub fn measure<R>(run: impl FnOnce() -> R) -> (R, Option<AllocStats>) {
+    let before = COUNTS.with(Cell::get);
+    let result = run();
+    let after = COUNTS.with(Cell::get);
+
+    let stats = enabled().then(|| AllocStats {
+        allocations: after.allocations - before.allocations,
+        bytes: after.bytes - before.bytes,
+    });
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (length, stats) = measure(|| vec![0u8; 1000].len());
+        assert_eq!(length, 1000);
+        match stats {
+            Some(stats) => {
+                assert!(stats.allocations >= 1);
+                assert!(stats.bytes >= 1000);
+            }
+            None => assert!(!enabled()),
+        }
+    }
+
+    #[test]
+    fn test_count() {
+        let before = COUNTS.with(Cell::get);
+        CountingAllocator::count(24);
+        let after = COUNTS.with(Cell::get);
+        assert_eq!(after.allocations, before.allocations + 1);
+        assert_eq!(after.bytes, before.bytes + 24);
+    }
+}
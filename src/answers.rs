@@ -0,0 +1,478 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use crate::config;
+use crate::registry;
+use crate::solver::Answer;
+use crate::utils::{EXAMPLE_PATH, OutputOptions, RunReport, Verbosity, uses_embedded_example};
+
+/// Returns the answers file of the puzzle inputs, if accepted answers were recorded.
+///
+/// The file is `answers.toml` in the first input directory containing one
+/// (see [`Config::input_dirs`](config::Config::input_dirs)), by default
+/// `inputs/2025/answers.toml`.
+pub fn answers_path() -> Option<String> {
+    config::current()
+        .input_dirs()
+        .into_iter()
+        .map(|dir| format!("{}/answers.toml", dir))
+        .find(|path| Path::new(path).exists())
+}
+
+/// Returns the recorded answers that runs on `input_path` are verified against.
+///
+/// Runs on the automatically selected inputs use the accepted answers (see
+/// [`answers_path`]) and runs with `--example` the example answers (see
+/// [`example_answers`]). Other inputs have no recorded answers.
+///
+/// # Returns
+/// The answers, `None` if none apply, or an I/O error if the answers file
+/// cannot be read or is invalid.
+pub fn recorded_answers(input_path: Option<&str>) -> io::Result<Option<ExpectedAnswers>> {
+    match input_path {
+        Some(EXAMPLE_PATH) => example_answers().map(Some),
+        Some(_) => Ok(None),
+        None => answers_path()
+            .map(|path| ExpectedAnswers::load(&path))
+            .transpose(),
+    }
+}
+
+/// Returns the answers file of the bundled example inputs, if example answers were recorded.
+///
+/// The file is `answers.toml` in the first examples directory containing one,
+/// by default `inputs/2025/examples/answers.toml`.
+pub fn example_answers_path() -> Option<String> {
+    config::current()
+        .examples_dirs()
+        .into_iter()
+        .map(|dir| format!("{}/answers.toml", dir))
+        .find(|path| Path::new(path).exists())
+}
+
+/// Returns the expected answers of `--example` runs.
+///
+/// The recorded answers file (see [`example_answers_path`]) is used if it
+/// exists. Days without an example file run on the example embedded in their
+/// day module, so its answers are added for them.
+///
+/// # Returns
+/// The answers, or an I/O error if the answers file cannot be read or is invalid.
+pub fn example_answers() -> io::Result<ExpectedAnswers> {
+    let mut answers = match example_answers_path() {
+        Some(path) => ExpectedAnswers::load(&path)?,
+        None => ExpectedAnswers::default(),
+    };
+    for (day, part, _) in registry::solvers() {
+        if let Some(answer) = registry::example_answer(*day, *part)
+            && answers.get(*day, *part).is_none()
+            && uses_embedded_example(*day, *part)
+        {
+            answers.insert(*day, *part, answer.to_string());
+        }
+    }
+    Ok(answers)
+}
+
+/// Expected answers per day and part, used to verify solver output.
+///
+/// Answers are stored in a TOML file with one table per day:
+///
+/// ```toml
+/// [day01]
+/// part1 = 3
+/// part2 = "6"
+/// ```
+///
+/// Answers may be written as strings or integers.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ExpectedAnswers {
+    answers: BTreeMap<(i32, i32), String>,
+}
+
+impl ExpectedAnswers {
+    /// Parses expected answers from the contents of an answers file.
+    ///
+    /// # Returns
+    /// The parsed answers, or a message describing why the file is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::answers::ExpectedAnswers;
+    ///
+    /// let answers = ExpectedAnswers::parse("[day03]\npart2 = 3121910778619").unwrap();
+    /// assert_eq!(answers.get(3, 2), Some("3121910778619"));
+    /// assert_eq!(answers.get(3, 1), None);
+    /// ```
+    pub fn parse(content: &str) -> Result<ExpectedAnswers, String> {
+        let table: toml::Table = content.parse().map_err(|err| format!("{}", err))?;
+
+        let mut answers = BTreeMap::new();
+        for (day_key, parts) in &table {
+            let day = parse_key(day_key, "day")?;
+            let parts = parts
+                .as_table()
+                .ok_or_else(|| format!("'{}' must be a table of parts", day_key))?;
+            for (part_key, value) in parts {
+                let part = parse_key(part_key, "part")?;
+                let answer = match value {
+                    toml::Value::String(text) => text.clone(),
+                    toml::Value::Integer(number) => number.to_string(),
+                    _ => {
+                        return Err(format!(
+                            "answer for {}.{} must be a string or an integer",
+                            day_key, part_key
+                        ));
+                    }
+                };
+                answers.insert((day, part), answer);
+            }
+        }
+
+        Ok(ExpectedAnswers { answers })
+    }
+
+    /// Reads and parses an answers file.
+    ///
+    /// # Returns
+    /// The parsed answers, or an I/O error if the file cannot be read or is invalid.
+    pub fn load(path: &str) -> io::Result<ExpectedAnswers> {
+        let content = fs::read_to_string(path)?;
+        ExpectedAnswers::parse(&content).map_err(|message| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid answers file '{}': {}", path, message),
+            )
+        })
+    }
+
+    /// Returns the expected answer for a day and part, if recorded.
+    pub fn get(&self, day: i32, part: i32) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Records the expected answer for a day and part.
+    pub fn insert(&mut self, day: i32, part: i32, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+}
+
+/// Parses keys like `day01` or `part2` into their number.
+fn parse_key(key: &str, prefix: &str) -> Result<i32, String> {
+    key.strip_prefix(prefix)
+        .and_then(|number| number.parse().ok())
+        .ok_or_else(|| format!("expected a key like '{}1', found '{}'", prefix, key))
+}
+
+/// Compares the answer of a run against the expected answer and prints the outcome.
+///
+/// Numbers are compared by value and other answers without surrounding
+/// whitespace, see [`Answer`]. A `PASS` or `FAIL`
+/// line is printed to stdout; in quiet mode only failures are reported, on stderr.
+/// A wrong answer is followed by a character-level diff against the expected
+/// one and a hint if they differ only in whitespace or sign, see [`mismatch_details`].
+///
+/// # Returns
+/// `true` if the answer matches the expected one.
+pub fn verify(report: &RunReport, expected: &str, options: &OutputOptions) -> bool {
+    let passed = report.finished() && Answer::parse(&report.answer) == Answer::parse(expected);
+    let use_color = options.color.enabled();
+
+    if passed {
+        if options.verbosity > Verbosity::Quiet {
+            if use_color {
+                println!(
+                    "\x1b[32mPASS\x1b[0m day {} part {}",
+                    report.day, report.part
+                );
+            } else {
+                println!("PASS day {} part {}", report.day, report.part);
+            }
+        }
+    } else {
+        let message = format!(
+            "day {} part {}: expected '{}', got '{}'",
+            report.day,
+            report.part,
+            expected,
+            report.display_answer()
+        );
+        let details = if report.finished() {
+            mismatch_details(expected.trim(), report.answer.trim(), use_color)
+        } else {
+            Vec::new()
+        };
+        if options.verbosity == Verbosity::Quiet {
+            eprintln!("FAIL {}", message);
+            details.iter().for_each(|line| eprintln!("{}", line));
+        } else {
+            if use_color {
+                println!("\x1b[31mFAIL\x1b[0m {}", message);
+            } else {
+                println!("FAIL {}", message);
+            }
+            details.iter().for_each(|line| println!("{}", line));
+        }
+    }
+
+    passed
+}
+
+/// A run of characters in the [`diff`] of two answers.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffSpan {
+    /// Characters both answers have.
+    Same(String),
+    /// Characters only the expected answer has.
+    Missing(String),
+    /// Characters only the actual answer has.
+    Extra(String),
+}
+
+/// Computes a character-level diff of two answers.
+///
+/// The shared characters are a longest common subsequence of both answers;
+/// every other character is either missing from or extra in `actual`.
+/// Adjacent characters of the same kind are merged into one span.
+///
+/// # Examples
+/// ```
+/// use aoc2025::answers::{DiffSpan, diff};
+///
+/// assert_eq!(
+///     diff("1234", "1244"),
+///     [
+///         DiffSpan::Same("12".to_string()),
+///         DiffSpan::Missing("3".to_string()),
+///         DiffSpan::Same("4".to_string()),
+///         DiffSpan::Extra("4".to_string()),
+///     ]
+/// );
+/// ```
+pub fn diff(expected: &str, actual: &str) -> Vec<DiffSpan> {
+    let expected: Vec<char> = expected.chars().collect();
+    let actual: Vec<char> = actual.chars().collect();
+
+    // common[i][j] is the length of the longest common subsequence of
+    // expected[i..] and actual[j..].
+    let mut common = vec![vec![0_usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+
+    let mut spans: Vec<DiffSpan> = Vec::new();
+    let mut push =
+        |span: fn(String) -> DiffSpan, c: char| match (spans.last_mut(), span(String::new())) {
+            (Some(DiffSpan::Same(text)), DiffSpan::Same(_))
+            | (Some(DiffSpan::Missing(text)), DiffSpan::Missing(_))
+            | (Some(DiffSpan::Extra(text)), DiffSpan::Extra(_)) => text.push(c),
+            _ => spans.push(span(c.to_string())),
+        };
+    let (mut i, mut j) = (0, 0);
+    while i < expected.len() || j < actual.len() {
+        if i < expected.len() && j < actual.len() && expected[i] == actual[j] {
+            push(DiffSpan::Same, expected[i]);
+            i += 1;
+            j += 1;
+        } else if j == actual.len() || (i < expected.len() && common[i + 1][j] >= common[i][j + 1])
+        {
+            push(DiffSpan::Missing, expected[i]);
+            i += 1;
+        } else {
+            push(DiffSpan::Extra, actual[j]);
+            j += 1;
+        }
+    }
+    spans
+}
+
+/// Returns a hint if two different answers differ only in whitespace or sign.
+///
+/// # Examples
+/// ```
+/// use aoc2025::answers::mismatch_hint;
+///
+/// assert_eq!(mismatch_hint("-42", "42"), Some("the answers differ only in sign"));
+/// assert_eq!(mismatch_hint("41", "42"), None);
+/// ```
+pub fn mismatch_hint(expected: &str, actual: &str) -> Option<&'static str> {
+    let without_whitespace = |answer: &str| {
+        answer
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+    };
+    let without_sign = |answer: &str| answer.trim().trim_start_matches(['-', '+']).to_string();
+
+    if expected == actual {
+        None
+    } else if without_whitespace(expected) == without_whitespace(actual) {
+        Some("the answers differ only in whitespace")
+    } else if without_sign(expected) == without_sign(actual) {
+        Some("the answers differ only in sign")
+    } else {
+        None
+    }
+}
+
+/// Returns the lines explaining a wrong answer, printed by [`verify`] below the `FAIL` line.
+///
+/// With colors, the expected answer is shown with its missing characters in
+/// red and the actual answer with its extra characters in green. Without
+/// them, a single line marks missing characters as `[-…-]` and extra ones as
+/// `{+…+}`, like `git diff --word-diff`. A [`mismatch_hint`] is added if one applies.
+pub fn mismatch_details(expected: &str, actual: &str, use_color: bool) -> Vec<String> {
+    let spans = diff(expected, actual);
+    let render = |missing: (&str, &str), extra: (&str, &str), show: fn(&DiffSpan) -> bool| {
+        spans
+            .iter()
+            .filter(|span| show(span))
+            .map(|span| match span {
+                DiffSpan::Same(text) => text.clone(),
+                DiffSpan::Missing(text) => format!("{}{}{}", missing.0, text, missing.1),
+                DiffSpan::Extra(text) => format!("{}{}{}", extra.0, text, extra.1),
+            })
+            .collect::<String>()
+    };
+
+    let mut lines = if use_color {
+        let red = ("\x1b[31m", "\x1b[0m");
+        let green = ("\x1b[32m", "\x1b[0m");
+        vec![
+            format!(
+                "  expected: {}",
+                render(red, green, |span| !matches!(span, DiffSpan::Extra(_)))
+            ),
+            format!(
+                "  actual:   {}",
+                render(red, green, |span| !matches!(span, DiffSpan::Missing(_)))
+            ),
+        ]
+    } else {
+        vec![format!(
+            "  diff: {}",
+            render(("[-", "-]"), ("{+", "+}"), |_| true)
+        )]
+    };
+    if let Some(hint) = mismatch_hint(expected, actual) {
+        lines.push(format!("  hint: {}", hint));
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let answers =
+            ExpectedAnswers::parse("[day01]\npart1 = 3\npart2 = \"6\"\n\n[day12]\npart1 = \"abc\"")
+                .unwrap();
+        assert_eq!(answers.get(1, 1), Some("3"));
+        assert_eq!(answers.get(1, 2), Some("6"));
+        assert_eq!(answers.get(12, 1), Some("abc"));
+        assert_eq!(answers.get(2, 1), None);
+    }
+
+    #[test]
+    fn test_recorded_answers_other_input() {
+        assert_eq!(recorded_answers(Some("alternate.txt")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_key() {
+        let result = ExpectedAnswers::parse("[first]\npart1 = 3");
+        assert_eq!(
+            result,
+            Err("expected a key like 'day1', found 'first'".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid_value() {
+        let result = ExpectedAnswers::parse("[day01]\npart1 = 1.5");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_verify() {
+        let report = RunReport {
+            day: 1,
+            part: 1,
+            input_path: "inputs/day01.txt".to_string(),
+            answer: "42".to_string(),
+            input_bytes: 0,
+            input_lines: 0,
+            input_hash: 0,
+            input_duration: std::time::Duration::ZERO,
+            parse_duration: std::time::Duration::ZERO,
+            solve_duration: std::time::Duration::ZERO,
+            samples: Vec::new(),
+            timed_out: false,
+            cancelled: false,
+            panic: None,
+            allocations: None,
+            peak_rss: None,
+            environment: None,
+        };
+        let options = OutputOptions {
+            verbosity: Verbosity::Quiet,
+            ..OutputOptions::default()
+        };
+        assert!(verify(&report, " 42\n", &options));
+        assert!(verify(&report, "042", &options));
+        assert!(!verify(&report, "43", &options));
+    }
+
+    #[test]
+    fn test_diff() {
+        let spans = |expected, actual| {
+            diff(expected, actual)
+                .into_iter()
+                .map(|span| match span {
+                    DiffSpan::Same(text) => text,
+                    DiffSpan::Missing(text) => format!("-{}", text),
+                    DiffSpan::Extra(text) => format!("+{}", text),
+                })
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(spans("42", "42"), ["42"]);
+        assert_eq!(spans("1234", "124"), ["12", "-3", "4"]);
+        assert_eq!(spans("99", "1001"), ["-99", "+1001"]);
+        assert_eq!(spans("1001", "1010"), ["10", "-0", "1", "+0"]);
+        assert_eq!(spans("", "7"), ["+7"]);
+        assert_eq!(spans("abc", ""), ["-abc"]);
+    }
+
+    #[test]
+    fn test_mismatch_details() {
+        assert_eq!(
+            mismatch_details("12345", "1245", false),
+            ["  diff: 12[-3-]45"]
+        );
+        assert_eq!(
+            mismatch_details("AB CD", "ABCD", false),
+            [
+                "  diff: AB[- -]CD",
+                "  hint: the answers differ only in whitespace"
+            ]
+        );
+        assert_eq!(
+            mismatch_details("-7", "7", true),
+            [
+                "  expected: \x1b[31m-\x1b[0m7",
+                "  actual:   7",
+                "  hint: the answers differ only in sign"
+            ]
+        );
+    }
+}
@@ -0,0 +1,417 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use tracing::debug;
+
+use crate::config::Config;
+
+/// The website all requests are sent to.
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// The directory responses are cached in.
+pub const CACHE_DIR: &str = ".aoc/cache";
+
+/// The environment variable that takes precedence over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// The service the session token is stored under in the system keyring.
+const KEYRING_SERVICE: &str = "aoc2025";
+
+/// The user name the session token is stored under in the system keyring.
+const KEYRING_USER: &str = "session";
+
+/// The minimum time between two requests, across all invocations.
+pub const MIN_REQUEST_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Identifies the tool in requests, as asked for by the Advent of Code automation guidelines.
+const USER_AGENT: &str = "github.com/Shadow3xploit/AOC-2025";
+
+/// The file below the cache directory recording when the last request was sent.
+const LAST_REQUEST_FILE: &str = "last_request";
+
+/// How long a cached response may be reused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CachePolicy {
+    /// The response never changes, e.g. a puzzle input.
+    Forever,
+    /// The response is reused while it is younger than the given age.
+    MaxAge(Duration),
+    /// The response is always requested anew and not cached.
+    Never,
+    /// The response is requested anew, replacing a cached one.
+    Refresh,
+}
+
+/// A client for the Advent of Code website shared by all network features.
+///
+/// Every request carries the session cookie and a descriptive User-Agent,
+/// keeps at least [`MIN_REQUEST_INTERVAL`] from the previous one (also across
+/// separate invocations of the binaries), and `GET` responses are cached on
+/// disk according to their [`CachePolicy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Client {
+    /// The URL paths are appended to, see [`BASE_URL`].
+    pub base_url: String,
+    /// The session token sent as cookie.
+    pub session: String,
+    /// The directory responses and the rate limiting state are stored in.
+    pub cache_dir: PathBuf,
+    /// The minimum time between two requests.
+    pub min_interval: Duration,
+}
+
+impl Client {
+    /// Creates a client for the website using the given session token.
+    pub fn new(session: &str) -> Client {
+        Client {
+            base_url: BASE_URL.to_string(),
+            session: session.to_string(),
+            cache_dir: PathBuf::from(CACHE_DIR),
+            min_interval: MIN_REQUEST_INTERVAL,
+        }
+    }
+
+    /// Creates a client using the session token of the configuration, see [`session_token`].
+    pub fn from_config(config: &Config) -> io::Result<Client> {
+        Ok(Client::new(&session_token(config)?))
+    }
+
+    /// Sends a `GET` request, or answers it from the cache.
+    ///
+    /// # Parameters
+    /// - `path`: The path below the base URL, e.g. `/2025/day/3/input`.
+    /// - `cache`: How long the response may be reused.
+    ///
+    /// # Returns
+    /// The response body, or an error describing the failed request. Rejected
+    /// session tokens are reported as `PermissionDenied` and unknown pages as
+    /// `NotFound`.
+    pub fn get(&self, path: &str, cache: CachePolicy) -> io::Result<String> {
+        let cache_path = self.cache_path(path);
+        if let Some(body) = read_cached(&cache_path, cache) {
+            debug!(path, "answered from cache");
+            return Ok(body);
+        }
+
+        self.wait_for_turn()?;
+        let request = self.request(ureq::get(&self.url(path)));
+        let body = Client::send(path, request.call())?;
+
+        if cache != CachePolicy::Never {
+            if let Some(dir) = cache_path.parent() {
+                fs::create_dir_all(dir)?;
+            }
+            fs::write(&cache_path, &body)?;
+        }
+        Ok(body)
+    }
+
+    /// Sends a `POST` request with form fields. Responses are never cached.
+    ///
+    /// # Returns
+    /// The response body, or an error like for [`Client::get`].
+    pub fn post_form(&self, path: &str, fields: &[(&str, &str)]) -> io::Result<String> {
+        self.wait_for_turn()?;
+        let request = self.request(ureq::post(&self.url(path)));
+        Client::send(path, request.send_form(fields))
+    }
+
+    /// Returns the full URL of a path.
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+
+    /// Returns the file the response to a `GET` of `path` is cached in.
+    fn cache_path(&self, path: &str) -> PathBuf {
+        let relative = path.trim_start_matches('/').replace(['?', '&', '='], "_");
+        self.cache_dir.join("responses").join(relative)
+    }
+
+    /// Reads the body of a response, describing a failed request.
+    fn send(path: &str, response: Result<ureq::Response, ureq::Error>) -> io::Result<String> {
+        debug!(path, "request sent");
+        match response {
+            Ok(response) => response.into_string(),
+            Err(ureq::Error::Status(status, _)) => Err(status_error(status, path)),
+            Err(err) => Err(io::Error::other(format!(
+                "Request to '{}' failed: {}",
+                path, err
+            ))),
+        }
+    }
+
+    /// Sleeps until [`Client::min_interval`] has passed since the last request
+    /// and records the current time as the time of the next one.
+    fn wait_for_turn(&self) -> io::Result<()> {
+        let state = self.cache_dir.join(LAST_REQUEST_FILE);
+        let now = unix_millis(SystemTime::now());
+        let last = fs::read_to_string(&state)
+            .ok()
+            .and_then(|content| content.trim().parse().ok());
+
+        let wait = remaining_wait(last, now, self.min_interval);
+        if !wait.is_zero() {
+            debug!(wait_ms = wait.as_millis() as u64, "rate limited");
+            thread::sleep(wait);
+        }
+
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&state, unix_millis(SystemTime::now()).to_string())
+    }
+
+    /// Returns a request builder with the session cookie and User-Agent set.
+    fn request(&self, request: ureq::Request) -> ureq::Request {
+        request
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", USER_AGENT)
+    }
+}
+
+/// Reads a cached response if it exists and is still fresh under `cache`.
+fn read_cached(path: &Path, cache: CachePolicy) -> Option<String> {
+    let max_age = match cache {
+        CachePolicy::Never | CachePolicy::Refresh => return None,
+        CachePolicy::Forever => None,
+        CachePolicy::MaxAge(age) => Some(age),
+    };
+    if let Some(max_age) = max_age {
+        let age = fs::metadata(path).ok()?.modified().ok()?.elapsed().ok()?;
+        if age > max_age {
+            return None;
+        }
+    }
+    fs::read_to_string(path).ok()
+}
+
+/// Returns how long to wait before the next request.
+///
+/// # Parameters
+/// - `last`: The time of the last request in milliseconds since the epoch, if any.
+/// - `now`: The current time in milliseconds since the epoch.
+fn remaining_wait(last: Option<u64>, now: u64, min_interval: Duration) -> Duration {
+    let Some(last) = last else {
+        return Duration::ZERO;
+    };
+    let elapsed = Duration::from_millis(now.saturating_sub(last));
+    min_interval.saturating_sub(elapsed)
+}
+
+/// Converts a point in time to milliseconds since the epoch.
+fn unix_millis(time: SystemTime) -> u64 {
+    time.duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// Describes an unsuccessful HTTP status.
+fn status_error(status: u16, path: &str) -> io::Error {
+    match status {
+        400 | 401 | 403 => io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            format!(
+                "The session token was rejected (HTTP {}); log in again and update it",
+                status
+            ),
+        ),
+        404 => io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("'{}' is not available (HTTP 404)", path),
+        ),
+        _ => io::Error::other(format!("Request to '{}' failed with HTTP {}", path, status)),
+    }
+}
+
+/// Reads the session token used to authenticate requests.
+///
+/// The token stored in the system keyring by [`store_session_token`] is
+/// preferred. Without one, the [`SESSION_ENV`] environment variable is used
+/// if set, and otherwise the configured session file. Surrounding whitespace
+/// is removed, and a `session=` prefix copied along from the browser is accepted.
+///
+/// # Returns
+/// The token, or a `NotFound` error explaining where the token is expected.
+pub fn session_token(config: &Config) -> io::Result<String> {
+    if let Some(token) = keyring_token() {
+        return Ok(token);
+    }
+    let token = match std::env::var(SESSION_ENV) {
+        Ok(token) => token,
+        Err(_) => fs::read_to_string(&config.session_file).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "No session token: run `aoc login`, set {} or store it in '{}' ({})",
+                    SESSION_ENV, config.session_file, err
+                ),
+            )
+        })?,
+    };
+    Ok(clean_token(&token))
+}
+
+/// Returns whether a session token is available, i.e. requests can be sent.
+pub fn has_session_token(config: &Config) -> bool {
+    keyring_token().is_some()
+        || std::env::var_os(SESSION_ENV).is_some()
+        || Path::new(&config.session_file).exists()
+}
+
+/// Stores the session token in the system keyring, replacing a stored one.
+///
+/// # Returns
+/// An error if the platform has no usable keyring.
+pub fn store_session_token(token: &str) -> io::Result<()> {
+    keyring_entry()
+        .and_then(|entry| entry.set_password(&clean_token(token)))
+        .map_err(|err| {
+            io::Error::other(format!(
+                "Could not store the session token in the system keyring ({}); set {} or use the session file instead",
+                err, SESSION_ENV
+            ))
+        })
+}
+
+/// Removes the session token from the system keyring.
+///
+/// # Returns
+/// `true` if a token was stored, or an error if the keyring cannot be accessed.
+pub fn remove_session_token() -> io::Result<bool> {
+    match keyring_entry().and_then(|entry| entry.delete_credential()) {
+        Ok(()) => Ok(true),
+        Err(keyring::Error::NoEntry) => Ok(false),
+        Err(err) => Err(io::Error::other(format!(
+            "Could not remove the session token from the system keyring: {}",
+            err
+        ))),
+    }
+}
+
+/// Returns the keyring entry the session token is stored in.
+fn keyring_entry() -> keyring::Result<keyring::Entry> {
+    keyring::Entry::new(KEYRING_SERVICE, KEYRING_USER)
+}
+
+/// Reads the session token from the system keyring.
+///
+/// A missing entry and an unusable keyring both yield `None`, so the other
+/// sources are tried on machines without a keyring, e.g. in CI.
+fn keyring_token() -> Option<String> {
+    match keyring_entry().and_then(|entry| entry.get_password()) {
+        Ok(token) => Some(token),
+        Err(keyring::Error::NoEntry) => None,
+        Err(err) => {
+            debug!(%err, "system keyring unavailable");
+            None
+        }
+    }
+}
+
+/// Removes whitespace and a leading `session=` from a token.
+fn clean_token(token: &str) -> String {
+    let token = token.trim();
+    token.strip_prefix("session=").unwrap_or(token).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// Serves `body` to every request on a local port and counts the requests.
+    fn serve(body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut line = String::new();
+                while reader.read_line(&mut line).unwrap() > 2 {
+                    line.clear();
+                }
+                counter.fetch_add(1, Ordering::SeqCst);
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+            }
+        });
+        (format!("http://{}", address), requests)
+    }
+
+    #[test]
+    fn test_get_caches_responses() {
+        let (base_url, requests) = serve("1 2 3\n");
+        let cache_dir = std::env::temp_dir().join(format!("aoc2025-client-{}", std::process::id()));
+        let client = Client {
+            base_url,
+            cache_dir: cache_dir.clone(),
+            min_interval: Duration::ZERO,
+            ..Client::new("token")
+        };
+
+        let first = client
+            .get("/2025/day/1/input", CachePolicy::Forever)
+            .unwrap();
+        let second = client
+            .get("/2025/day/1/input", CachePolicy::Forever)
+            .unwrap();
+        let uncached = client.get("/2025/day/1", CachePolicy::Never).unwrap();
+        fs::remove_dir_all(&cache_dir).unwrap();
+
+        assert_eq!(first, "1 2 3\n");
+        assert_eq!(second, first);
+        assert_eq!(uncached, first);
+        assert_eq!(requests.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_remaining_wait() {
+        let interval = Duration::from_secs(3);
+        assert_eq!(remaining_wait(None, 10_000, interval), Duration::ZERO);
+        assert_eq!(
+            remaining_wait(Some(9_000), 10_000, interval),
+            Duration::from_secs(2)
+        );
+        assert_eq!(
+            remaining_wait(Some(5_000), 10_000, interval),
+            Duration::ZERO
+        );
+    }
+
+    #[test]
+    fn test_cache_path() {
+        let client = Client::new("token");
+        assert_eq!(
+            client.cache_path("/2025/leaderboard/private/view/1.json?x=1"),
+            Path::new(".aoc/cache/responses/2025/leaderboard/private/view/1.json_x_1")
+        );
+    }
+
+    #[test]
+    fn test_status_error() {
+        assert_eq!(
+            status_error(400, "/").kind(),
+            io::ErrorKind::PermissionDenied
+        );
+        assert_eq!(status_error(404, "/").kind(), io::ErrorKind::NotFound);
+        assert!(status_error(500, "/x").to_string().contains("HTTP 500"));
+    }
+
+    #[test]
+    fn test_clean_token() {
+        assert_eq!(clean_token("abc123\n"), "abc123");
+        assert_eq!(clean_token(" session=abc123 "), "abc123");
+    }
+}
@@ -0,0 +1,173 @@
+use std::fmt;
+use std::io;
+
+use crate::registry::{self, DayEntry};
+use crate::solver::{Answer, SolveError};
+
+/// The year of the puzzles solved by [`solve`].
+pub const YEAR: i32 = 2025;
+
+/// The error of [`solve`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The requested day and part has no solution.
+    NotImplemented {
+        /// The requested day.
+        day: i32,
+        /// The requested part.
+        part: i32,
+    },
+    /// The puzzle could not be solved, e.g. because the input is malformed.
+    Solve(SolveError),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotImplemented { day, part } => {
+                write!(f, "day {} part {} is not implemented", day, part)
+            }
+            Error::Solve(err) => write!(f, "{}", err),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::NotImplemented { .. } => None,
+            Error::Solve(err) => Some(err),
+        }
+    }
+}
+
+impl From<SolveError> for Error {
+    fn from(err: SolveError) -> Error {
+        Error::Solve(err)
+    }
+}
+
+impl From<Error> for io::Error {
+    fn from(err: Error) -> io::Error {
+        match err {
+            Error::NotImplemented { .. } => io::Error::new(io::ErrorKind::NotFound, err),
+            Error::Solve(err) => err.into(),
+        }
+    }
+}
+
+/// Solves one puzzle of [`YEAR`] on the given input.
+///
+/// The input is validated and parsed before solving, so a malformed input is reported
+/// as [`SolveError::InvalidInput`] instead of a panic. Unlike the `aoc`
+/// binary, this does not read `aoc.toml`, print anything, or record history.
+///
+/// # Returns
+/// The answer, [`Error::NotImplemented`] for an unknown day or part, or
+/// [`Error::Solve`] if the input cannot be solved or the solver was stopped
+/// by the [global cancel token](crate::cancel::global), see
+/// [`SolveError::Cancelled`].
+///
+/// # Examples
+/// ```
+/// use aoc2025::{Answer, Error};
+///
+/// assert_eq!(aoc2025::solve(1, 2, "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82"), Ok(Answer::I64(6)));
+/// assert_eq!(aoc2025::solve(25, 1, ""), Err(Error::NotImplemented { day: 25, part: 1 }));
+/// assert!(matches!(aoc2025::solve(1, 1, "forward 5"), Err(Error::Solve(_))));
+/// ```
+pub fn solve(day: i32, part: i32, input: &str) -> Result<Answer, Error> {
+    let not_implemented = Error::NotImplemented { day, part };
+    let entry = day_entry(day).ok_or(not_implemented.clone())?;
+    let (_, try_solve) = entry
+        .try_parts
+        .iter()
+        .find(|(solver_part, _)| *solver_part == part)
+        .ok_or(not_implemented)?;
+
+    Ok(try_solve(input)?)
+}
+
+/// Returns every puzzle of [`YEAR`] that [`solve`] can solve as `(day, part)`,
+/// ordered by day and part.
+///
+/// # Examples
+/// ```
+/// assert!(aoc2025::puzzles().contains(&(1, 2)));
+/// ```
+pub fn puzzles() -> Vec<(i32, i32)> {
+    registry::event(YEAR).map_or_else(Vec::new, |event| {
+        event
+            .solvers
+            .iter()
+            .map(|(day, part, _)| (*day, *part))
+            .collect()
+    })
+}
+
+/// Returns the registered entry of a day of [`YEAR`], independent of the configured year.
+fn day_entry(day: i32) -> Option<&'static DayEntry> {
+    registry::event(YEAR)?
+        .days
+        .iter()
+        .find(|entry| entry.day == day)
+        .copied()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{example, example_answer};
+
+    #[test]
+    fn test_solve_examples() {
+        for (day, part) in puzzles() {
+            let answer = solve(day, part, example(day).unwrap()).unwrap();
+            assert_eq!(Some(answer), example_answer(day, part).map(Answer::parse));
+        }
+    }
+
+    #[test]
+    fn test_solve_never_panics() {
+        let inputs = [
+            "",
+            "\n",
+            " ",
+            "-",
+            "R",
+            "L-5",
+            "ü",
+            "@\n\n@",
+            "1-2,",
+            "3-5\n\n",
+            "\n\n1",
+            "99999999999999999999",
+            "9223372036854775800-9223372036854775807",
+            "1 2\n*",
+            "1\n+\n*",
+            "9223372036854775807 1\n+                   +",
+            "99999 99999 99999 99999\n*     *     *     *    ",
+        ];
+        for (day, part) in puzzles() {
+            for input in inputs {
+                let _ = solve(day, part, input);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(all(feature = "day02", feature = "day05"))]
+    fn test_solve_errors() {
+        assert_eq!(
+            solve(5, 2, example(5).unwrap()),
+            Err(Error::NotImplemented { day: 5, part: 2 })
+        );
+        let err = solve(2, 1, "11-22,oops").unwrap_err();
+        assert!(matches!(err, Error::Solve(SolveError::InvalidInput(_))));
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            io::Error::from(Error::Solve(SolveError::Cancelled)).kind(),
+            io::ErrorKind::Interrupted
+        );
+    }
+}
@@ -0,0 +1,337 @@
+use std::fs;
+use std::io;
+use std::panic;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Deserialize;
+
+use crate::registry;
+use crate::solver::Answer;
+use crate::utils::{
+    OutputOptions, RunOptions, RunReport, Verbosity, format_duration, run_day_with,
+};
+
+/// One run listed in a batch manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct BatchEntry {
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The part number of the puzzle.
+    pub part: i32,
+    /// The input file, relative to the directory of the manifest.
+    pub input: String,
+    /// The expected answer, if known.
+    #[serde(default, deserialize_with = "deserialize_answer")]
+    pub answer: Option<String>,
+}
+
+/// A list of puzzle runs with their inputs and expected answers.
+///
+/// Manifests are TOML files with one `[[run]]` table per run:
+///
+/// ```toml
+/// [[run]]
+/// day = 2
+/// part = 1
+/// input = "friends/alice/day02.txt"
+/// answer = 1227775554
+/// ```
+///
+/// Answers may be written as strings or integers, and may be omitted to
+/// just run the input.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Manifest {
+    /// The runs in manifest order.
+    #[serde(default, rename = "run")]
+    pub runs: Vec<BatchEntry>,
+}
+
+impl Manifest {
+    /// Parses a manifest from the contents of a manifest file.
+    ///
+    /// # Returns
+    /// The parsed manifest, or a message describing why it is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::batch::Manifest;
+    ///
+    /// let manifest = Manifest::parse("[[run]]\nday = 1\npart = 2\ninput = \"a.txt\"\nanswer = 6").unwrap();
+    /// assert_eq!(manifest.runs[0].answer.as_deref(), Some("6"));
+    /// ```
+    pub fn parse(content: &str) -> Result<Manifest, String> {
+        toml::from_str(content).map_err(|err| err.message().to_string())
+    }
+
+    /// Reads and parses a manifest file.
+    ///
+    /// Relative input paths are resolved against the directory of the
+    /// manifest, so a manifest can be kept next to the inputs it lists.
+    ///
+    /// # Returns
+    /// The parsed manifest, or an I/O error if the file cannot be read or is invalid.
+    pub fn load(path: &str) -> io::Result<Manifest> {
+        let content = fs::read_to_string(path).map_err(|err| {
+            io::Error::new(
+                err.kind(),
+                format!("Could not read manifest '{}': {}", path, err),
+            )
+        })?;
+        let mut manifest = Manifest::parse(&content).map_err(|message| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid manifest '{}': {}", path, message),
+            )
+        })?;
+
+        let base = Path::new(path).parent().unwrap_or(Path::new(""));
+        for run in &mut manifest.runs {
+            run.input = base.join(&run.input).to_string_lossy().into_owned();
+        }
+        Ok(manifest)
+    }
+}
+
+/// Accepts answers written as TOML strings or integers.
+fn deserialize_answer<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Answer {
+        Text(String),
+        Number(i64),
+    }
+
+    Ok(match Answer::deserialize(deserializer)? {
+        Answer::Text(text) => Some(text),
+        Answer::Number(number) => Some(number.to_string()),
+    })
+}
+
+/// The outcome of one run of a batch.
+#[derive(Debug, Clone, PartialEq)]
+pub enum BatchOutcome {
+    /// The solver returned the expected answer.
+    Pass(RunReport),
+    /// The solver returned a different answer or timed out.
+    Fail(RunReport),
+    /// The solver ran, but the manifest has no expected answer.
+    Ran(RunReport),
+    /// The run could not be executed, e.g. because the input is missing.
+    Error(String),
+}
+
+impl BatchOutcome {
+    /// Returns whether the run counts as failed.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, BatchOutcome::Fail(_) | BatchOutcome::Error(_))
+    }
+}
+
+/// Executes every run of a manifest, one after another.
+///
+/// Missing solvers, unreadable inputs, and solver panics are recorded as
+/// [`BatchOutcome::Error`] and do not stop the remaining runs.
+///
+/// # Parameters
+/// - `timeout`: The time budget of every single solver, or `None` for no limit.
+///
+/// # Returns
+/// One outcome per run, in manifest order.
+pub fn run_batch(manifest: &Manifest, timeout: Option<Duration>) -> Vec<BatchOutcome> {
+    manifest
+        .runs
+        .iter()
+        .map(|entry| {
+            let Some(solve) = registry::solver(entry.day, entry.part) else {
+                return BatchOutcome::Error(format!(
+                    "Day {} part {} is not implemented",
+                    entry.day, entry.part
+                ));
+            };
+            let outcome = panic::catch_unwind(|| {
+                run_day_with(
+                    entry.day,
+                    &[(entry.part, solve)],
+                    Some(&entry.input),
+                    &RunOptions {
+                        timeout,
+                        ..RunOptions::default()
+                    },
+                )
+            });
+            let report = match outcome {
+                Ok(Ok(mut reports)) => reports.remove(0),
+                Ok(Err(err)) => return BatchOutcome::Error(err.to_string()),
+                Err(_) => return BatchOutcome::Error("the solver panicked".to_string()),
+            };
+
+            match &entry.answer {
+                Some(expected)
+                    if report.finished()
+                        && Answer::parse(&report.answer) == Answer::parse(expected) =>
+                {
+                    BatchOutcome::Pass(report)
+                }
+                Some(_) => BatchOutcome::Fail(report),
+                None if !report.finished() => BatchOutcome::Fail(report),
+                None => BatchOutcome::Ran(report),
+            }
+        })
+        .collect()
+}
+
+/// Prints an overview table of a batch with one line per run, followed by the totals.
+///
+/// With [`Verbosity::Quiet`] only failed runs are printed.
+///
+/// # Returns
+/// `true` if no run failed.
+pub fn print_batch(
+    manifest: &Manifest,
+    outcomes: &[BatchOutcome],
+    options: &OutputOptions,
+) -> bool {
+    let use_color = options.color.enabled();
+    let input_width = manifest
+        .runs
+        .iter()
+        .map(|entry| entry.input.len())
+        .chain(std::iter::once("Input".len()))
+        .max()
+        .unwrap_or(0);
+
+    if options.verbosity > Verbosity::Quiet {
+        println!(
+            "{:>3}  {:>4}  {:<input_width$}  {:<6}  {:>12}  Answer",
+            "Day", "Part", "Input", "Result", "Solve"
+        );
+    }
+
+    for (entry, outcome) in manifest.runs.iter().zip(outcomes) {
+        if options.verbosity == Verbosity::Quiet && !outcome.is_failure() {
+            continue;
+        }
+
+        let (result, color, solve, detail) = match outcome {
+            BatchOutcome::Pass(report) => ("PASS", "32", solve_time(report), report.answer.clone()),
+            BatchOutcome::Fail(report) => (
+                "FAIL",
+                "31",
+                solve_time(report),
+                format!(
+                    "expected '{}', got '{}'",
+                    entry.answer.as_deref().unwrap_or("-"),
+                    report.display_answer()
+                ),
+            ),
+            BatchOutcome::Ran(report) => ("-", "33", solve_time(report), report.answer.clone()),
+            BatchOutcome::Error(message) => ("ERROR", "31", "-".to_string(), message.clone()),
+        };
+        let result = if use_color {
+            format!("\x1b[{}m{:<6}\x1b[0m", color, result)
+        } else {
+            format!("{:<6}", result)
+        };
+        println!(
+            "{:>3}  {:>4}  {:<input_width$}  {}  {:>12}  {}",
+            entry.day, entry.part, entry.input, result, solve, detail
+        );
+    }
+
+    let passed = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome, BatchOutcome::Pass(_)))
+        .count();
+    let failed = outcomes
+        .iter()
+        .filter(|outcome| outcome.is_failure())
+        .count();
+    if options.verbosity > Verbosity::Quiet {
+        println!();
+        println!(
+            "{} passed, {} failed, {} without expected answer",
+            passed,
+            failed,
+            outcomes.len() - passed - failed
+        );
+    }
+    failed == 0
+}
+
+/// Formats the solve time of a report, see [`format_duration`].
+fn solve_time(report: &RunReport) -> String {
+    format_duration(report.solve_duration)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let manifest = Manifest::parse(
+            "[[run]]\nday = 2\npart = 1\ninput = \"a.txt\"\nanswer = \"12\"\n\n\
+             [[run]]\nday = 3\npart = 2\ninput = \"b.txt\"\n",
+        )
+        .unwrap();
+        assert_eq!(
+            manifest.runs,
+            vec![
+                BatchEntry {
+                    day: 2,
+                    part: 1,
+                    input: "a.txt".to_string(),
+                    answer: Some("12".to_string()),
+                },
+                BatchEntry {
+                    day: 3,
+                    part: 2,
+                    input: "b.txt".to_string(),
+                    answer: None,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(Manifest::parse("[[run]]\nday = 2\npart = 1").is_err());
+        assert!(
+            Manifest::parse("[[run]]\nday = 2\npart = 1\ninput = \"a\"\nanswer = 1.5").is_err()
+        );
+        assert!(Manifest::parse("[[run]]\nday = 2\npart = 1\ninput = \"a\"\nexpect = 1").is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn test_run_batch() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-batch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day01.txt"), registry::example(1).unwrap()).unwrap();
+        fs::write(
+            dir.join("manifest.toml"),
+            "[[run]]\nday = 1\npart = 1\ninput = \"day01.txt\"\nanswer = 3\n\
+             [[run]]\nday = 1\npart = 1\ninput = \"day01.txt\"\nanswer = 4\n\
+             [[run]]\nday = 1\npart = 2\ninput = \"day01.txt\"\n\
+             [[run]]\nday = 1\npart = 1\ninput = \"missing.txt\"\n\
+             [[run]]\nday = 25\npart = 1\ninput = \"day01.txt\"\n",
+        )
+        .unwrap();
+
+        let manifest = Manifest::load(dir.join("manifest.toml").to_str().unwrap()).unwrap();
+        let outcomes = run_batch(&manifest, None);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(matches!(outcomes[0], BatchOutcome::Pass(_)));
+        assert!(matches!(outcomes[1], BatchOutcome::Fail(_)));
+        assert!(matches!(&outcomes[2], BatchOutcome::Ran(report) if report.answer == "6"));
+        assert!(matches!(outcomes[3], BatchOutcome::Error(_)));
+        assert!(matches!(outcomes[4], BatchOutcome::Error(_)));
+    }
+}
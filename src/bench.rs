@@ -0,0 +1,519 @@
+use std::fs;
+use std::hint::black_box;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::solver::Answer;
+use crate::stats::Stats;
+use crate::utils::{OutputOptions, Solver, Verbosity, format_duration};
+
+/// The directory named baselines are stored in.
+pub const BASELINE_DIR: &str = ".aoc/baselines";
+
+/// The timings of repeatedly executing one solver on the same input.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchReport {
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The part number of the puzzle.
+    pub part: i32,
+    /// The answer returned by the last iteration.
+    pub answer: String,
+    /// The solve duration of every measured iteration, in execution order.
+    pub samples: Vec<Duration>,
+}
+
+impl BenchReport {
+    /// Returns the fastest measured iteration.
+    pub fn min(&self) -> Duration {
+        self.samples.iter().min().copied().unwrap_or_default()
+    }
+
+    /// Returns the slowest measured iteration.
+    pub fn max(&self) -> Duration {
+        self.samples.iter().max().copied().unwrap_or_default()
+    }
+
+    /// Returns the mean duration of all measured iterations.
+    pub fn mean(&self) -> Duration {
+        if self.samples.is_empty() {
+            return Duration::ZERO;
+        }
+        self.samples.iter().sum::<Duration>() / self.samples.len() as u32
+    }
+
+    /// Returns the statistics of all measured iterations, or `None` if there are none.
+    pub fn stats(&self) -> Option<Stats> {
+        Stats::from_samples(&self.samples)
+    }
+}
+
+/// Executes a solver repeatedly on already loaded input and records every solve time.
+///
+/// Reading the input is not part of the measurement. The `warmup` iterations
+/// are executed first and discarded, so caches and branch predictors are warm
+/// when the measured iterations start.
+///
+/// # Parameters
+/// - `day`: The day number of the puzzle.
+/// - `part`: The part number of the puzzle.
+/// - `input`: The puzzle input passed to every iteration.
+/// - `solve`: The solver to measure.
+/// - `iterations`: The number of measured iterations.
+/// - `warmup`: The number of unmeasured iterations executed beforehand.
+///
+/// # Returns
+/// A [`BenchReport`] with one sample per measured iteration.
+///
+/// # Panics
+/// Panics if `iterations` is zero.
+pub fn bench(
+    day: i32,
+    part: i32,
+    input: &str,
+    solve: Solver,
+    iterations: usize,
+    warmup: usize,
+) -> BenchReport {
+    assert!(iterations > 0, "at least one iteration is required");
+
+    for _ in 0..warmup {
+        black_box(solve(black_box(input)));
+    }
+
+    let mut samples: Vec<Duration> = Vec::with_capacity(iterations);
+    let mut answer = Answer::Text(String::new());
+    for _ in 0..iterations {
+        let start = Instant::now();
+        answer = black_box(solve(black_box(input)));
+        samples.push(start.elapsed());
+    }
+
+    BenchReport {
+        day,
+        part,
+        answer: answer.to_string(),
+        samples,
+    }
+}
+
+/// Prints the aggregated timings of a benchmark to stdout.
+///
+/// Besides the minimum, mean and maximum, the median, standard deviation,
+/// 95th percentile and number of outliers are printed (see [`Stats`]). With
+/// [`Verbosity::Quiet`] only the mean in milliseconds is printed.
+pub fn print_bench(report: &BenchReport, options: &OutputOptions) {
+    if options.verbosity == Verbosity::Quiet {
+        println!("{:.3}", report.mean().as_secs_f64() * 1000.0);
+        return;
+    }
+
+    println!("Day {} part {}", report.day, report.part);
+    println!("Answer:     {}", report.answer);
+    println!("Iterations: {}", report.samples.len());
+    println!("Min:        {}", format_duration(report.min()));
+    println!("Mean:       {}", format_duration(report.mean()));
+    println!("Max:        {}", format_duration(report.max()));
+    if let Some(stats) = report.stats() {
+        println!("Median:     {}", format_duration(stats.median));
+        println!("Std dev:    {}", format_duration(stats.std_dev));
+        println!("p95:        {}", format_duration(stats.p95));
+        println!("Outliers:   {}", stats.outliers.len());
+    }
+}
+
+/// The file formats raw benchmark samples can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum SampleFormat {
+    /// A JSON array with one object per benchmarked puzzle.
+    Json,
+    /// Comma-separated values with one row per iteration.
+    Csv,
+}
+
+impl SampleFormat {
+    /// Guesses the sample format from the extension of `path`.
+    ///
+    /// `.csv` files are exported as CSV, everything else as JSON.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::bench::SampleFormat;
+    ///
+    /// assert_eq!(SampleFormat::from_path("samples.csv"), SampleFormat::Csv);
+    /// assert_eq!(SampleFormat::from_path("samples.json"), SampleFormat::Json);
+    /// ```
+    pub fn from_path(path: &str) -> SampleFormat {
+        match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => SampleFormat::Csv,
+            _ => SampleFormat::Json,
+        }
+    }
+
+    /// Renders the samples of the reports in this format.
+    pub fn render(self, reports: &[BenchReport]) -> String {
+        match self {
+            SampleFormat::Json => samples_to_json(reports),
+            SampleFormat::Csv => samples_to_csv(reports),
+        }
+    }
+}
+
+/// The raw samples of one benchmark as written to a JSON export.
+#[derive(Serialize)]
+struct SampleRecord {
+    day: i32,
+    part: i32,
+    samples_ns: Vec<u128>,
+}
+
+/// Renders the per-iteration samples of benchmarks as JSON.
+///
+/// The document is an array with one object per report holding the day,
+/// part, and the solve time of every measured iteration in nanoseconds.
+///
+/// # Returns
+/// The JSON document, terminated by a newline.
+pub fn samples_to_json(reports: &[BenchReport]) -> String {
+    let records: Vec<SampleRecord> = reports
+        .iter()
+        .map(|report| SampleRecord {
+            day: report.day,
+            part: report.part,
+            samples_ns: report.samples.iter().map(Duration::as_nanos).collect(),
+        })
+        .collect();
+    serde_json::to_string_pretty(&records).expect("samples are always serializable") + "\n"
+}
+
+/// Renders the per-iteration samples of benchmarks as CSV.
+///
+/// The first line is a header, followed by one line per measured iteration
+/// with its zero-based index and solve time in nanoseconds.
+///
+/// # Returns
+/// The CSV document, terminated by a newline.
+pub fn samples_to_csv(reports: &[BenchReport]) -> String {
+    let mut output = String::new();
+    output.push_str("day,part,iteration,solve_ns\n");
+
+    for report in reports {
+        for (iteration, sample) in report.samples.iter().enumerate() {
+            output.push_str(&format!(
+                "{},{},{},{}\n",
+                report.day,
+                report.part,
+                iteration,
+                sample.as_nanos()
+            ));
+        }
+    }
+
+    output
+}
+
+/// Writes the per-iteration samples of the reports to `path` in the given format.
+///
+/// # Returns
+/// An I/O error if the file could not be written.
+pub fn export_samples(path: &str, format: SampleFormat, reports: &[BenchReport]) -> io::Result<()> {
+    fs::write(path, format.render(reports))
+}
+
+/// The recorded timings of one puzzle in a saved baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The part number of the puzzle.
+    pub part: i32,
+    /// The fastest iteration, in milliseconds.
+    pub min_ms: f64,
+    /// The mean of all iterations, in milliseconds.
+    pub mean_ms: f64,
+    /// The slowest iteration, in milliseconds.
+    pub max_ms: f64,
+}
+
+impl BaselineEntry {
+    /// Creates the baseline entry of a benchmark.
+    pub fn from_report(report: &BenchReport) -> BaselineEntry {
+        BaselineEntry {
+            day: report.day,
+            part: report.part,
+            min_ms: report.min().as_secs_f64() * 1000.0,
+            mean_ms: report.mean().as_secs_f64() * 1000.0,
+            max_ms: report.max().as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+/// Returns the file a named baseline is stored in.
+///
+/// # Returns
+/// The path below [`BASELINE_DIR`], or an error if `name` is empty or
+/// contains characters that are not allowed in a baseline name.
+pub fn baseline_path(name: &str) -> io::Result<PathBuf> {
+    let valid = !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        && !name.starts_with('.');
+    if !valid {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Invalid baseline name '{}': use letters, digits, '-', '_' and '.'",
+                name
+            ),
+        ));
+    }
+    Ok(PathBuf::from(BASELINE_DIR).join(format!("{}.json", name)))
+}
+
+/// Saves the benchmarks as the baseline `name`.
+///
+/// Puzzles already stored in the baseline but not benchmarked now keep their
+/// previous timings, so a baseline can be built up one puzzle at a time.
+///
+/// # Returns
+/// An I/O error if the baseline could not be read or written.
+pub fn save_baseline(name: &str, reports: &[BenchReport]) -> io::Result<()> {
+    let path = baseline_path(name)?;
+    let mut entries: Vec<BaselineEntry> = match load_baseline(name) {
+        Ok(entries) => entries,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Vec::new(),
+        Err(err) => return Err(err),
+    };
+
+    for report in reports {
+        entries.retain(|entry| (entry.day, entry.part) != (report.day, report.part));
+        entries.push(BaselineEntry::from_report(report));
+    }
+    entries.sort_by_key(|entry| (entry.day, entry.part));
+
+    fs::create_dir_all(BASELINE_DIR)?;
+    let json = serde_json::to_string_pretty(&entries).map_err(io::Error::other)?;
+    fs::write(path, json + "\n")
+}
+
+/// Loads the baseline `name`.
+///
+/// # Returns
+/// The stored entries, or a `NotFound` error if no such baseline was saved.
+pub fn load_baseline(name: &str) -> io::Result<Vec<BaselineEntry>> {
+    let path = baseline_path(name)?;
+    let content = fs::read_to_string(&path).map_err(|err| {
+        io::Error::new(
+            err.kind(),
+            format!("Could not read baseline '{}': {}", name, err),
+        )
+    })?;
+    serde_json::from_str(&content).map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid baseline '{}': {}", name, err),
+        )
+    })
+}
+
+/// The mean solve time of one puzzle compared to a baseline.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The part number of the puzzle.
+    pub part: i32,
+    /// The mean recorded in the baseline, in milliseconds.
+    pub baseline_ms: f64,
+    /// The mean of the current benchmark, in milliseconds.
+    pub current_ms: f64,
+}
+
+impl Comparison {
+    /// Returns the relative change of the mean in percent. Positive values are slower.
+    pub fn change_percent(&self) -> f64 {
+        if self.baseline_ms == 0.0 {
+            return 0.0;
+        }
+        (self.current_ms / self.baseline_ms - 1.0) * 100.0
+    }
+
+    /// Returns whether the puzzle got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change_percent() > threshold
+    }
+}
+
+/// Compares benchmarks with a baseline.
+///
+/// # Returns
+/// One comparison per report that has an entry in `baseline`, in the order of `reports`.
+pub fn compare(reports: &[BenchReport], baseline: &[BaselineEntry]) -> Vec<Comparison> {
+    reports
+        .iter()
+        .filter_map(|report| {
+            let entry = baseline
+                .iter()
+                .find(|entry| (entry.day, entry.part) == (report.day, report.part))?;
+            Some(Comparison {
+                day: report.day,
+                part: report.part,
+                baseline_ms: entry.mean_ms,
+                current_ms: report.mean().as_secs_f64() * 1000.0,
+            })
+        })
+        .collect()
+}
+
+/// Prints a table of comparisons, highlighting regressions above `threshold` percent.
+///
+/// With [`Verbosity::Quiet`] only the regressions are printed.
+///
+/// # Returns
+/// `true` if no puzzle regressed by more than `threshold` percent.
+pub fn print_comparison(
+    comparisons: &[Comparison],
+    threshold: f64,
+    options: &OutputOptions,
+) -> bool {
+    let use_color = options.color.enabled();
+    if options.verbosity > Verbosity::Quiet {
+        println!(
+            "{:>3}  {:>4}  {:>12}  {:>12}  {:>8}",
+            "Day", "Part", "Baseline", "Current", "Change"
+        );
+    }
+
+    let mut passed = true;
+    for comparison in comparisons {
+        let regression = comparison.is_regression(threshold);
+        passed &= !regression;
+        if options.verbosity == Verbosity::Quiet && !regression {
+            continue;
+        }
+
+        let line = format!(
+            "{:>3}  {:>4}  {:>9.3} ms  {:>9.3} ms  {:>+7.1}%",
+            comparison.day,
+            comparison.part,
+            comparison.baseline_ms,
+            comparison.current_ms,
+            comparison.change_percent()
+        );
+        match (regression, use_color) {
+            (true, true) => println!("\x1b[31m{}  REGRESSION\x1b[0m", line),
+            (true, false) => println!("{}  REGRESSION", line),
+            (false, _) => println!("{}", line),
+        }
+    }
+    passed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn echo(input: &str) -> Answer {
+        input.into()
+    }
+
+    #[test]
+    fn test_bench_collects_samples() {
+        let report = bench(1, 2, "42", echo, 5, 2);
+        assert_eq!(report.day, 1);
+        assert_eq!(report.part, 2);
+        assert_eq!(report.answer, "42");
+        assert_eq!(report.samples.len(), 5);
+    }
+
+    #[test]
+    fn test_statistics() {
+        let report = BenchReport {
+            day: 1,
+            part: 1,
+            answer: String::new(),
+            samples: vec![
+                Duration::from_millis(3),
+                Duration::from_millis(1),
+                Duration::from_millis(5),
+            ],
+        };
+        assert_eq!(report.min(), Duration::from_millis(1));
+        assert_eq!(report.mean(), Duration::from_millis(3));
+        assert_eq!(report.max(), Duration::from_millis(5));
+    }
+
+    #[test]
+    fn test_samples_export() {
+        let reports = vec![BenchReport {
+            day: 3,
+            part: 2,
+            answer: String::new(),
+            samples: vec![Duration::from_nanos(1500), Duration::from_micros(2)],
+        }];
+
+        assert_eq!(
+            samples_to_csv(&reports),
+            "day,part,iteration,solve_ns\n3,2,0,1500\n3,2,1,2000\n"
+        );
+        let json: serde_json::Value = serde_json::from_str(&samples_to_json(&reports)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{ "day": 3, "part": 2, "samples_ns": [1500, 2000] }])
+        );
+    }
+
+    #[test]
+    fn test_baseline_path() {
+        assert_eq!(
+            baseline_path("main").unwrap(),
+            PathBuf::from(".aoc/baselines/main.json")
+        );
+        assert!(baseline_path("").is_err());
+        assert!(baseline_path("../main").is_err());
+        assert!(baseline_path("a/b").is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let report = BenchReport {
+            day: 2,
+            part: 1,
+            answer: String::new(),
+            samples: vec![Duration::from_millis(12)],
+        };
+        let baseline = vec![
+            BaselineEntry {
+                day: 1,
+                part: 1,
+                min_ms: 1.0,
+                mean_ms: 1.0,
+                max_ms: 1.0,
+            },
+            BaselineEntry {
+                day: 2,
+                part: 1,
+                min_ms: 8.0,
+                mean_ms: 10.0,
+                max_ms: 12.0,
+            },
+        ];
+
+        let comparisons = compare(&[report], &baseline);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].baseline_ms, 10.0);
+        assert!((comparisons[0].change_percent() - 20.0).abs() < 1e-9);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(25.0));
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one iteration")]
+    fn test_bench_zero_iterations() {
+        bench(1, 1, "", echo, 0, 0);
+    }
+}
@@ -0,0 +1,21 @@
+use aoc2025::check;
+use aoc2025::registry;
+use aoc2025::utils::{OutputOptions, enable_progress, init_logging, print_report, run_day};
+
+/// Entry point for running all implemented Advent of Code puzzles.
+///
+/// The puzzles are taken from the solver registry. Every day's input is read
+/// and parsed only once and shared by both parts using the `run_day` utility.
+///
+/// The results, along with metadata and timings, are printed to stdout.
+fn main() {
+    init_logging();
+    enable_progress(&OutputOptions::default());
+    check::set_enabled(true);
+    for (day, parts) in registry::days() {
+        for report in run_day(day, &parts, None).expect("failed to run puzzle") {
+            print_report(&report, &OutputOptions::default());
+            println!();
+        }
+    }
+}
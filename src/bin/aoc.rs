@@ -0,0 +1,1300 @@
+use std::fs;
+use std::num::NonZeroUsize;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitCode};
+use std::time::Duration;
+
+use aoc2025::answers::{self, ExpectedAnswers};
+use aoc2025::aoc_client;
+use aoc2025::batch::{Manifest, print_batch, run_batch};
+use aoc2025::bench::{
+    BenchReport, SampleFormat, bench, compare, export_samples, load_baseline, print_bench,
+    print_comparison, save_baseline,
+};
+use aoc2025::cancel;
+use aoc2025::check::{self, print_check};
+#[cfg(feature = "clipboard")]
+use aoc2025::clipboard;
+use aoc2025::config::{self, CONFIG_PATH, Config};
+use aoc2025::dashboard;
+use aoc2025::fetch::{self, FetchOutcome};
+use aoc2025::generators;
+use aoc2025::history::{self, DEFAULT_HISTORY_PATH, HistoryEntry, format_timestamp};
+#[cfg(feature = "notify")]
+use aoc2025::notify;
+use aoc2025::puzzle;
+use aoc2025::registry::{self, solver};
+use aoc2025::report::{self, ExportFormat};
+use aoc2025::scaffold;
+use aoc2025::serve::serve;
+use aoc2025::utils::{
+    ColorChoice, EXAMPLE_PATH, OutputOptions, RunOptions, RunReport, STDIN_PATH, Solver, Verbosity,
+    enable_progress, find_input, init_logging, load_input, parse_duration, print_report,
+    print_summary, run_both, run_day, run_day_with, run_days, terminal_width, write_report,
+    write_summary,
+};
+use aoc2025::watch::watch;
+use clap::{Args, Parser, Subcommand};
+
+/// Unified command line interface for all Advent of Code 2025 solutions.
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs Advent of Code 2025 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+
+    /// Prints only the answers.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+
+    /// Prints additional diagnostics (`-vv` for even more).
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// When to use colored output. Defaults to `AOC_COLOR`, the configured choice, or `auto`.
+    #[arg(long, global = true, value_enum)]
+    color: Option<ColorChoice>,
+
+    /// Runs solvers without validating their inputs first.
+    #[arg(long, global = true)]
+    no_validate: bool,
+
+    /// The event year to work on. Defaults to `AOC_YEAR`, the configured year, or 2025.
+    #[arg(long, global = true)]
+    year: Option<i32>,
+}
+
+/// Options for exporting run results to a file.
+#[derive(Args)]
+struct ExportArgs {
+    /// Writes the answers and timings to this file after running.
+    #[arg(long, value_name = "FILE")]
+    export: Option<String>,
+
+    /// The format of the exported file. Guessed from the file extension if omitted.
+    #[arg(long, value_enum, requires = "export")]
+    export_format: Option<ExportFormat>,
+}
+
+impl ExportArgs {
+    /// Exports the reports if an export file was requested.
+    fn apply(&self, reports: &[RunReport]) -> std::io::Result<()> {
+        match &self.export {
+            Some(path) => {
+                let format = self
+                    .export_format
+                    .unwrap_or_else(|| ExportFormat::from_path(path));
+                report::export(path, format, reports)
+            }
+            None => Ok(()),
+        }
+    }
+}
+
+/// What `--output` writes to its file.
+#[derive(Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+enum OutputContent {
+    /// Only the answers, one per line.
+    Answer,
+    /// The report as printed with the selected verbosity, without colors.
+    Report,
+}
+
+/// Options for writing the answers or the report to a file.
+#[derive(Args)]
+struct OutputArgs {
+    /// Writes the answers, or the report with `--output-format report`, to this file.
+    #[arg(long, value_name = "FILE")]
+    output: Option<String>,
+
+    /// What to write to the output file.
+    #[arg(long, value_enum, default_value_t = OutputContent::Answer, requires = "output")]
+    output_format: OutputContent,
+}
+
+impl OutputArgs {
+    /// Writes the reports to the output file if one was requested.
+    ///
+    /// # Parameters
+    /// - `options`: The options of the printed output; only the verbosity is used.
+    /// - `summary`: Whether the report is the summary table of `run-all --summary`.
+    fn apply(
+        &self,
+        reports: &[RunReport],
+        options: &OutputOptions,
+        summary: bool,
+    ) -> std::io::Result<()> {
+        let Some(path) = &self.output else {
+            return Ok(());
+        };
+        let options = OutputOptions {
+            verbosity: match self.output_format {
+                OutputContent::Answer => Verbosity::Quiet,
+                OutputContent::Report => options.verbosity,
+            },
+            color: ColorChoice::Never,
+            max_width: None,
+        };
+
+        let mut out: Vec<u8> = Vec::new();
+        if summary {
+            write_summary(&mut out, reports, &options)?;
+        } else {
+            for (index, report) in reports.iter().enumerate() {
+                if index > 0 && options.verbosity > Verbosity::Quiet {
+                    out.push(b'\n');
+                }
+                write_report(&mut out, report, &options)?;
+            }
+        }
+        fs::write(path, out)
+    }
+}
+
+/// Options for verifying answers against recorded ones.
+#[derive(Args)]
+struct VerifyArgs {
+    /// Verifies the answers against this answers file instead of the recorded ones.
+    #[arg(long, value_name = "FILE")]
+    answers: Option<String>,
+}
+
+impl RunArgs {
+    /// Returns the input path used for all puzzles, if not selected automatically.
+    fn input_path<'a>(&self, input_path: Option<&'a str>) -> Option<&'a str> {
+        if self.example {
+            Some(EXAMPLE_PATH)
+        } else {
+            input_path
+        }
+    }
+
+    /// Returns how the solvers are executed.
+    fn run_options(&self) -> RunOptions {
+        RunOptions {
+            timeout: self.timeout,
+            iterations: self.iterations,
+        }
+    }
+
+    /// Copies the answer of the last report to the clipboard if `--copy` was
+    /// given and the run succeeded.
+    ///
+    /// A missing clipboard only prints a warning, as the answer is printed anyway.
+    #[cfg(feature = "clipboard")]
+    fn copy_answer(&self, reports: &[RunReport], passed: bool, options: &OutputOptions) {
+        let Some(report) = reports.last().filter(|_| self.copy && passed) else {
+            return;
+        };
+        match clipboard::copy(&report.answer) {
+            Ok(()) if options.verbosity > Verbosity::Quiet => {
+                eprintln!("Copied {} to the clipboard", report.answer)
+            }
+            Ok(()) => {}
+            Err(err) => eprintln!("Could not copy the answer to the clipboard: {}", err),
+        }
+    }
+}
+
+impl VerifyArgs {
+    /// Loads the answers file, if one was given.
+    ///
+    /// Without one, the answers recorded for `input_path` are used, see
+    /// [`answers::recorded_answers`].
+    fn load(&self, input_path: Option<&str>) -> std::io::Result<Option<ExpectedAnswers>> {
+        match &self.answers {
+            Some(path) => ExpectedAnswers::load(path).map(Some),
+            None => answers::recorded_answers(input_path),
+        }
+    }
+}
+
+/// Options shared by all commands that run solvers and report their answers.
+#[derive(Args)]
+struct RunArgs {
+    /// Runs on the bundled example inputs and verifies against the example answers.
+    #[arg(long)]
+    example: bool,
+
+    /// Abandons every solver running longer than this, e.g. `30s` or `500ms`.
+    #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+    timeout: Option<Duration>,
+
+    /// Times every solver this many times after a warm-up run and reports
+    /// the median instead of a single cold run.
+    #[arg(long, value_name = "N")]
+    iterations: Option<NonZeroUsize>,
+
+    /// Does not record the runs in the run history.
+    #[arg(long)]
+    no_history: bool,
+
+    /// Copies the final answer to the clipboard after a successful run.
+    #[cfg(feature = "clipboard")]
+    #[arg(long)]
+    copy: bool,
+
+    #[command(flatten)]
+    verify: VerifyArgs,
+
+    #[command(flatten)]
+    export: ExportArgs,
+
+    #[command(flatten)]
+    output: OutputArgs,
+}
+
+/// Options of the `bench` command.
+#[derive(Args)]
+struct BenchArgs {
+    /// The day of the puzzle (1-25). Benchmarks every implemented puzzle if omitted.
+    #[arg(short, long)]
+    day: Option<i32>,
+
+    /// The part of the puzzle (1 or 2). Benchmarks both parts if omitted.
+    #[arg(short, long, requires = "day")]
+    part: Option<i32>,
+
+    /// Path or URL of the input file to use instead of the automatic selection.
+    #[arg(short, long, value_name = "FILE", requires = "day")]
+    input: Option<String>,
+
+    /// The number of measured iterations.
+    #[arg(short = 'n', long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+    iterations: u64,
+
+    /// The number of unmeasured iterations executed before measuring.
+    #[arg(long, default_value_t = 0)]
+    warmup: u64,
+
+    /// Saves the timings as a named baseline for later comparison.
+    #[arg(long, value_name = "NAME")]
+    save_baseline: Option<String>,
+
+    /// Compares the timings with a saved baseline and exits nonzero on regressions.
+    #[arg(long, value_name = "NAME")]
+    compare_baseline: Option<String>,
+
+    /// The slowdown of the mean, in percent, reported as a regression.
+    #[arg(long, default_value_t = 10.0, requires = "compare_baseline")]
+    threshold: f64,
+
+    /// Writes the solve time of every measured iteration to this file.
+    #[arg(long, value_name = "FILE")]
+    export_samples: Option<String>,
+
+    /// The format of the samples file. Guessed from the file extension if omitted.
+    #[arg(long, value_enum, requires = "export_samples")]
+    samples_format: Option<SampleFormat>,
+}
+
+/// The part selected with `run --part`.
+#[derive(Clone, Copy)]
+enum PartArg {
+    /// A single part, 1 or 2.
+    Single(i32),
+    /// Both parts, solved on one parse of the input.
+    Both,
+}
+
+/// Parses a `--part` value: a part number or `both`.
+fn parse_part(value: &str) -> Result<PartArg, String> {
+    if value.eq_ignore_ascii_case("both") {
+        return Ok(PartArg::Both);
+    }
+    value
+        .parse()
+        .map(PartArg::Single)
+        .map_err(|_| format!("invalid part '{}', use 1, 2 or both", value))
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Runs the solver for one day and part, or for every implemented puzzle.
+    Run {
+        /// The day of the puzzle (1-25).
+        #[arg(short, long, required_unless_present = "all")]
+        day: Option<i32>,
+
+        /// The part of the puzzle (1 or 2), or `both` to solve both parts on one parse of the input.
+        #[arg(short, long, required_unless_present = "all", value_parser = parse_part)]
+        part: Option<PartArg>,
+
+        /// Runs every implemented day and part, reading each input only once.
+        #[arg(long, conflicts_with_all = ["day", "part", "stdin", "input"])]
+        all: bool,
+
+        /// Reads the puzzle input from stdin instead of the inputs directory.
+        #[arg(long, conflicts_with_all = ["input", "example"])]
+        stdin: bool,
+
+        /// Path or URL of the input file to use instead of the automatic selection.
+        #[arg(short, long, value_name = "FILE", conflicts_with = "example")]
+        input: Option<String>,
+
+        /// The number of days to run in parallel with `--all`. Defaults to 1.
+        #[arg(short, long, conflicts_with_all = ["day", "part"])]
+        jobs: Option<NonZeroUsize>,
+
+        /// Verifies the answer against this value and exits nonzero on mismatch.
+        #[arg(long, value_name = "ANSWER", conflicts_with_all = ["all", "answers"])]
+        expect: Option<String>,
+
+        /// Animates the dial in the terminal before solving. Only available for day 1.
+        #[arg(long, conflicts_with_all = ["all", "stdin"])]
+        visualize: bool,
+
+        /// Writes a CSV row per rotation to this file before solving. Only available for day 1.
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["all", "stdin"])]
+        audit: Option<String>,
+
+        /// Reuses the sums of ranges solved before, cached in `.aoc/cache`. Only available for day 2.
+        #[arg(long, conflicts_with = "all")]
+        cache_ranges: bool,
+
+        /// Saves the progress to `.aoc/cache` and resumes an interrupted run from it. Only available for day 2.
+        #[arg(long, conflicts_with = "all")]
+        checkpoint: bool,
+
+        #[command(flatten)]
+        run: RunArgs,
+    },
+
+    /// Runs every implemented day and part and prints a summary table.
+    RunAll {
+        /// The number of days to run in parallel.
+        #[arg(short, long, default_value = "1")]
+        jobs: NonZeroUsize,
+
+        #[command(flatten)]
+        run: RunArgs,
+    },
+
+    /// Runs every puzzle input listed in a manifest and prints a pass/fail overview.
+    Batch {
+        /// The manifest file listing day, part, input, and expected answer of every run.
+        #[arg(value_name = "MANIFEST")]
+        manifest: String,
+
+        /// Abandons every solver running longer than this, e.g. `30s` or `500ms`.
+        #[arg(long, value_name = "DURATION", value_parser = parse_duration)]
+        timeout: Option<Duration>,
+    },
+
+    /// Runs solvers repeatedly and reports the min, mean and max solve time.
+    Bench(BenchArgs),
+
+    /// Checks the input of a day for malformed lines without solving it.
+    CheckInput {
+        /// The day of the puzzle (1-25).
+        #[arg(short, long)]
+        day: i32,
+
+        /// Path of the input file to check instead of the inputs of all parts.
+        #[arg(short, long, value_name = "FILE")]
+        input: Option<String>,
+    },
+
+    /// Downloads the puzzle input of a day into the inputs directory.
+    Fetch {
+        /// The day of the puzzle (1-25).
+        #[arg(short, long)]
+        day: i32,
+
+        /// Downloads the input again even if it already exists.
+        #[arg(long)]
+        force: bool,
+
+        /// Requests the input from the server even if it is cached, and reports
+        /// whether the stored file differed.
+        #[arg(long)]
+        refresh: bool,
+    },
+
+    /// Downloads the title and description of a day into the puzzles directory.
+    Puzzle {
+        /// The day of the puzzle (1-25).
+        #[arg(short, long)]
+        day: i32,
+    },
+
+    /// Generates a large random input of a day for stress-testing its solvers.
+    Gen {
+        /// The day of the puzzle (1-25).
+        #[arg(short, long)]
+        day: i32,
+
+        /// The scale of the input, e.g. the number of lines or the width of a grid.
+        #[arg(short, long, default_value_t = 1000)]
+        size: usize,
+
+        /// Selects one of the possible inputs; the same seed always produces the same input.
+        #[arg(long, default_value_t = generators::DEFAULT_SEED)]
+        seed: u64,
+
+        /// Writes the input to this file instead of stdout.
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<String>,
+    },
+
+    /// Shows the recorded runs of one puzzle, most recent last.
+    History {
+        /// The day of the puzzle (1-25).
+        #[arg(short, long)]
+        day: i32,
+
+        /// The part of the puzzle (1 or 2).
+        #[arg(short, long)]
+        part: i32,
+
+        /// The number of most recent runs to show.
+        #[arg(short = 'n', long, default_value_t = 10)]
+        limit: usize,
+    },
+
+    /// Lists the implemented days and parts with their inputs and expected answers.
+    List {
+        /// Answers file used to show which answers are recorded, instead of the recorded one.
+        #[arg(long, value_name = "FILE")]
+        answers: Option<String>,
+    },
+
+    /// Stores the session token in the system keyring, prompting for it without echo.
+    Login {
+        /// Removes the stored session token instead.
+        #[arg(long)]
+        remove: bool,
+    },
+
+    /// Opens an interactive dashboard showing every day of the event.
+    Dashboard {
+        /// Does not record runs started from the dashboard in the run history.
+        #[arg(long)]
+        no_history: bool,
+    },
+
+    /// Re-runs a solver whenever its input file changes.
+    Watch {
+        /// The day of the puzzle (1-25).
+        #[arg(short, long)]
+        day: i32,
+
+        /// The part of the puzzle (1 or 2).
+        #[arg(short, long)]
+        part: i32,
+
+        /// Path of the input file to use. Defaults to watching the whole inputs directory.
+        #[arg(short, long, value_name = "FILE")]
+        input: Option<String>,
+
+        /// Also watches `src/` and rebuilds the solver with cargo before every run.
+        #[arg(long)]
+        rebuild: bool,
+
+        /// How often to check for changes, in milliseconds.
+        #[arg(long, default_value_t = 500)]
+        interval: u64,
+    },
+
+    /// Serves `POST /solve/{day}/{part}` over HTTP, answering with JSON.
+    Serve {
+        /// The port to listen on.
+        #[arg(long, default_value_t = 8080)]
+        port: u16,
+
+        /// The address to listen on. Use `0.0.0.0` to accept remote requests.
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+    },
+
+    /// Creates the module, tests and input file of a new day from templates.
+    NewDay {
+        /// The day to create (1-25).
+        day: i32,
+    },
+}
+
+/// Entry point for the `aoc` command line interface.
+///
+/// Parses the command line, dispatches to the matching solver and runs it
+/// through the shared runner utilities. Errors are printed to stderr and
+/// reported with a nonzero exit code, as are answers failing verification.
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    init_logging();
+    match Config::load(CONFIG_PATH) {
+        Ok(config) => config::init(Config {
+            year: cli.year.unwrap_or(config.year),
+            ..config
+        }),
+        Err(err) => {
+            eprintln!("{}", err);
+            return ExitCode::FAILURE;
+        }
+    }
+    let options = OutputOptions {
+        verbosity: Verbosity::from_flags(cli.quiet, cli.verbose),
+        color: ColorChoice::resolve(cli.color),
+        max_width: terminal_width(),
+    };
+    enable_progress(&options);
+    check::set_enabled(!cli.no_validate);
+    if matches!(cli.command, Command::Run { .. } | Command::RunAll { .. })
+        && let Err(err) = cancel::install_ctrl_c_handler()
+    {
+        eprintln!("Ctrl-C cannot cancel the run: {}", err);
+    }
+
+    let outcome = match cli.command {
+        Command::Run {
+            all: true,
+            jobs,
+            run,
+            ..
+        } => command_run_all(jobs.map_or(1, NonZeroUsize::get), &run, &options, false),
+        Command::Run {
+            day,
+            part,
+            stdin,
+            input,
+            expect,
+            visualize,
+            audit,
+            cache_ranges,
+            checkpoint,
+            run,
+            ..
+        } => {
+            let input_path = if stdin {
+                Some(STDIN_PATH)
+            } else {
+                input.as_deref()
+            };
+            let day = day.unwrap_or_default();
+            let visualized = if visualize {
+                command_visualize(day, run.input_path(input_path), &options)
+            } else {
+                Ok(())
+            };
+            let audited = visualized.and_then(|()| match &audit {
+                Some(path) => command_audit(day, run.input_path(input_path), path),
+                None => Ok(()),
+            });
+            let cached = audited.and_then(|()| {
+                if cache_ranges {
+                    command_cache_ranges(day)
+                } else {
+                    Ok(())
+                }
+            });
+            let checkpointed = cached.and_then(|()| {
+                if checkpoint {
+                    command_checkpoint(day)
+                } else {
+                    Ok(())
+                }
+            });
+            checkpointed.and_then(|()| match part.unwrap_or(PartArg::Single(0)) {
+                PartArg::Both => command_run_both(day, input_path, expect, &run, &options),
+                PartArg::Single(part) => command_run(day, part, input_path, expect, &run, &options),
+            })
+        }
+        Command::RunAll { jobs, run } => command_run_all(jobs.get(), &run, &options, true),
+        Command::Batch { manifest, timeout } => command_batch(&manifest, timeout, &options),
+        Command::Bench(args) => command_bench(&args, &options),
+        Command::CheckInput { day, input } => command_check_input(day, input.as_deref(), &options),
+        Command::Fetch {
+            day,
+            force,
+            refresh,
+        } => command_fetch(day, force, refresh, &options),
+        Command::Puzzle { day } => command_puzzle(day, &options),
+        Command::Gen {
+            day,
+            size,
+            seed,
+            output,
+        } => command_gen(day, size, seed, output.as_deref(), &options),
+        Command::History { day, part, limit } => command_history(day, part, limit),
+        Command::List { answers } => command_list(answers.as_deref(), &options),
+        Command::Login { remove } => command_login(remove, &options),
+        Command::Dashboard { no_history } => dashboard::run(!no_history).map(|()| true),
+        Command::Watch {
+            day,
+            part,
+            input,
+            rebuild,
+            interval,
+        } => match solver(day, part) {
+            Some(solve) => {
+                watch_puzzle(day, part, solve, input, rebuild, interval, &options);
+                Ok(true)
+            }
+            None => Err(not_implemented(day, part)),
+        },
+        Command::Serve { port, host } => serve(&host, port).map(|()| true),
+        Command::NewDay { day } => command_new_day(day, &options),
+    };
+
+    if cancel::global().is_cancelled() {
+        eprintln!("Cancelled by Ctrl-C");
+        return ExitCode::from(cancel::EXIT_CODE);
+    }
+    match outcome {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprintln!("{}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs a single puzzle, printing and optionally verifying and exporting its report.
+///
+/// # Returns
+/// Whether the solver finished in time and its answer passed verification
+/// (always `true` without an expected answer).
+fn command_run(
+    day: i32,
+    part: i32,
+    input_path: Option<&str>,
+    expect: Option<String>,
+    run: &RunArgs,
+    options: &OutputOptions,
+) -> std::io::Result<bool> {
+    let solve = solver(day, part).ok_or_else(|| not_implemented(day, part))?;
+    let input_path = run.input_path(input_path);
+    let answers = run.verify.load(input_path)?;
+
+    let reports = run_day_with(day, &[(part, solve)], input_path, &run.run_options())?;
+    if !run.no_history {
+        history::record(&reports);
+    }
+    #[cfg(feature = "notify")]
+    notify::notify_slow(&reports);
+    reports
+        .iter()
+        .for_each(|report| print_report(report, options));
+    run.export.apply(&reports)?;
+    run.output.apply(&reports, options, false)?;
+
+    let expected: Option<String> = expect.or_else(|| Some(answers?.get(day, part)?.to_string()));
+    let passed = match expected {
+        Some(expected) => answers::verify(&reports[0], &expected, options),
+        None => reports[0].finished(),
+    };
+    #[cfg(feature = "clipboard")]
+    run.copy_answer(&reports, passed, options);
+    Ok(passed)
+}
+
+/// Runs both parts of a day on one parse of the input, see [`run_both`].
+///
+/// # Returns
+/// Whether both answers with a recorded expected answer passed verification.
+fn command_run_both(
+    day: i32,
+    input_path: Option<&str>,
+    expect: Option<String>,
+    run: &RunArgs,
+    options: &OutputOptions,
+) -> std::io::Result<bool> {
+    if expect.is_some() || run.timeout.is_some() || run.iterations.is_some() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "--expect, --timeout and --iterations need a single part, use --part 1 or --part 2",
+        ));
+    }
+    let solve = registry::both_parts(day).ok_or_else(|| {
+        std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            format!("Day {} does not have both parts implemented", day),
+        )
+    })?;
+    let input_path = run.input_path(input_path);
+    let answers = run.verify.load(input_path)?;
+
+    let reports = run_both(day, solve, input_path)?;
+    if !run.no_history {
+        history::record(&reports);
+    }
+    #[cfg(feature = "notify")]
+    notify::notify_slow(&reports);
+    for report in &reports {
+        print_report(report, options);
+        if options.verbosity > Verbosity::Quiet {
+            println!();
+        }
+    }
+    run.export.apply(&reports)?;
+    run.output.apply(&reports, options, false)?;
+
+    let mut passed = reports.iter().all(RunReport::finished);
+    if let Some(answers) = answers {
+        for report in &reports {
+            if let Some(expected) = answers.get(report.day, report.part) {
+                passed &= answers::verify(report, expected, options);
+            }
+        }
+    }
+    #[cfg(feature = "clipboard")]
+    run.copy_answer(&reports, passed, options);
+    Ok(passed)
+}
+
+/// Runs all puzzles, printing either every report or a summary table.
+///
+/// # Returns
+/// Whether all solvers finished in time and all answers with a recorded
+/// expected answer passed verification.
+fn command_run_all(
+    jobs: usize,
+    run: &RunArgs,
+    options: &OutputOptions,
+    summary: bool,
+) -> std::io::Result<bool> {
+    let answers = run.verify.load(run.input_path(None))?;
+    let reports = run_days(
+        &registry::days(),
+        run.input_path(None),
+        jobs,
+        &run.run_options(),
+    )?;
+    if !run.no_history {
+        history::record(&reports);
+    }
+    #[cfg(feature = "notify")]
+    notify::notify_slow(&reports);
+
+    if summary {
+        print_summary(&reports, options);
+    } else {
+        for report in &reports {
+            print_report(report, options);
+            if options.verbosity > Verbosity::Quiet {
+                println!();
+            }
+        }
+    }
+    run.export.apply(&reports)?;
+    run.output.apply(&reports, options, summary)?;
+
+    let mut passed = reports.iter().all(RunReport::finished);
+    if let Some(answers) = answers {
+        for report in &reports {
+            if let Some(expected) = answers.get(report.day, report.part) {
+                passed &= answers::verify(report, expected, options);
+            }
+        }
+    }
+    #[cfg(feature = "clipboard")]
+    run.copy_answer(&reports, passed, options);
+    Ok(passed)
+}
+
+/// Runs all entries of a batch manifest and prints the overview.
+///
+/// Batch runs use alternate inputs, so they are not recorded in the run history.
+fn command_batch(
+    path: &str,
+    timeout: Option<Duration>,
+    options: &OutputOptions,
+) -> std::io::Result<bool> {
+    let manifest = Manifest::load(path)?;
+    let outcomes = run_batch(&manifest, timeout);
+    Ok(print_batch(&manifest, &outcomes, options))
+}
+
+/// Benchmarks one or more puzzles, reading their inputs before measuring.
+///
+/// # Returns
+/// Whether no puzzle regressed compared to the baseline given with `--compare-baseline`.
+fn command_bench(args: &BenchArgs, options: &OutputOptions) -> std::io::Result<bool> {
+    let puzzles: Vec<(i32, i32, Solver)> = match (args.day, args.part) {
+        (Some(day), Some(part)) => {
+            let solve = solver(day, part).ok_or_else(|| not_implemented(day, part))?;
+            vec![(day, part, solve)]
+        }
+        (Some(day), None) => registry::parts(day)
+            .into_iter()
+            .map(|(part, solve)| (day, part, solve))
+            .collect(),
+        (None, _) => registry::solvers().to_vec(),
+    };
+    if puzzles.is_empty() {
+        return Err(not_implemented(args.day.unwrap_or_default(), 1));
+    }
+    // Fail early instead of after a long benchmark.
+    let baseline = args
+        .compare_baseline
+        .as_deref()
+        .map(load_baseline)
+        .transpose()?;
+
+    let mut reports: Vec<BenchReport> = Vec::new();
+    for (day, part, solve) in puzzles {
+        let (_, input) = load_input(day, part, args.input.as_deref())?;
+        let report = bench(
+            day,
+            part,
+            &input,
+            solve,
+            args.iterations as usize,
+            args.warmup as usize,
+        );
+        if !reports.is_empty() && options.verbosity > Verbosity::Quiet {
+            println!();
+        }
+        print_bench(&report, options);
+        reports.push(report);
+    }
+
+    if let Some(path) = &args.export_samples {
+        let format = args
+            .samples_format
+            .unwrap_or_else(|| SampleFormat::from_path(path));
+        export_samples(path, format, &reports)?;
+    }
+    if let Some(name) = &args.save_baseline {
+        save_baseline(name, &reports)?;
+        if options.verbosity > Verbosity::Quiet {
+            println!("\nSaved baseline '{}'", name);
+        }
+    }
+    match baseline {
+        Some(baseline) => {
+            if options.verbosity > Verbosity::Quiet {
+                println!();
+            }
+            let comparisons = compare(&reports, &baseline);
+            Ok(print_comparison(&comparisons, args.threshold, options))
+        }
+        None => Ok(true),
+    }
+}
+
+/// Checks the inputs of every implemented part of a day with the day's input checker.
+///
+/// Parts sharing the same input file are checked only once.
+///
+/// # Returns
+/// Whether all checked inputs are valid.
+fn command_check_input(
+    day: i32,
+    input_path: Option<&str>,
+    options: &OutputOptions,
+) -> std::io::Result<bool> {
+    let validate = registry::validator(day).ok_or_else(|| not_implemented(day, 1))?;
+
+    let mut checked: Vec<String> = Vec::new();
+    let mut passed = true;
+    for (part, _) in registry::parts(day) {
+        let (path, input) = load_input(day, part, input_path)?;
+        if checked.contains(&path) {
+            continue;
+        }
+        let diagnostics = validate(&input).err().unwrap_or_default();
+        passed &= print_check(&path, &diagnostics, options);
+        checked.push(path);
+    }
+    Ok(passed)
+}
+
+/// Animates the dial of day 1 performing the rotations of the input, see
+/// [`visualize::animate`](aoc2025::day01::visualize::animate).
+///
+/// # Returns
+/// An error for days other than 1, or if the input cannot be read or parsed.
+fn command_visualize(
+    day: i32,
+    input_path: Option<&str>,
+    options: &OutputOptions,
+) -> std::io::Result<()> {
+    #[cfg(feature = "day01")]
+    {
+        use aoc2025::day01::visualize;
+
+        let rotations = day01_rotations(day, input_path, "--visualize")?;
+        visualize::animate(
+            &rotations,
+            &mut std::io::stdout(),
+            visualize::FRAME_DELAY,
+            options.color.enabled(),
+            cancel::global(),
+        )
+    }
+    #[cfg(not(feature = "day01"))]
+    {
+        let _ = (input_path, options);
+        Err(day_only(1, day, "--visualize"))
+    }
+}
+
+/// Writes the audit log of the rotations of day 1 to `path`, see
+/// [`audit::write_audit`](aoc2025::day01::audit::write_audit).
+///
+/// # Returns
+/// An error for days other than 1, or if the input cannot be read or parsed
+/// or the file cannot be written.
+fn command_audit(day: i32, input_path: Option<&str>, path: &str) -> std::io::Result<()> {
+    #[cfg(feature = "day01")]
+    {
+        use aoc2025::day01::audit;
+
+        let rotations = day01_rotations(day, input_path, "--audit")?;
+        let mut file = std::io::BufWriter::new(fs::File::create(path)?);
+        audit::write_audit(&rotations, &mut file)
+    }
+    #[cfg(not(feature = "day01"))]
+    {
+        let _ = (input_path, path);
+        Err(day_only(1, day, "--audit"))
+    }
+}
+
+/// Makes day 2 reuse the range sums cached in
+/// [`CACHE_FILE`](aoc2025::day02::cache::CACHE_FILE), see
+/// [`cache::enable`](aoc2025::day02::cache::enable).
+///
+/// # Returns
+/// An error for days other than 2, or if the cache cannot be read.
+fn command_cache_ranges(day: i32) -> std::io::Result<()> {
+    if day != 2 {
+        return Err(day_only(2, day, "--cache-ranges"));
+    }
+    #[cfg(feature = "day02")]
+    {
+        use aoc2025::day02::cache;
+
+        cache::enable(cache::CACHE_FILE)
+    }
+    #[cfg(not(feature = "day02"))]
+    Ok(())
+}
+
+/// Makes day 2 save its progress to
+/// [`CHECKPOINT_FILE`](aoc2025::day02::checkpoint::CHECKPOINT_FILE) and resume
+/// from it, see [`checkpoint::enable`](aoc2025::day02::checkpoint::enable).
+///
+/// # Returns
+/// An error for days other than 2.
+fn command_checkpoint(day: i32) -> std::io::Result<()> {
+    if day != 2 {
+        return Err(day_only(2, day, "--checkpoint"));
+    }
+    #[cfg(feature = "day02")]
+    {
+        use aoc2025::day02::checkpoint;
+
+        checkpoint::enable(checkpoint::CHECKPOINT_FILE);
+    }
+    Ok(())
+}
+
+/// Reads and parses the rotations of day 1 for `flag`.
+///
+/// # Returns
+/// An error for days other than 1, or if the input cannot be read or parsed.
+#[cfg(feature = "day01")]
+fn day01_rotations(day: i32, input_path: Option<&str>, flag: &str) -> std::io::Result<Vec<i32>> {
+    if day != 1 {
+        return Err(day_only(1, day, flag));
+    }
+    let (_, input) = load_input(day, 1, input_path)?;
+    aoc2025::day01::parse_rotations(&input)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))
+}
+
+/// The error of a flag that only works for the day `only` used with another day.
+fn day_only(only: i32, day: i32, flag: &str) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        format!("{} is only available for day {}, not day {}", flag, only, day),
+    )
+}
+
+/// Downloads the input of a day unless it already exists or `force` or `refresh` is set.
+///
+/// With `refresh`, the input is requested from the server even if it is
+/// cached, and a stored file with different contents is reported.
+fn command_fetch(
+    day: i32,
+    force: bool,
+    refresh: bool,
+    options: &OutputOptions,
+) -> std::io::Result<bool> {
+    let config = config::current();
+    let path = fetch::input_path(config, day);
+    if !force && !refresh && Path::new(&path).exists() {
+        if options.verbosity > Verbosity::Quiet {
+            println!(
+                "Input '{}' already exists, use --force to download it again",
+                path
+            );
+        }
+        return Ok(true);
+    }
+
+    let (path, outcome) = fetch::fetch_input(config, day, refresh)?;
+    if options.verbosity > Verbosity::Quiet {
+        match outcome {
+            FetchOutcome::Created => {
+                println!("Downloaded the input of day {} to '{}'", day, path)
+            }
+            FetchOutcome::Unchanged => println!("Input '{}' is up to date", path),
+            FetchOutcome::Replaced => println!(
+                "Input '{}' differed from the downloaded input and was replaced",
+                path
+            ),
+        }
+    }
+    Ok(true)
+}
+
+/// Downloads and stores the description of a day.
+fn command_puzzle(day: i32, options: &OutputOptions) -> std::io::Result<bool> {
+    let (puzzle, path) = puzzle::fetch_puzzle(config::current(), day)?;
+    if options.verbosity > Verbosity::Quiet {
+        println!(
+            "Stored day {} ({}) with {} part(s) in '{}'",
+            puzzle.day,
+            puzzle.title,
+            puzzle.parts.len(),
+            path
+        );
+    }
+    Ok(true)
+}
+
+/// Creates the skeleton of a new day in the current directory and lists the written files.
+fn command_new_day(day: i32, options: &OutputOptions) -> std::io::Result<bool> {
+    let written = scaffold::new_day(Path::new("."), config::current(), day)?;
+    if options.verbosity > Verbosity::Quiet {
+        for path in written {
+            println!("Wrote '{}'", path.display());
+        }
+    }
+    Ok(true)
+}
+
+/// Generates a stress input of a day and writes it to `output`, or to stdout.
+fn command_gen(
+    day: i32,
+    size: usize,
+    seed: u64,
+    output: Option<&str>,
+    options: &OutputOptions,
+) -> std::io::Result<bool> {
+    let generate = registry::generator(day).ok_or_else(|| not_implemented(day, 1))?;
+    let input = generate(size, seed);
+    match output {
+        Some(path) => {
+            fs::write(path, &input)?;
+            if options.verbosity > Verbosity::Quiet {
+                println!(
+                    "Generated {} bytes for day {} in '{}'",
+                    input.len(),
+                    day,
+                    path
+                );
+            }
+        }
+        None => println!("{}", input),
+    }
+    Ok(true)
+}
+
+/// Stores the session token read from the terminal (or piped to stdin) in the
+/// system keyring, or removes the stored one.
+fn command_login(remove: bool, options: &OutputOptions) -> std::io::Result<bool> {
+    let quiet = options.verbosity == Verbosity::Quiet;
+    if remove {
+        let removed = aoc_client::remove_session_token()?;
+        if !quiet {
+            if removed {
+                println!("Removed the session token from the system keyring");
+            } else {
+                println!("No session token is stored in the system keyring");
+            }
+        }
+        return Ok(true);
+    }
+
+    let token = if atty::is(atty::Stream::Stdin) {
+        rpassword::prompt_password("Session token (value of the `session` cookie): ")?
+    } else {
+        let mut line = String::new();
+        std::io::stdin().read_line(&mut line)?;
+        line
+    };
+    if token.trim().is_empty() {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::InvalidInput,
+            "No session token given",
+        ));
+    }
+
+    aoc_client::store_session_token(&token)?;
+    if !quiet {
+        println!("Stored the session token in the system keyring");
+    }
+    Ok(true)
+}
+
+/// Prints the most recent recorded runs of one puzzle with the change in solve time.
+///
+/// Runs on a different input than the previous one are marked, since their
+/// timings are not comparable.
+fn command_history(day: i32, part: i32, limit: usize) -> std::io::Result<bool> {
+    let entries: Vec<HistoryEntry> = history::load(DEFAULT_HISTORY_PATH)?
+        .into_iter()
+        .filter(|entry| entry.day == day && entry.part == part)
+        .collect();
+    if entries.is_empty() {
+        println!("No recorded runs of day {} part {}", day, part);
+        return Ok(true);
+    }
+
+    println!(
+        "{:<19}  {:<16}  {:>12}  {:>8}  Answer",
+        "When (UTC)", "Input", "Solve", "Change"
+    );
+    let start = entries.len().saturating_sub(limit);
+    for (index, entry) in entries.iter().enumerate().skip(start) {
+        let change = match index.checked_sub(1).map(|previous| &entries[previous]) {
+            Some(previous) if previous.input_hash != entry.input_hash => "new input".to_string(),
+            Some(previous) if previous.solve_ms > 0.0 => {
+                format!(
+                    "{:+.1}%",
+                    (entry.solve_ms / previous.solve_ms - 1.0) * 100.0
+                )
+            }
+            _ => "-".to_string(),
+        };
+        println!(
+            "{:<19}  {:<16}  {:>9.3} ms  {:>8}  {}",
+            format_timestamp(entry.timestamp),
+            entry.input_hash,
+            entry.solve_ms,
+            change,
+            entry.answer
+        );
+    }
+    Ok(true)
+}
+
+/// Prints one line per part of every day that has at least one solver.
+///
+/// Each line shows the puzzle title, whether the part is implemented, which
+/// input file it would read, and whether an expected answer is recorded in
+/// `answers`, or without it in the recorded answers file. With `--verbose`,
+/// the complexity notes of every day follow its parts.
+fn command_list(answers: Option<&str>, options: &OutputOptions) -> std::io::Result<bool> {
+    let answers = match answers {
+        Some(path) => Some(ExpectedAnswers::load(path)?),
+        None => answers::recorded_answers(None)?,
+    };
+
+    println!(
+        "{:>3}  {:<20}  {:>4}  {:<11}  {:<24}  Answer",
+        "Day", "Title", "Part", "Solver", "Input"
+    );
+    for (day, _) in registry::days() {
+        let metadata = registry::metadata(day);
+        let title = metadata.map_or("-", |metadata| metadata.title);
+        for part in 1..=2 {
+            let implemented = if solver(day, part).is_some() {
+                "implemented"
+            } else {
+                "missing"
+            };
+            let input = find_input(day, part).unwrap_or_else(|| "-".to_string());
+            let answer = match &answers {
+                Some(answers) => answers.get(day, part).unwrap_or("-"),
+                None => "?",
+            };
+            println!(
+                "{:>3}  {:<20}  {:>4}  {:<11}  {:<24}  {}",
+                day, title, part, implemented, input, answer
+            );
+        }
+        if options.verbosity >= Verbosity::Verbose
+            && let Some(metadata) = metadata
+        {
+            println!("     {}", metadata.complexity);
+        }
+    }
+    Ok(true)
+}
+
+/// Builds the error reported for a day and part without a solver.
+fn not_implemented(day: i32, part: i32) -> std::io::Error {
+    std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        format!("Day {} part {} is not implemented", day, part),
+    )
+}
+
+/// Runs a puzzle every time its input (and, with `rebuild`, the sources) change.
+///
+/// Without `rebuild`, the solver compiled into this binary is executed directly.
+/// With `rebuild`, every run goes through `cargo run` so source changes are
+/// compiled first. Failing runs and solver panics are reported, but never stop
+/// the watcher.
+fn watch_puzzle(
+    day: i32,
+    part: i32,
+    solve: Solver,
+    input: Option<String>,
+    rebuild: bool,
+    interval: u64,
+    options: &OutputOptions,
+) {
+    let mut paths: Vec<PathBuf> = vec![PathBuf::from(
+        input.as_deref().unwrap_or(&config::current().input_dir),
+    )];
+    if rebuild {
+        paths.push(PathBuf::from("src"));
+    }
+
+    watch(&paths, Duration::from_millis(interval), || {
+        println!("\n=== Running day {} part {} ===", day, part);
+        if rebuild {
+            let mut command = process::Command::new("cargo");
+            command.args(["run", "--quiet", "--bin", "aoc", "--", "run"]);
+            command.args(["--day", &day.to_string(), "--part", &part.to_string()]);
+            if let Some(path) = &input {
+                command.args(["--input", path]);
+            }
+            match options.verbosity {
+                Verbosity::Quiet => command.arg("--quiet"),
+                Verbosity::Normal => &mut command,
+                Verbosity::Verbose => command.arg("-v"),
+                Verbosity::Debug => command.arg("-vv"),
+            };
+            // The child's stdout is inherited, so resolve `auto` against our own terminal.
+            let color = if options.color.enabled() {
+                "always"
+            } else {
+                "never"
+            };
+            command.args(["--color", color]);
+            if let Err(err) = command.status() {
+                eprintln!("Could not run cargo: {}", err);
+            }
+        } else {
+            let outcome = panic::catch_unwind(|| run_day(day, &[(part, solve)], input.as_deref()));
+            match outcome {
+                Ok(Ok(reports)) => reports
+                    .iter()
+                    .for_each(|report| print_report(report, options)),
+                Ok(Err(err)) => eprintln!("{}", err),
+                Err(_) => eprintln!("The solver panicked"),
+            }
+        }
+        println!("\nWatching {:?} for changes...", paths);
+        true
+    });
+}
@@ -0,0 +1,126 @@
+use std::fmt;
+use std::io;
+use std::sync::Arc;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The exit code of a run cancelled with Ctrl-C, as used by shells for SIGINT.
+pub const EXIT_CODE: u8 = 130;
+
+/// A flag asking long-running solvers to stop early.
+///
+/// Clones share the flag, so a token can be handed to a solver while another
+/// thread, e.g. a Ctrl-C handler, cancels it. Solvers check the token
+/// periodically with [`CancelToken::check`]; checking is a single atomic
+/// load and cheap enough for hot loops.
+///
+/// # Examples
+/// ```
+/// use aoc2025::cancel::{CancelToken, Cancelled};
+///
+/// let token = CancelToken::new();
+/// let handle = token.clone();
+/// assert_eq!(token.check(), Ok(()));
+/// handle.cancel();
+/// assert_eq!(token.check(), Err(Cancelled));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl CancelToken {
+    /// Creates a token that is not cancelled.
+    pub fn new() -> CancelToken {
+        CancelToken::default()
+    }
+
+    /// Cancels the token and all of its clones. Cancelling twice has no further effect.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Returns whether the token was cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
+    }
+
+    /// Returns [`Cancelled`] if the token was cancelled, so solvers can stop with `?`.
+    pub fn check(&self) -> Result<(), Cancelled> {
+        if self.is_cancelled() {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// The error of a solver that stopped early because its [`CancelToken`] was cancelled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+impl From<Cancelled> for io::Error {
+    fn from(err: Cancelled) -> io::Error {
+        io::Error::new(io::ErrorKind::Interrupted, err)
+    }
+}
+
+/// Returns the token of the whole process, cancelled by Ctrl-C once
+/// [`install_ctrl_c_handler`] was called.
+///
+/// The solvers registered for the runner check this token, since their
+/// signature `fn(&str) -> Answer` has no room for one.
+pub fn global() -> &'static CancelToken {
+    static GLOBAL: OnceLock<CancelToken> = OnceLock::new();
+    GLOBAL.get_or_init(CancelToken::new)
+}
+
+/// Makes Ctrl-C cancel the [`global`] token instead of killing the process.
+///
+/// The running solver stops at its next check (or finishes, if it never
+/// checks), no further solvers are started, and the reports collected so far
+/// are printed. A second Ctrl-C exits immediately with [`EXIT_CODE`].
+///
+/// # Returns
+/// An error if another handler is already installed.
+#[cfg(feature = "cli")]
+pub fn install_ctrl_c_handler() -> io::Result<()> {
+    ctrlc::set_handler(|| {
+        if global().is_cancelled() {
+            std::process::exit(EXIT_CODE.into());
+        }
+        eprintln!("\nCancelling, press Ctrl-C again to exit immediately");
+        global().cancel();
+    })
+    .map_err(io::Error::other)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cancel_is_shared_between_clones() {
+        let token = CancelToken::new();
+        let clone = token.clone();
+        assert!(!clone.is_cancelled());
+        token.cancel();
+        assert!(clone.is_cancelled());
+        assert_eq!(clone.check(), Err(Cancelled));
+    }
+
+    #[test]
+    fn test_cancelled_into_io_error() {
+        let err = io::Error::from(Cancelled);
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert_eq!(err.to_string(), "cancelled");
+    }
+}
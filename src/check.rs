@@ -0,0 +1,331 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use crate::error::AocError;
+use crate::registry;
+#[cfg(feature = "cli")]
+use crate::utils::{OutputOptions, Verbosity};
+
+/// Whether the runner validates inputs before solving them, see [`set_enabled`].
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// A problem found in a puzzle input by a day's input validator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// The one-based line number of the problem, or `None` if it concerns the whole input.
+    pub line: Option<usize>,
+    /// The one-based column within the line, if the problem has a precise position.
+    pub column: Option<usize>,
+    /// What is wrong with the input.
+    pub message: String,
+}
+
+impl Diagnostic {
+    /// Creates a diagnostic for the one-based line `line`.
+    pub fn at(line: usize, message: impl Into<String>) -> Diagnostic {
+        Diagnostic {
+            line: Some(line),
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Creates a diagnostic for the one-based `column` of the one-based `line`.
+    pub fn at_column(line: usize, column: usize, message: impl Into<String>) -> Diagnostic {
+        Diagnostic {
+            line: Some(line),
+            column: Some(column),
+            message: message.into(),
+        }
+    }
+
+    /// Creates a diagnostic concerning the whole input.
+    pub fn input(message: impl Into<String>) -> Diagnostic {
+        Diagnostic {
+            line: None,
+            column: None,
+            message: message.into(),
+        }
+    }
+
+    /// Returns the position as `line:column` suffix for a path, e.g. `":3:7"`.
+    pub(crate) fn location(&self) -> String {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => format!(":{}:{}", line, column),
+            (Some(line), None) => format!(":{}", line),
+            _ => String::new(),
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.line, self.column) {
+            (Some(line), Some(column)) => {
+                write!(f, "line {}, column {}: {}", line, column, self.message)
+            }
+            (Some(line), None) => write!(f, "line {}: {}", line, self.message),
+            _ => write!(f, "{}", self.message),
+        }
+    }
+}
+
+/// An input validator checking the structure of the puzzle input of one day without solving it.
+///
+/// Returns every problem found, in input order. `Ok` means the solvers of
+/// that day can parse the input.
+pub type Validator = fn(&str) -> Result<(), Vec<Diagnostic>>;
+
+/// Converts collected diagnostics into the result of a [`Validator`].
+pub fn into_result(diagnostics: Vec<Diagnostic>) -> Result<(), Vec<Diagnostic>> {
+    if diagnostics.is_empty() {
+        Ok(())
+    } else {
+        Err(diagnostics)
+    }
+}
+
+/// Checks every line of `input` with `check_line`.
+///
+/// Lines are split at `'\n'` exactly like the solvers splitting their input
+/// with `split("\n")` do, so a trailing line break shows up as an empty last
+/// line. A trailing `'\r'` is not removed either.
+///
+/// # Parameters
+/// - `check_line`: Returns the one-based column and a message describing the
+///   problem of a line, if any.
+///
+/// # Examples
+/// ```
+/// use aoc2025::check::{Diagnostic, check_lines};
+///
+/// let result = check_lines("1\nx\n3", |line| match line.parse::<i32>() {
+///     Ok(_) => None,
+///     Err(_) => Some((1, format!("expected a number, found '{}'", line))),
+/// });
+/// assert_eq!(result, Err(vec![Diagnostic::at_column(2, 1, "expected a number, found 'x'")]));
+/// ```
+pub fn check_lines<F>(input: &str, mut check_line: F) -> Result<(), Vec<Diagnostic>>
+where
+    F: FnMut(&str) -> Option<(usize, String)>,
+{
+    into_result(
+        input
+            .split('\n')
+            .enumerate()
+            .filter_map(|(index, line)| {
+                check_line(line)
+                    .map(|(column, message)| Diagnostic::at_column(index + 1, column, message))
+            })
+            .collect(),
+    )
+}
+
+/// Returns whether `value` is a non-empty string of ASCII digits fitting into an `i64`.
+pub fn is_number(value: &str) -> bool {
+    !value.is_empty()
+        && value.bytes().all(|byte| byte.is_ascii_digit())
+        && value.parse::<i64>().is_ok()
+}
+
+/// Checks a range written as `"start-end"` with `start <= end`, both IDs
+/// fitting into `T`.
+///
+/// # Returns
+/// A message describing the problem, or `None` if the range is valid.
+pub fn check_range<T: FromStr + PartialOrd>(range: &str) -> Option<String> {
+    let digits = |value: &str| !value.is_empty() && value.bytes().all(|byte| byte.is_ascii_digit());
+    let Some((start, end)) = range
+        .split_once('-')
+        .filter(|(start, end)| digits(start) && digits(end))
+    else {
+        return Some(format!("expected a range like '3-5', found '{}'", range));
+    };
+    let (Ok(start), Ok(end)) = (start.parse::<T>(), end.parse::<T>()) else {
+        return Some(format!(
+            "range '{}' exceeds the largest supported ID",
+            range
+        ));
+    };
+    if start > end {
+        return Some(format!("range '{}' ends before it starts", range));
+    }
+    None
+}
+
+/// Prints the result of checking one input file to stdout.
+///
+/// Every problem is printed on its own line prefixed with the path, like
+/// compiler diagnostics. A valid input is only mentioned above
+/// [`Verbosity::Quiet`].
+///
+/// # Returns
+/// `true` if no problems were found.
+#[cfg(feature = "cli")]
+pub fn print_check(path: &str, diagnostics: &[Diagnostic], options: &OutputOptions) -> bool {
+    let use_color = options.color.enabled();
+
+    if diagnostics.is_empty() {
+        if options.verbosity > Verbosity::Quiet {
+            if use_color {
+                println!("\x1b[32m{}: OK\x1b[0m", path);
+            } else {
+                println!("{}: OK", path);
+            }
+        }
+        return true;
+    }
+
+    for diagnostic in diagnostics {
+        let location = format!("{}{}", path, diagnostic.location());
+        if use_color {
+            println!("\x1b[31m{}:\x1b[0m {}", location, diagnostic.message);
+        } else {
+            println!("{}: {}", location, diagnostic.message);
+        }
+    }
+    false
+}
+
+/// Turns validating inputs before solving them on or off for the whole process.
+///
+/// Validation is disabled by default, so library users and tests can run
+/// solvers on arbitrary inputs. The binaries enable it unless `--no-validate`
+/// is given.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Validates the input of a day before it is solved, if enabled by [`set_enabled`].
+///
+/// # Returns
+/// The result of [`validate_input`], or `Ok` if validation is disabled.
+pub fn validate_before_solving(day: i32, path: &str, input: &str) -> Result<(), AocError> {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+    validate_input(day, path, input)
+}
+
+/// Validates the input of a day with the validator of the day.
+///
+/// Days without a validator are accepted as is.
+///
+/// # Returns
+/// An [`AocError::Parse`] with every problem if the input is malformed,
+/// instead of letting a solver panic on it.
+pub fn validate_input(day: i32, path: &str, input: &str) -> Result<(), AocError> {
+    let Some(validate) = registry::validator(day) else {
+        return Ok(());
+    };
+    validate(input).map_err(|diagnostics| AocError::Parse {
+        day,
+        path: path.to_string(),
+        diagnostics,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_range() {
+        assert_eq!(check_range::<i64>("3-5"), None);
+        assert_eq!(check_range::<i64>("5-5"), None);
+        assert_eq!(
+            check_range::<i64>("5-3"),
+            Some("range '5-3' ends before it starts".to_string())
+        );
+        assert!(check_range::<i64>("3").is_some());
+        assert!(check_range::<i64>("-5").is_some());
+        assert!(check_range::<i64>("a-5").is_some());
+        assert!(check_range::<i64>("3-5\r").is_some());
+        let huge = format!("1-{}", u128::MAX);
+        assert_eq!(
+            check_range::<i64>(&huge),
+            Some(format!("range '{}' exceeds the largest supported ID", huge))
+        );
+        assert_eq!(check_range::<u128>(&huge), None);
+    }
+
+    #[test]
+    #[cfg(feature = "all-days")]
+    fn test_day_validators() {
+        use crate::{day01, day02, day03, day04, day05, day06};
+
+        assert!(day01::validate("L68\nR48").is_ok());
+        assert_eq!(
+            day01::validate("L68\nX1\n").unwrap_err()[0],
+            Diagnostic::at_column(2, 1, "expected a rotation like 'R5' or 'L12', found 'X1'")
+        );
+        assert!(day02::validate("11-22,95-115").is_ok());
+        assert!(day02::validate("11-22,95-115\n").is_ok());
+        assert_eq!(
+            day02::validate("11-22,95-9").unwrap_err()[0],
+            Diagnostic::at_column(1, 7, "range '95-9': ends before it starts")
+        );
+        assert!(day03::validate("987654321111111").is_ok());
+        assert_eq!(
+            day03::validate("987654321111111\n12x4").unwrap_err()[0],
+            Diagnostic::at_column(2, 3, "expected only digits, found 'x'")
+        );
+        assert!(day04::validate("..@@\n@@@.\n").is_ok());
+        assert_eq!(
+            day04::validate("..@@\n@x@.").unwrap_err()[0],
+            Diagnostic::at_column(2, 2, "expected only '@' and '.', found 'x'")
+        );
+        assert!(day05::validate("3-5\n10-14\n\n1\n5\n").is_ok());
+        assert_eq!(
+            day05::validate("3-5\n1\n"),
+            Err(vec![Diagnostic::input(
+                "expected an empty line between the ranges and the IDs"
+            )])
+        );
+        assert_eq!(
+            day05::validate("3-5\n\n1\nfoo").unwrap_err()[0].line,
+            Some(4)
+        );
+        assert!(day06::validate("123 328\n 45 64 \n*   +  \n").is_ok());
+        assert_eq!(
+            day06::validate("123 328\n 45\n*   +  ").unwrap_err()[0].line,
+            Some(2)
+        );
+        assert_eq!(
+            day06::validate("123 328\n*   -  ").unwrap_err()[0],
+            Diagnostic::at_column(2, 5, "expected only '+' and '*' operators, found '-'")
+        );
+        assert_eq!(
+            day06::validate("1 9999999999\n2 9999999999\n+ *         ").unwrap_err(),
+            vec![
+                Diagnostic::at_column(3, 3, "part 1: the result of problem 2 overflows i64"),
+                Diagnostic::at_column(3, 3, "part 2: the result of problem 2 overflows i64"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Diagnostic::at(3, "bad").to_string(), "line 3: bad");
+        assert_eq!(
+            Diagnostic::at_column(3, 4, "bad").to_string(),
+            "line 3, column 4: bad"
+        );
+        assert_eq!(Diagnostic::input("empty").to_string(), "empty");
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn test_validate_input() {
+        assert!(validate_before_solving(1, "in.txt", "L68\nX1").is_ok());
+        assert!(validate_input(1, "in.txt", "L68\nR48").is_ok());
+        assert!(validate_input(25, "in.txt", "anything").is_ok());
+
+        let err = validate_input(1, "in.txt", "L68\nX1\nX2\nX3\nX4\nX5\nX6").unwrap_err();
+        assert!(matches!(&err, AocError::Parse { diagnostics, .. } if diagnostics.len() == 6));
+        let message = err.to_string();
+        assert!(message.contains("in.txt:2:1: expected a rotation"));
+        assert!(message.contains("and 1 more"));
+    }
+}
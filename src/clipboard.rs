@@ -0,0 +1,16 @@
+use std::io;
+
+use arboard::Clipboard;
+
+/// Places `text` on the system clipboard, replacing its contents.
+///
+/// On X11 and Wayland the clipboard is owned by the process that set it, so
+/// the text only outlives the process if a clipboard manager takes it over,
+/// which desktop environments usually run.
+///
+/// # Returns
+/// An error if no clipboard is available, e.g. over SSH without a display.
+pub fn copy(text: &str) -> io::Result<()> {
+    let mut clipboard = Clipboard::new().map_err(io::Error::other)?;
+    clipboard.set_text(text).map_err(io::Error::other)
+}
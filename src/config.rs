@@ -0,0 +1,380 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::sync::OnceLock;
+use std::time::Duration;
+
+use clap::ValueEnum;
+use tracing::warn;
+
+use crate::utils::{ColorChoice, parse_duration};
+
+/// The configuration file read from the project root.
+pub const CONFIG_PATH: &str = "aoc.toml";
+
+/// The directory puzzle inputs are read from unless configured otherwise.
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+
+/// The environment variable overriding the configured input directory.
+pub const INPUT_DIR_ENV: &str = "AOC_INPUT_DIR";
+
+/// The environment variable overriding the configured color choice.
+pub const COLOR_ENV: &str = "AOC_COLOR";
+
+/// The environment variable overriding the configured year.
+pub const YEAR_ENV: &str = "AOC_YEAR";
+
+/// The event year unless configured otherwise.
+pub const DEFAULT_YEAR: i32 = crate::api::YEAR;
+
+/// The file the Advent of Code session token is read from unless configured otherwise.
+pub const DEFAULT_SESSION_FILE: &str = ".aoc/session";
+
+/// The solve time after which a desktop notification is shown unless configured otherwise.
+pub const DEFAULT_NOTIFY_AFTER: Duration = Duration::from_secs(10);
+
+/// The configuration in use, see [`current`].
+static CURRENT: OnceLock<Config> = OnceLock::new();
+
+/// Settings overriding the defaults for a single day.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DayConfig {
+    /// The input file used for both parts instead of the automatic selection.
+    pub input: Option<String>,
+}
+
+/// Project settings read from [`CONFIG_PATH`].
+///
+/// Settings are taken from the command line flags, then the environment
+/// variables (see [`Config::load`]), then the file, then the defaults. Every
+/// setting of the file is optional:
+///
+/// ```toml
+/// input_dir = "puzzle-inputs"
+/// color = "never"
+/// year = 2025
+/// session_file = "/home/me/.config/aoc/session"
+/// timings_file = "timings.csv"
+/// notify_after = "1m"
+///
+/// [day05]
+/// input = "puzzle-inputs/day05_large.txt"
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Config {
+    /// The directory inputs are selected from, see [`DEFAULT_INPUT_DIR`].
+    pub input_dir: String,
+    /// When to use colored output if `--color` is not given.
+    pub color: Option<ColorChoice>,
+    /// The year of the event.
+    pub year: i32,
+    /// The file containing the session token, see [`DEFAULT_SESSION_FILE`].
+    pub session_file: String,
+    /// The CSV file every recorded run appends its solve time to, if any, see
+    /// [`history::append_timings`](crate::history::append_timings).
+    pub timings_file: Option<String>,
+    /// The solve time after which a desktop notification is shown with the
+    /// `notify` feature, see [`DEFAULT_NOTIFY_AFTER`].
+    pub notify_after: Duration,
+    /// Overrides per day.
+    pub days: BTreeMap<i32, DayConfig>,
+}
+
+impl Default for Config {
+    fn default() -> Config {
+        Config {
+            input_dir: DEFAULT_INPUT_DIR.to_string(),
+            color: None,
+            year: DEFAULT_YEAR,
+            session_file: DEFAULT_SESSION_FILE.to_string(),
+            timings_file: None,
+            notify_after: DEFAULT_NOTIFY_AFTER,
+            days: BTreeMap::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Parses the configuration from the contents of a configuration file.
+    ///
+    /// # Returns
+    /// The parsed configuration, or a message describing why the file is invalid.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::config::Config;
+    ///
+    /// let config = Config::parse("input_dir = \"data\"\n[day05]\ninput = \"data/big.txt\"").unwrap();
+    /// assert_eq!(config.input_dir, "data");
+    /// assert_eq!(config.day_input(5), Some("data/big.txt"));
+    /// assert_eq!(config.year, 2025);
+    /// ```
+    pub fn parse(content: &str) -> Result<Config, String> {
+        let table: toml::Table = content.parse().map_err(|err| format!("{}", err))?;
+
+        let mut config = Config::default();
+        for (key, value) in &table {
+            match key.as_str() {
+                "input_dir" => config.input_dir = string(key, value)?,
+                "session_file" => config.session_file = string(key, value)?,
+                "timings_file" => config.timings_file = Some(string(key, value)?),
+                "notify_after" => {
+                    config.notify_after = parse_duration(&string(key, value)?).map_err(|err| {
+                        format!("'notify_after' must be a duration like 30s, {}", err)
+                    })?;
+                }
+                "color" => {
+                    let choice = string(key, value)?;
+                    config.color = Some(ColorChoice::from_str(&choice, true).map_err(|_| {
+                        format!("'color' must be auto, always or never, found '{}'", choice)
+                    })?);
+                }
+                "year" => {
+                    config.year = value
+                        .as_integer()
+                        .and_then(|year| i32::try_from(year).ok())
+                        .ok_or_else(|| "'year' must be an integer".to_string())?;
+                }
+                _ => {
+                    let day = key
+                        .strip_prefix("day")
+                        .and_then(|number| number.parse().ok())
+                        .ok_or_else(|| format!("unknown setting '{}'", key))?;
+                    config.days.insert(day, parse_day(key, value)?);
+                }
+            }
+        }
+
+        Ok(config)
+    }
+
+    /// Reads and parses a configuration file, then applies the environment overrides.
+    ///
+    /// A missing file is treated as an empty configuration. Non-empty
+    /// [`INPUT_DIR_ENV`], [`COLOR_ENV`] and [`YEAR_ENV`] variables take
+    /// precedence over the `input_dir`, `color` and `year` of the file. The
+    /// session token has its own lookup, see
+    /// [`SESSION_ENV`](crate::aoc_client::SESSION_ENV).
+    ///
+    /// # Returns
+    /// The parsed configuration, or an I/O error if the file cannot be read or
+    /// it or an environment variable is invalid.
+    pub fn load(path: &str) -> io::Result<Config> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+        let config = Config::parse(&content).map_err(|message| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid configuration file '{}': {}", path, message),
+            )
+        })?;
+        config
+            .with_env(|name| std::env::var(name).ok())
+            .map_err(|message| io::Error::new(io::ErrorKind::InvalidData, message))
+    }
+
+    /// Applies the environment overrides of [`Config::load`].
+    ///
+    /// # Parameters
+    /// - `var`: Returns the value of an environment variable, if set.
+    ///
+    /// # Returns
+    /// The configuration with the overrides, or a message naming the invalid variable.
+    fn with_env(mut self, var: impl Fn(&str) -> Option<String>) -> Result<Config, String> {
+        let var = |name: &str| var(name).filter(|value| !value.is_empty());
+        if let Some(input_dir) = var(INPUT_DIR_ENV) {
+            self.input_dir = input_dir;
+        }
+        if let Some(choice) = var(COLOR_ENV) {
+            self.color = Some(ColorChoice::from_str(&choice, true).map_err(|_| {
+                format!(
+                    "{} must be auto, always or never, found '{}'",
+                    COLOR_ENV, choice
+                )
+            })?);
+        }
+        if let Some(year) = var(YEAR_ENV) {
+            self.year = year
+                .trim()
+                .parse()
+                .map_err(|_| format!("{} must be a year, found '{}'", YEAR_ENV, year))?;
+        }
+        Ok(self)
+    }
+
+    /// Returns the input file configured for a day, if any.
+    pub fn day_input(&self, day: i32) -> Option<&str> {
+        self.days.get(&day)?.input.as_deref()
+    }
+
+    /// Returns the directory the inputs of the configured year are stored in, e.g. `inputs/2025`.
+    pub fn year_input_dir(&self) -> String {
+        format!("{}/{}", self.input_dir, self.year)
+    }
+
+    /// Returns the directories inputs are selected from, in order of preference.
+    ///
+    /// Inputs live in [`Config::year_input_dir`]. For [`DEFAULT_YEAR`], the
+    /// input directory itself is searched as well, where inputs were stored
+    /// before other years were supported.
+    pub fn input_dirs(&self) -> Vec<String> {
+        let mut dirs = vec![self.year_input_dir()];
+        if self.year == DEFAULT_YEAR {
+            dirs.push(self.input_dir.clone());
+        }
+        dirs
+    }
+
+    /// Returns the directories bundled example inputs are read from, in order of preference.
+    ///
+    /// These are the `examples` subdirectories of [`Config::input_dirs`].
+    pub fn examples_dirs(&self) -> Vec<String> {
+        self.input_dirs()
+            .into_iter()
+            .map(|dir| format!("{}/examples", dir))
+            .collect()
+    }
+}
+
+/// Parses the table of overrides of one day.
+fn parse_day(key: &str, value: &toml::Value) -> Result<DayConfig, String> {
+    let table = value
+        .as_table()
+        .ok_or_else(|| format!("'{}' must be a table of settings", key))?;
+
+    let mut day = DayConfig::default();
+    for (setting, value) in table {
+        match setting.as_str() {
+            "input" => day.input = Some(string(&format!("{}.input", key), value)?),
+            _ => return Err(format!("unknown setting '{}.{}'", key, setting)),
+        }
+    }
+    Ok(day)
+}
+
+/// Returns the value of a setting that must be a string.
+fn string(key: &str, value: &toml::Value) -> Result<String, String> {
+    value
+        .as_str()
+        .map(str::to_string)
+        .ok_or_else(|| format!("'{}' must be a string", key))
+}
+
+/// Makes `config` the configuration returned by [`current`].
+///
+/// Has no effect if a configuration is already in use, so the binaries call
+/// this once at startup before running anything.
+pub fn init(config: Config) {
+    let _ = CURRENT.set(config);
+}
+
+/// Returns the configuration in use.
+///
+/// Unless [`init`] was called before, [`CONFIG_PATH`] is loaded on first use.
+/// An invalid file is reported as a warning and the defaults are used instead.
+pub fn current() -> &'static Config {
+    CURRENT.get_or_init(|| {
+        Config::load(CONFIG_PATH).unwrap_or_else(|err| {
+            warn!("{}", err);
+            Config::default()
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_empty() {
+        assert_eq!(Config::parse("").unwrap(), Config::default());
+    }
+
+    #[test]
+    fn test_parse_all_settings() {
+        let config = Config::parse(
+            "input_dir = \"data\"\ncolor = \"Never\"\nyear = 2024\nsession_file = \"token\"\n\
+             timings_file = \"times.csv\"\nnotify_after = \"90s\"\n\
+             [day07]\ninput = \"data/alt.txt\"",
+        )
+        .unwrap();
+        assert_eq!(config.input_dir, "data");
+        assert_eq!(config.color, Some(ColorChoice::Never));
+        assert_eq!(config.year, 2024);
+        assert_eq!(config.session_file, "token");
+        assert_eq!(config.timings_file.as_deref(), Some("times.csv"));
+        assert_eq!(config.notify_after, Duration::from_secs(90));
+        assert_eq!(config.day_input(7), Some("data/alt.txt"));
+        assert_eq!(config.day_input(8), None);
+        assert_eq!(config.input_dirs(), vec!["data/2024"]);
+        assert_eq!(config.examples_dirs(), vec!["data/2024/examples"]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert_eq!(
+            Config::parse("inputs = \"x\""),
+            Err("unknown setting 'inputs'".to_string())
+        );
+        assert!(Config::parse("color = \"sometimes\"").is_err());
+        assert!(Config::parse("year = \"2025\"").is_err());
+        assert!(Config::parse("notify_after = \"soon\"").is_err());
+        assert!(Config::parse("[day05]\nanswer = 1").is_err());
+        assert!(Config::parse("day05 = \"x\"").is_err());
+    }
+
+    #[test]
+    fn test_input_dirs_default_year() {
+        let config = Config::default();
+        assert_eq!(config.input_dirs(), vec!["inputs/2025", "inputs"]);
+        assert_eq!(
+            config.examples_dirs(),
+            vec!["inputs/2025/examples", "inputs/examples"]
+        );
+    }
+
+    #[test]
+    fn test_with_env() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(var, _)| *var == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+        let config = Config::parse("input_dir = \"data\"\ncolor = \"never\"\nyear = 2024").unwrap();
+
+        assert_eq!(config.clone().with_env(env(&[])), Ok(config.clone()));
+        let empty = env(&[(INPUT_DIR_ENV, ""), (COLOR_ENV, ""), (YEAR_ENV, "")]);
+        assert_eq!(config.clone().with_env(empty), Ok(config.clone()));
+
+        let overridden = config
+            .clone()
+            .with_env(env(&[
+                (INPUT_DIR_ENV, "../private-inputs"),
+                (COLOR_ENV, "Always"),
+                (YEAR_ENV, "2023"),
+            ]))
+            .unwrap();
+        assert_eq!(overridden.input_dir, "../private-inputs");
+        assert_eq!(overridden.color, Some(ColorChoice::Always));
+        assert_eq!(overridden.year, 2023);
+
+        assert_eq!(
+            config.clone().with_env(env(&[(COLOR_ENV, "sometimes")])),
+            Err("AOC_COLOR must be auto, always or never, found 'sometimes'".to_string())
+        );
+        assert!(config.with_env(env(&[(YEAR_ENV, "next")])).is_err());
+    }
+
+    #[test]
+    fn test_load_missing() {
+        assert_eq!(
+            Config::load("does/not/exist.toml").unwrap(),
+            Config::default()
+        );
+    }
+}
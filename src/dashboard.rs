@@ -0,0 +1,323 @@
+use std::io;
+use std::panic;
+use std::process::Command;
+
+use ratatui::DefaultTerminal;
+use ratatui::Frame;
+use ratatui::crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Cell, Paragraph, Row, Table, TableState};
+
+use crate::config;
+use crate::history::{self, HistoryEntry};
+use crate::progress;
+use crate::registry;
+use crate::utils::{RunReport, find_input, run_day};
+
+/// The number of puzzle days of the event.
+const DAYS: i32 = 25;
+
+/// The last known outcome of one part of a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartStatus {
+    /// Whether a solver for this part exists.
+    pub implemented: bool,
+    /// The last answer, from this session or the run history.
+    pub answer: Option<String>,
+    /// The solve time of the last answer, in milliseconds.
+    pub solve_ms: Option<f64>,
+}
+
+/// One row of the dashboard.
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayStatus {
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The status of part 1 and part 2.
+    pub parts: [PartStatus; 2],
+}
+
+/// The state of the interactive dashboard showing every day of the event.
+pub struct Dashboard {
+    /// One row per day, from day 1 to day 25.
+    pub days: Vec<DayStatus>,
+    /// Whether runs started from the dashboard are recorded in the run history.
+    pub record_history: bool,
+    table: TableState,
+    status: String,
+}
+
+impl Dashboard {
+    /// Creates the dashboard state, showing the most recent answer of every
+    /// part found in `history`.
+    pub fn new(history: &[HistoryEntry], record_history: bool) -> Dashboard {
+        let days = (1..=DAYS)
+            .map(|day| DayStatus {
+                day,
+                parts: [1, 2].map(|part| {
+                    let last = history
+                        .iter()
+                        .rev()
+                        .find(|entry| entry.day == day && entry.part == part);
+                    PartStatus {
+                        implemented: registry::solver(day, part).is_some(),
+                        answer: last.map(|entry| entry.answer.clone()),
+                        solve_ms: last.map(|entry| entry.solve_ms),
+                    }
+                }),
+            })
+            .collect();
+
+        Dashboard {
+            days,
+            record_history,
+            table: TableState::default().with_selected(Some(0)),
+            status: "Select a day with ↑/↓, r: run, o: open input, q: quit".to_string(),
+        }
+    }
+
+    /// Returns the day number of the selected row.
+    pub fn selected_day(&self) -> i32 {
+        self.table.selected().unwrap_or(0) as i32 + 1
+    }
+
+    /// Moves the selection by `offset` rows, stopping at the first and last day.
+    pub fn move_selection(&mut self, offset: i32) {
+        let day = (self.selected_day() + offset).clamp(1, DAYS);
+        self.table.select(Some(day as usize - 1));
+    }
+
+    /// Updates the rows with the outcome of fresh runs.
+    pub fn apply_reports(&mut self, reports: &[RunReport]) {
+        for report in reports {
+            let Some(row) = self.days.iter_mut().find(|row| row.day == report.day) else {
+                continue;
+            };
+            let Some(part) = row.parts.get_mut(report.part as usize - 1) else {
+                continue;
+            };
+            part.answer = Some(report.display_answer().to_string());
+            part.solve_ms = Some(report.solve_duration.as_secs_f64() * 1000.0);
+        }
+    }
+
+    /// Runs every implemented part of the selected day and shows the answers.
+    ///
+    /// Solver panics and missing inputs are reported in the status line
+    /// instead of ending the dashboard.
+    fn run_selected(&mut self) {
+        let day = self.selected_day();
+        let parts = registry::parts(day);
+        if parts.is_empty() {
+            self.status = format!("Day {} is not implemented", day);
+            return;
+        }
+
+        match panic::catch_unwind(|| run_day(day, &parts, None)) {
+            Ok(Ok(reports)) => {
+                if self.record_history {
+                    history::record(&reports);
+                }
+                self.apply_reports(&reports);
+                self.status = format!("Ran day {}", day);
+            }
+            Ok(Err(err)) => self.status = format!("Day {}: {}", day, err),
+            Err(_) => self.status = format!("Day {}: the solver panicked", day),
+        }
+    }
+
+    /// Draws the table of days and the status line.
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let [table_area, status_area] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+        let header = Row::new(["Day", "Part 1", "Solve", "Part 2", "Solve"])
+            .style(Style::new().add_modifier(Modifier::BOLD));
+        let rows = self.days.iter().map(|row| {
+            let mut cells = vec![Cell::from(format!("{:>3}", row.day))];
+            for part in &row.parts {
+                cells.extend(part_cells(part));
+            }
+            Row::new(cells)
+        });
+        let widths = [
+            Constraint::Length(4),
+            Constraint::Min(16),
+            Constraint::Length(12),
+            Constraint::Min(16),
+            Constraint::Length(12),
+        ];
+        let table = Table::new(rows, widths)
+            .header(header)
+            .block(Block::bordered().title(format!(" Advent of Code {} ", config::current().year)))
+            .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(table, table_area, &mut self.table);
+        frame.render_widget(
+            Paragraph::new(Line::from(self.status.as_str())),
+            status_area,
+        );
+    }
+}
+
+/// Returns the answer and solve time cells of one part.
+fn part_cells(part: &PartStatus) -> [Cell<'static>; 2] {
+    if !part.implemented {
+        let missing = Style::new().fg(Color::DarkGray);
+        return [Cell::from("missing").style(missing), Cell::from("")];
+    }
+    match (&part.answer, part.solve_ms) {
+        (Some(answer), Some(solve_ms)) => [
+            Cell::from(answer.clone()).style(Style::new().fg(Color::Green)),
+            Cell::from(format!("{:>9.3} ms", solve_ms)),
+        ],
+        _ => [
+            Cell::from("not run").style(Style::new().fg(Color::Yellow)),
+            Cell::from(""),
+        ],
+    }
+}
+
+/// Runs the interactive dashboard until the user quits.
+///
+/// Takes over the terminal, showing the last answer and solve time of every
+/// part of the event, taken from the run history at start. Key bindings:
+/// - `↑`/`↓` or `k`/`j`: select a day
+/// - `r` or `Enter`: run the selected day
+/// - `o`: open the input of the selected day in `$EDITOR` (defaults to `vi`)
+/// - `q` or `Esc`: quit
+///
+/// # Returns
+/// An I/O error if the terminal could not be set up or read.
+pub fn run(record_history: bool) -> io::Result<()> {
+    let history = history::load(history::DEFAULT_HISTORY_PATH)?;
+    let mut dashboard = Dashboard::new(&history, record_history);
+
+    // Progress bars would be drawn over the dashboard.
+    progress::set_enabled(false);
+    let mut terminal = ratatui::try_init()?;
+    let result = event_loop(&mut terminal, &mut dashboard);
+    ratatui::try_restore()?;
+    result
+}
+
+/// Draws the dashboard and handles key presses until the user quits.
+fn event_loop(terminal: &mut DefaultTerminal, dashboard: &mut Dashboard) -> io::Result<()> {
+    loop {
+        terminal.draw(|frame| dashboard.draw(frame))?;
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            KeyCode::Up | KeyCode::Char('k') => dashboard.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => dashboard.move_selection(1),
+            KeyCode::Home => dashboard.move_selection(-DAYS),
+            KeyCode::End => dashboard.move_selection(DAYS),
+            KeyCode::Char('r') | KeyCode::Enter => {
+                dashboard.status = format!("Running day {}...", dashboard.selected_day());
+                terminal.draw(|frame| dashboard.draw(frame))?;
+                dashboard.run_selected();
+                // Panic messages are printed over the dashboard, so redraw it completely.
+                terminal.clear()?;
+            }
+            KeyCode::Char('o') => {
+                let day = dashboard.selected_day();
+                dashboard.status = match find_input(day, 1).or_else(|| find_input(day, 2)) {
+                    Some(path) => {
+                        ratatui::try_restore()?;
+                        let editor = std::env::var("EDITOR").unwrap_or_else(|_| "vi".to_string());
+                        let outcome = Command::new(&editor).arg(&path).status();
+                        *terminal = ratatui::try_init()?;
+                        match outcome {
+                            Ok(_) => format!("Edited {}", path),
+                            Err(err) => format!("Could not start '{}': {}", editor, err),
+                        }
+                    }
+                    None => format!("Day {} has no input file", day),
+                };
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn entry(day: i32, part: i32, answer: &str, timestamp: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            day,
+            part,
+            answer: answer.to_string(),
+            input_hash: String::new(),
+            input_ms: 0.0,
+            solve_ms: 2.0,
+        }
+    }
+
+    #[test]
+    fn test_new_uses_latest_history_entry() {
+        let history = vec![
+            entry(1, 1, "3", 1),
+            entry(1, 1, "4", 2),
+            entry(1, 2, "6", 3),
+        ];
+        let dashboard = Dashboard::new(&history, false);
+
+        assert_eq!(dashboard.days.len(), 25);
+        assert_eq!(dashboard.days[0].parts[0].answer.as_deref(), Some("4"));
+        assert_eq!(dashboard.days[0].parts[1].answer.as_deref(), Some("6"));
+        #[cfg(feature = "day01")]
+        assert!(dashboard.days[0].parts[0].implemented);
+        assert!(!dashboard.days[24].parts[0].implemented);
+        assert_eq!(dashboard.days[1].parts[0].answer, None);
+    }
+
+    #[test]
+    fn test_move_selection() {
+        let mut dashboard = Dashboard::new(&[], false);
+        dashboard.move_selection(-1);
+        assert_eq!(dashboard.selected_day(), 1);
+        dashboard.move_selection(3);
+        assert_eq!(dashboard.selected_day(), 4);
+        dashboard.move_selection(DAYS);
+        assert_eq!(dashboard.selected_day(), 25);
+    }
+
+    #[test]
+    fn test_apply_reports() {
+        let mut dashboard = Dashboard::new(&[], false);
+        dashboard.apply_reports(&[RunReport {
+            day: 2,
+            part: 2,
+            input_path: String::new(),
+            answer: "42".to_string(),
+            input_bytes: 0,
+            input_lines: 0,
+            input_hash: 0,
+            input_duration: Duration::ZERO,
+            parse_duration: Duration::ZERO,
+            solve_duration: Duration::from_millis(5),
+            samples: Vec::new(),
+            timed_out: false,
+            cancelled: false,
+            panic: None,
+            allocations: None,
+            peak_rss: None,
+            environment: None,
+        }]);
+
+        assert_eq!(dashboard.days[1].parts[1].answer.as_deref(), Some("42"));
+        assert_eq!(dashboard.days[1].parts[1].solve_ms, Some(5.0));
+    }
+}
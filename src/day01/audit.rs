@@ -0,0 +1,72 @@
+use std::io::{self, Write};
+
+use super::dial::{DialState, trace};
+use super::format_rotation;
+
+/// The header line of the audit log written by [`write_audit`].
+pub const AUDIT_HEADER: &str = "step,command,start,end,zero_passes";
+
+/// Writes a CSV audit log with one row per rotation, for tracking down wrong
+/// answers without temporary prints.
+///
+/// Every row holds the number of the rotation (starting at 1), the rotation
+/// as command like `L68`, the positions before and after it, and how often
+/// the dial pointed at 0 during this rotation alone, e.g. `1,L68,50,82,1`.
+/// Summing the last column gives the answer of part 2, counting the rows
+/// ending at 0 the answer of part 1.
+///
+/// # Returns
+/// An error if writing failed.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::audit::write_audit;
+///
+/// let mut out: Vec<u8> = Vec::new();
+/// write_audit(&[-68, -30, 48], &mut out).unwrap();
+/// assert_eq!(
+///     String::from_utf8(out).unwrap(),
+///     "step,command,start,end,zero_passes\n1,L68,50,82,1\n2,L30,82,52,0\n3,R48,52,0,1\n"
+/// );
+/// ```
+pub fn write_audit(rotations: &[i32], out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "{}", AUDIT_HEADER)?;
+    let mut previous = DialState::start();
+    for (index, state) in trace(rotations.iter().copied()).enumerate() {
+        writeln!(
+            out,
+            "{},{},{},{},{}",
+            index + 1,
+            format_rotation(state.rotation),
+            previous.position,
+            state.position,
+            state.zero_passes - previous.zero_passes
+        )?;
+        previous = state;
+    }
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_audit_wraps_around() {
+        let mut out: Vec<u8> = Vec::new();
+        write_audit(&[250, -1, 0], &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        let rows: Vec<&str> = out.lines().collect();
+        assert_eq!(
+            rows,
+            vec![AUDIT_HEADER, "1,R250,50,0,3", "2,L1,0,99,0", "3,R0,99,99,0"]
+        );
+    }
+
+    #[test]
+    fn test_write_audit_empty() {
+        let mut out: Vec<u8> = Vec::new();
+        write_audit(&[], &mut out).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), format!("{}\n", AUDIT_HEADER));
+    }
+}
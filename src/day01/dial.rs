@@ -0,0 +1,332 @@
+/// A safe dial with the positions 0 to 99 that wraps around in both directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Dial {
+    position: i32,
+}
+
+impl Dial {
+    /// The number of positions on the dial.
+    pub const POSITIONS: i32 = 100;
+
+    /// The position the dial points at before the first rotation.
+    pub const START: i32 = 50;
+
+    /// Creates a dial pointing at [`Dial::START`].
+    pub fn new() -> Dial {
+        Dial::at(Dial::START)
+    }
+
+    /// Creates a dial pointing at `position`.
+    ///
+    /// # Panics
+    /// If `position` is not within `0..Dial::POSITIONS`.
+    pub fn at(position: i32) -> Dial {
+        assert!(
+            (0..Dial::POSITIONS).contains(&position),
+            "dial position {} is out of range",
+            position
+        );
+        Dial { position }
+    }
+
+    /// Returns the position the dial points at.
+    pub fn position(&self) -> i32 {
+        self.position
+    }
+
+    /// Rotates the dial by a signed distance in constant time.
+    ///
+    /// Instead of turning the dial click by click, the final position is the
+    /// distance reduced modulo [`Dial::POSITIONS`], and the zero passes are the
+    /// multiples of [`Dial::POSITIONS`] reached on the way. A left rotation is
+    /// counted as a right rotation on the mirrored dial.
+    ///
+    /// # Parameters
+    /// - `distance`: The rotation, e.g. `5` for `"R5"` or `-12` for `"L12"`.
+    ///
+    /// # Returns
+    /// How often the dial pointed at 0 during the rotation, including the
+    /// final position but not the starting one.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::day01::dial::Dial;
+    ///
+    /// let mut dial = Dial::at(99);
+    /// assert_eq!(dial.rotate(5), 1); // Wraps around once
+    /// assert_eq!(dial.position(), 4);
+    /// ```
+    pub fn rotate(&mut self, distance: i32) -> i32 {
+        let positions = i64::from(Dial::POSITIONS);
+        let position = i64::from(self.position);
+        let distance = i64::from(distance);
+        // The distance from the start to the next 0 is measured in the
+        // direction of the rotation, which mirrors the dial for left turns.
+        let offset = if distance >= 0 {
+            position
+        } else {
+            (positions - position) % positions
+        };
+        let zero_passes = (offset + distance.abs()) / positions;
+        self.position = (position + distance).rem_euclid(positions) as i32;
+        zero_passes as i32
+    }
+
+    /// Rotates the dial one click at a time, the original simulation kept as
+    /// reference for the arithmetic in [`Dial::rotate`].
+    ///
+    /// # Returns
+    /// The same as [`Dial::rotate`], in O(|distance|) time.
+    #[cfg(any(test, feature = "reference"))]
+    pub fn rotate_stepwise(&mut self, distance: i32) -> i32 {
+        let step: i32 = distance.signum();
+        let mut zero_passes: i32 = 0;
+        for _ in 0..distance.unsigned_abs() {
+            self.position = (self.position + step).rem_euclid(Dial::POSITIONS);
+            if self.position == 0 {
+                zero_passes += 1;
+            }
+        }
+        zero_passes
+    }
+}
+
+impl Default for Dial {
+    fn default() -> Dial {
+        Dial::new()
+    }
+}
+
+/// The state of a dial after a rotation, as yielded by [`trace`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DialState {
+    /// The rotation just performed as a signed distance.
+    pub rotation: i32,
+    /// The position the dial points at after the rotation.
+    pub position: i32,
+    /// How many rotations so far ended at 0, the answer of part 1.
+    pub zero_stops: usize,
+    /// How often the dial pointed at 0 during all rotations so far, the
+    /// answer of part 2.
+    pub zero_passes: i64,
+}
+
+impl DialState {
+    /// Returns the state before the first rotation: pointing at
+    /// [`Dial::START`] without any zero counted yet.
+    pub fn start() -> DialState {
+        DialState {
+            rotation: 0,
+            position: Dial::START,
+            zero_stops: 0,
+            zero_passes: 0,
+        }
+    }
+
+    /// Returns the state after performing one more rotation.
+    pub fn after(&self, rotation: i32) -> DialState {
+        let mut dial = Dial::at(self.position);
+        let zero_passes = dial.rotate(rotation);
+        DialState {
+            rotation,
+            position: dial.position(),
+            zero_stops: self.zero_stops + usize::from(dial.position() == 0),
+            zero_passes: self.zero_passes + i64::from(zero_passes),
+        }
+    }
+}
+
+/// Lazily yields the state of the dial after each rotation, for debugging
+/// wrong answers or visualizing the dial.
+///
+/// The [`Dial`] starts at [`Dial::START`], and the starting state itself is
+/// not yielded.
+///
+/// # Parameters
+/// - `rotations`: The rotations as signed distances, e.g. from
+///   [`Day01::parse`](super::Day01) or [`read_rotations`](super::read_rotations).
+///
+/// # Returns
+/// An iterator over the dial states, one per rotation, with the zero counts
+/// accumulated over all rotations so far.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::dial::trace;
+///
+/// let states: Vec<_> = trace([-68, -30, 48]).collect();
+/// assert_eq!(states[0].position, 82);
+/// assert_eq!(states[0].zero_passes, 1);
+/// assert_eq!(states[2].position, 0);
+/// assert_eq!(states[2].zero_stops, 1);
+/// assert_eq!(states[2].zero_passes, 2);
+/// ```
+pub fn trace(rotations: impl IntoIterator<Item = i32>) -> impl Iterator<Item = DialState> {
+    rotations
+        .into_iter()
+        .scan(DialState::start(), |state, rotation| {
+            *state = state.after(rotation);
+            Some(*state)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+
+    /// Rotates a dial starting at `start_position`, returning `(position, zero_passes)`.
+    fn rotate_dial(start_position: i32, distance: i32) -> (i32, i32) {
+        let mut dial = Dial::at(start_position);
+        let zero_passes = dial.rotate(distance);
+        (dial.position(), zero_passes)
+    }
+
+    #[test]
+    fn test_new_starts_at_50() {
+        assert_eq!(Dial::new().position(), 50);
+        assert_eq!(Dial::default(), Dial::new());
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_at_out_of_range() {
+        Dial::at(100);
+    }
+
+    #[test]
+    fn test_rotate_dial_right_without_overflow() {
+        assert_eq!(rotate_dial(50, 5), (55, 0));
+    }
+
+    #[test]
+    fn test_rotate_dial_right_with_small_overflow() {
+        assert_eq!(rotate_dial(99, 1), (0, 1));
+    }
+
+    #[test]
+    fn test_rotate_dial_right_with_large_overflow() {
+        assert_eq!(rotate_dial(99, 5), (4, 1));
+    }
+
+    #[test]
+    fn test_rotate_dial_right_with_extra_large_overflow() {
+        assert_eq!(rotate_dial(50, 1000), (50, 10));
+    }
+
+    #[test]
+    fn test_rotate_dial_left_without_overflow() {
+        assert_eq!(rotate_dial(50, -5), (45, 0));
+    }
+
+    #[test]
+    fn test_rotate_dial_left_without_overflow_to_zero() {
+        assert_eq!(rotate_dial(5, -5), (0, 1));
+    }
+
+    #[test]
+    fn test_rotate_dial_left_with_small_overflow() {
+        assert_eq!(rotate_dial(0, -1), (99, 0));
+    }
+
+    #[test]
+    fn test_rotate_dial_left_with_large_overflow_and_start_at_zero() {
+        assert_eq!(rotate_dial(0, -5), (95, 0));
+    }
+
+    #[test]
+    fn test_rotate_dial_left_with_large_overflow_and_start_above_zero() {
+        assert_eq!(rotate_dial(1, -5), (96, 1));
+    }
+
+    #[test]
+    fn test_rotate_dial_left_with_extra_large_overflow() {
+        assert_eq!(rotate_dial(50, -1000), (50, 10));
+    }
+
+    #[test]
+    fn test_rotate_dial_right_with_huge_distance() {
+        assert_eq!(rotate_dial(50, 1_000_000_000), (50, 10_000_000));
+        assert_eq!(rotate_dial(50, 1_000_000_049), (99, 10_000_000));
+        assert_eq!(rotate_dial(50, 1_000_000_050), (0, 10_000_001));
+    }
+
+    #[test]
+    fn test_rotate_dial_left_with_huge_distance() {
+        assert_eq!(rotate_dial(50, -1_000_000_000), (50, 10_000_000));
+        assert_eq!(rotate_dial(0, -1_000_000_000), (0, 10_000_000));
+        assert_eq!(rotate_dial(50, -1_000_000_050), (0, 10_000_001));
+    }
+
+    #[test]
+    fn test_rotate_dial_extreme_distances() {
+        assert_eq!(rotate_dial(0, i32::MAX), (47, 21_474_836));
+        assert_eq!(rotate_dial(0, i32::MIN), (52, 21_474_836));
+    }
+
+    #[test]
+    fn test_rotate_dial_zero_distance() {
+        assert_eq!(rotate_dial(0, 0), (0, 0));
+        assert_eq!(rotate_dial(42, 0), (42, 0));
+    }
+
+    #[test]
+    fn test_rotate_matches_click_by_click() {
+        for start in 0..Dial::POSITIONS {
+            for distance in -250..=250 {
+                let mut reference = Dial::at(start);
+                let zero_passes = reference.rotate_stepwise(distance);
+                assert_eq!(
+                    rotate_dial(start, distance),
+                    (reference.position(), zero_passes),
+                    "start {} distance {}",
+                    start,
+                    distance
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_trace() {
+        let states: Vec<DialState> = trace([-68, -30, 48, -5, 60]).collect();
+        let positions: Vec<i32> = states.iter().map(|state| state.position).collect();
+        assert_eq!(positions, vec![82, 52, 0, 95, 55]);
+        assert_eq!(states[3].rotation, -5);
+        let zero_stops: Vec<usize> = states.iter().map(|state| state.zero_stops).collect();
+        assert_eq!(zero_stops, vec![0, 0, 1, 1, 1]);
+        let zero_passes: Vec<i64> = states.iter().map(|state| state.zero_passes).collect();
+        assert_eq!(zero_passes, vec![1, 1, 2, 2, 3]);
+    }
+
+    #[test]
+    fn test_trace_empty() {
+        assert_eq!(trace([]).next(), None);
+    }
+
+    proptest! {
+        #[test]
+        fn prop_rotate_matches_stepwise(start in 0..Dial::POSITIONS, distance in -100_000..=100_000) {
+            let mut dial = Dial::at(start);
+            let mut reference = Dial::at(start);
+            prop_assert_eq!(dial.rotate(distance), reference.rotate_stepwise(distance));
+            prop_assert_eq!(dial, reference);
+        }
+
+        #[test]
+        fn prop_trace_matches_stepwise(rotations in prop::collection::vec(-1_000..=1_000, 0..50)) {
+            let mut reference = Dial::new();
+            let mut zero_stops = 0;
+            let mut zero_passes = 0;
+            for (state, &rotation) in trace(rotations.clone()).zip(&rotations) {
+                zero_passes += i64::from(reference.rotate_stepwise(rotation));
+                zero_stops += usize::from(reference.position() == 0);
+                prop_assert_eq!(
+                    state,
+                    DialState { rotation, position: reference.position(), zero_stops, zero_passes }
+                );
+            }
+        }
+    }
+}
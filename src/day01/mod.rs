@@ -1,2 +1,367 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, BufRead};
+
+use crate::check::{Diagnostic, check_lines, is_number};
+use crate::parsing::invalid_line;
+use crate::solver::{Answer, Metadata, Part2, Solver};
+
+pub mod audit;
+pub mod dial;
+pub mod named;
 pub mod part1;
 pub mod part2;
+#[cfg(feature = "cli")]
+pub mod visualize;
+
+/// The solution of day 1: counting how often a dial points at 0.
+pub struct Day01;
+
+impl Solver for Day01 {
+    /// The rotations as signed distances: positive to the right, negative to the left.
+    type Parsed = Vec<i32>;
+
+    const METADATA: Metadata = Metadata {
+        title: "Secret Entrance",
+        example: "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82",
+        example_answers: [Some("3"), Some("6")],
+        complexity: "Both parts rotate the dial with modular arithmetic: O(number of rotations).",
+    };
+
+    /// Parses one rotation like `"R5"` or `"L12"` per line, see [`parse_rotations`].
+    fn parse(input: &str) -> Result<Vec<i32>, Diagnostic> {
+        parse_rotations(input).map_err(|err| Diagnostic::at(err.line, err.reason))
+    }
+
+    fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+        validate(input)
+    }
+
+    fn part1(rotations: &Vec<i32>) -> Answer {
+        part1::solve(rotations)
+    }
+}
+
+impl Part2 for Day01 {
+    fn part2(rotations: &Vec<i32>) -> Answer {
+        part2::solve(rotations)
+    }
+}
+
+crate::register_day!(
+    year: 2025,
+    day: 1,
+    solver: Day01,
+    parts: [1, 2],
+    generator: crate::generators::day01,
+);
+
+/// A line of the input that is not a rotation like `"R5"` or `"L12"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// Why the line is not a rotation.
+    pub reason: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.reason)
+    }
+}
+
+impl Error for ParseError {}
+
+/// The notations a rotation can be written in.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Syntax {
+    /// Any of the notations below, recognized per line.
+    #[default]
+    Auto,
+    /// The puzzle's notation, `"R5"` to the right and `"L12"` to the left.
+    Letters,
+    /// `"CW5"` clockwise, i.e. to the right, and `"CCW12"` counterclockwise.
+    Clockwise,
+    /// Signed distances, `"+5"` to the right and `"-12"` to the left.
+    Signed,
+}
+
+impl Syntax {
+    /// The directions of this notation with their prefixes, longest first so
+    /// `"CCW"` is not mistaken for `"C"` followed by garbage.
+    fn directions(self) -> &'static [(&'static str, i32)] {
+        match self {
+            Syntax::Auto => &[
+                ("CCW", -1),
+                ("CW", 1),
+                ("R", 1),
+                ("L", -1),
+                ("+", 1),
+                ("-", -1),
+            ],
+            Syntax::Letters => &[("R", 1), ("L", -1)],
+            Syntax::Clockwise => &[("CCW", -1), ("CW", 1)],
+            Syntax::Signed => &[("+", 1), ("-", -1)],
+        }
+    }
+
+    /// Returns two example rotations for error messages, e.g. `"'R5' or 'L12'"`.
+    fn examples(self) -> &'static str {
+        match self {
+            Syntax::Auto | Syntax::Letters => "'R5' or 'L12'",
+            Syntax::Clockwise => "'CW5' or 'CCW12'",
+            Syntax::Signed => "'+5' or '-12'",
+        }
+    }
+}
+
+/// Options of [`parse_rotations_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The notation of the rotations, [`Syntax::Auto`] by default.
+    pub syntax: Syntax,
+}
+
+/// Parses the rotations of an input, one like `"R5"` or `"L12"` per line.
+///
+/// The notations `"CW5"`/`"CCW12"` and `"+5"`/`"-12"` are accepted as well,
+/// even mixed in one input, see [`Syntax::Auto`]. Blank lines are skipped
+/// and surrounding whitespace, including the `'\r'` of Windows line endings,
+/// is ignored, so a trailing line break parses fine.
+///
+/// # Returns
+/// The rotations as signed distances, positive to the right and negative to
+/// the left, or a [`ParseError`] for the first line that is not a rotation.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::parse_rotations;
+///
+/// assert_eq!(parse_rotations("L68\r\n R48 \n\n").unwrap(), vec![-68, 48]);
+/// assert_eq!(parse_rotations("CCW68\n+48").unwrap(), vec![-68, 48]);
+///
+/// let err = parse_rotations("R5\nX5").unwrap_err();
+/// assert_eq!(err.line, 2);
+/// assert_eq!(err.to_string(), "line 2: expected a rotation like 'R5' or 'L12', found 'X5'");
+/// ```
+pub fn parse_rotations(input: &str) -> Result<Vec<i32>, ParseError> {
+    parse_rotations_with(input, ParseOptions::default())
+}
+
+/// Like [`parse_rotations`], but accepts only the notation selected in `options`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::{ParseOptions, Syntax, parse_rotations_with};
+///
+/// let options = ParseOptions { syntax: Syntax::Signed };
+/// assert_eq!(parse_rotations_with("+5\n-12", options).unwrap(), vec![5, -12]);
+/// assert!(parse_rotations_with("R5", options).is_err());
+/// ```
+pub fn parse_rotations_with(input: &str, options: ParseOptions) -> Result<Vec<i32>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line, command)| {
+            parse_rotation_with(command, options.syntax)
+                .map_err(|reason| ParseError { line, reason })
+        })
+        .collect()
+}
+
+/// Parses a rotation in any [`Syntax`], like `"R5"` or `"CCW12"`, into a signed distance.
+///
+/// # Returns
+/// The distance, positive to the right and negative to the left, or a
+/// message describing why the rotation is invalid.
+fn parse_rotation(command: &str) -> Result<i32, String> {
+    parse_rotation_with(command, Syntax::Auto)
+}
+
+/// Parses a rotation written in `syntax` into a signed distance.
+///
+/// # Returns
+/// The distance, or a message describing why the rotation is invalid.
+fn parse_rotation_with(command: &str, syntax: Syntax) -> Result<i32, String> {
+    let invalid = || {
+        format!(
+            "expected a rotation like {}, found '{}'",
+            syntax.examples(),
+            command
+        )
+    };
+    let (direction, distance) = split_direction(command, syntax).ok_or_else(invalid)?;
+    if !is_number(distance) {
+        return Err(invalid());
+    }
+    let distance: i32 = distance.parse().map_err(|_| invalid())?;
+    Ok(direction * distance)
+}
+
+/// Formats a rotation in the puzzle's notation, e.g. `"L12"` for `-12`.
+pub fn format_rotation(rotation: i32) -> String {
+    let direction = if rotation < 0 { 'L' } else { 'R' };
+    format!("{}{}", direction, rotation.unsigned_abs())
+}
+
+/// Splits a rotation into its direction, `1` or `-1`, and the distance text
+/// after the direction prefix of `syntax`.
+fn split_direction(command: &str, syntax: Syntax) -> Option<(i32, &str)> {
+    syntax.directions().iter().find_map(|&(prefix, direction)| {
+        command
+            .strip_prefix(prefix)
+            .map(|distance| (direction, distance))
+    })
+}
+
+/// Reads the rotations line by line, without holding the whole input in memory.
+///
+/// Used by the `solve_reader` functions of both parts for inputs too large
+/// to read into a single string. Only one line is held at a time, so memory
+/// use stays constant however long the input is. Blank lines and whitespace
+/// are tolerated like in [`parse_rotations`].
+///
+/// # Returns
+/// An iterator over the rotations as signed distances, like [`Day01::parse`];
+/// an item is an `InvalidData` error naming the line if it is not a rotation.
+pub fn read_rotations(reader: impl BufRead) -> impl Iterator<Item = io::Result<i32>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(
+                parse_rotation(line.trim()).map_err(|message| invalid_line(index + 1, message)),
+            ),
+            Err(err) => Some(Err(err)),
+        })
+}
+
+/// Validates that every line is a rotation like `"R5"` or `"L12"`, or in
+/// one of the other notations of [`Syntax`].
+///
+/// Like [`parse_rotations`], blank lines and surrounding whitespace are
+/// ignored, so every validated input parses.
+pub fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+    check_lines(input, |line| {
+        let indent = line.len() - line.trim_start().len();
+        let line = line.trim();
+        if line.is_empty() {
+            return None;
+        }
+        let column = match split_direction(line, Syntax::Auto) {
+            Some((_, distance)) => {
+                if parse_rotation(line).is_ok() {
+                    return None;
+                }
+                line.len() - distance.len() + 1
+            }
+            None => 1,
+        };
+        Some((
+            indent + column,
+            format!("expected a rotation like 'R5' or 'L12', found '{}'", line),
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_rotations_tolerates_whitespace() {
+        assert_eq!(parse_rotations("L68\r\nR48\r\n").unwrap(), vec![-68, 48]);
+        assert_eq!(
+            parse_rotations("\n  R5 \n\t\nL12\n\n").unwrap(),
+            vec![5, -12]
+        );
+        assert_eq!(parse_rotations("").unwrap(), Vec::<i32>::new());
+    }
+
+    #[test]
+    fn test_parse_rotations_reports_line() {
+        let err = parse_rotations("R5\n\nX5\nL3").unwrap_err();
+        assert_eq!(err.line, 3);
+        assert_eq!(
+            err.reason,
+            "expected a rotation like 'R5' or 'L12', found 'X5'"
+        );
+        assert_eq!(parse_rotations("R5\nL").unwrap_err().line, 2);
+        assert_eq!(parse_rotations("R99999999999").unwrap_err().line, 1);
+    }
+
+    #[test]
+    fn test_validate_tolerates_whitespace() {
+        assert!(validate("L68\r\n R48 \n\n").is_ok());
+        assert_eq!(
+            validate("L68\n  X1\n").unwrap_err()[0],
+            Diagnostic::at_column(2, 3, "expected a rotation like 'R5' or 'L12', found 'X1'")
+        );
+    }
+
+    #[test]
+    fn test_parse_trailing_newline() {
+        assert_eq!(Day01::parse("R5\nL3\n").unwrap(), vec![5, -3]);
+    }
+
+    #[test]
+    fn test_read_rotations_tolerates_whitespace() {
+        let rotations: Vec<i32> = read_rotations("L68\r\n\n R48 \r\n".as_bytes())
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(rotations, vec![-68, 48]);
+
+        let err = read_rotations("R5\n\nX5".as_bytes())
+            .find_map(Result::err)
+            .unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 3: "));
+    }
+
+    #[test]
+    fn test_parse_rotations_alternative_syntaxes() {
+        assert_eq!(
+            parse_rotations("CW5\nCCW12\n+7\n-3\nR1\nL2").unwrap(),
+            vec![5, -12, 7, -3, 1, -2]
+        );
+        assert_eq!(Day01::parse("+5\n-12\n").unwrap(), vec![5, -12]);
+        assert!(validate("CW5\nCCW12\n+7\n-3").is_ok());
+        assert_eq!(
+            validate("CCWx").unwrap_err()[0],
+            Diagnostic::at_column(1, 4, "expected a rotation like 'R5' or 'L12', found 'CCWx'")
+        );
+    }
+
+    #[test]
+    fn test_parse_rotations_with_syntax() {
+        let clockwise = ParseOptions {
+            syntax: Syntax::Clockwise,
+        };
+        assert_eq!(
+            parse_rotations_with("CW5\nCCW12", clockwise).unwrap(),
+            vec![5, -12]
+        );
+        let err = parse_rotations_with("CW5\nL12", clockwise).unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(
+            err.reason,
+            "expected a rotation like 'CW5' or 'CCW12', found 'L12'"
+        );
+
+        let letters = ParseOptions {
+            syntax: Syntax::Letters,
+        };
+        assert!(parse_rotations_with("+5", letters).is_err());
+    }
+
+    #[test]
+    fn test_parse_rotations_rejects_signed_distance_after_direction() {
+        assert!(parse_rotations("R-5").is_err());
+        assert!(parse_rotations("+-5").is_err());
+        assert!(parse_rotations("L+5").is_err());
+    }
+}
@@ -0,0 +1,121 @@
+use std::collections::BTreeMap;
+
+use super::dial::DialState;
+use super::{ParseError, parse_rotation};
+
+/// A rotation of one of several dials, e.g. `("A".to_string(), 5)` for `"A: R5"`.
+pub type NamedRotation = (String, i32);
+
+/// Parses an input of several dials, one rotation like `"A: R5"` per line.
+///
+/// The name in front of the `':'` selects the dial. Like
+/// [`parse_rotations`](super::parse_rotations), blank lines are skipped and
+/// surrounding whitespace is ignored, also around the name.
+///
+/// # Returns
+/// The rotations with their dial names, or a [`ParseError`] for the first
+/// line without a name or with an invalid rotation.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::named::parse_named_rotations;
+///
+/// let rotations = parse_named_rotations("A: R5\nB: L12\n").unwrap();
+/// assert_eq!(rotations, vec![("A".to_string(), 5), ("B".to_string(), -12)]);
+/// assert_eq!(parse_named_rotations("A: R5\nR3").unwrap_err().line, 2);
+/// ```
+pub fn parse_named_rotations(input: &str) -> Result<Vec<NamedRotation>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| (index + 1, line.trim()))
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(line, command)| {
+            parse_named_rotation(command).map_err(|reason| ParseError { line, reason })
+        })
+        .collect()
+}
+
+/// Parses a rotation of a named dial like `"A: R5"` into `("A", 5)`.
+///
+/// # Returns
+/// The name and the distance, or a message describing why the line is invalid.
+fn parse_named_rotation(command: &str) -> Result<NamedRotation, String> {
+    let (name, rotation) = command
+        .split_once(':')
+        .map(|(name, rotation)| (name.trim(), rotation.trim()))
+        .filter(|(name, _)| !name.is_empty())
+        .ok_or_else(|| {
+            format!(
+                "expected a named rotation like 'A: R5', found '{}'",
+                command
+            )
+        })?;
+    Ok((name.to_string(), parse_rotation(rotation)?))
+}
+
+/// Performs the rotations of every dial independently, each starting at
+/// [`Dial::START`](super::dial::Dial::START).
+///
+/// # Returns
+/// The final state of every dial by name, with the zero counts of that dial
+/// alone.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::named::{dial_states, parse_named_rotations};
+///
+/// let rotations = parse_named_rotations("A: L50\nB: R60\nA: R100").unwrap();
+/// let states = dial_states(&rotations);
+/// assert_eq!(states["A"].position, 0);
+/// assert_eq!(states["A"].zero_stops, 2);
+/// assert_eq!(states["B"].zero_passes, 1);
+/// ```
+pub fn dial_states(rotations: &[NamedRotation]) -> BTreeMap<String, DialState> {
+    let mut states: BTreeMap<String, DialState> = BTreeMap::new();
+    for (name, rotation) in rotations {
+        let state = states.entry(name.clone()).or_insert_with(DialState::start);
+        *state = state.after(*rotation);
+    }
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_named_rotations_tolerates_whitespace() {
+        let rotations = parse_named_rotations("\r\n left :L68\r\n\nright: R48 \n").unwrap();
+        assert_eq!(
+            rotations,
+            vec![("left".to_string(), -68), ("right".to_string(), 48)]
+        );
+    }
+
+    #[test]
+    fn test_parse_named_rotations_reports_line() {
+        let err = parse_named_rotations("A: R5\n: R5").unwrap_err();
+        assert_eq!(err.line, 2);
+        assert_eq!(
+            err.reason,
+            "expected a named rotation like 'A: R5', found ': R5'"
+        );
+        let err = parse_named_rotations("A: X5").unwrap_err();
+        assert_eq!(
+            err.reason,
+            "expected a rotation like 'R5' or 'L12', found 'X5'"
+        );
+    }
+
+    #[test]
+    fn test_dial_states_are_independent() {
+        let rotations = parse_named_rotations("A: L68\nB: L50\nA: L30\nB: R100\nA: R48").unwrap();
+        let states = dial_states(&rotations);
+        assert_eq!(states.len(), 2);
+        assert_eq!(states["A"].position, 0);
+        assert_eq!((states["A"].zero_stops, states["A"].zero_passes), (1, 2));
+        assert_eq!(states["B"].position, 0);
+        assert_eq!((states["B"].zero_stops, states["B"].zero_passes), (2, 2));
+    }
+}
@@ -1,102 +1,148 @@
+use std::io::{self, BufRead};
+
+use super::dial::trace;
+use super::named::{NamedRotation, dial_states};
+use super::read_rotations;
+
+use crate::parsing::try_consume;
+use crate::solver::Answer;
+
 /// Solves Day 01 Part 1 puzzle.
 ///
-/// Takes a multiline string of dial rotation commands and returns the number of times
-/// the dial ends up at position 0 as a `String`.
+/// Takes the dial rotations and returns the number of times the dial ends up
+/// at position 0 as an [`Answer`].
 ///
 /// # Parameters
-/// - `input`: A string slice containing commands, one per line.  
-///   Each command starts with "R" or "L" followed by a number, e.g., "R5" or "L12".
+/// - `rotations`: The rotations parsed by [`Day01`](super::Day01), as signed
+///   distances: positive to the right, negative to the left.
 ///
 /// # Returns
-/// A `String` representing how many times the dial reached 0 after executing all commands.
-pub fn solve(input: &str) -> String {
-    let commands = input.split("\n");
-    let mut dial = 50;
-    let mut dial_zero_count = 0;
-    for command in commands {
-        dial = rotate_dial(dial, command);
-        if dial == 0 {
-            dial_zero_count += 1;
-        }
-    }
-    return dial_zero_count.to_string();
+/// An [`Answer`] holding how many times the dial reached 0 after executing all commands.
+pub fn solve(rotations: &[i32]) -> Answer {
+    count_zero_stops(rotations.iter().copied()).into()
 }
 
-/// Rotates a dial from a starting position based on a command.
+/// Solves Day 01 Part 1 for several independent dials, see
+/// [`named`](super::named).
 ///
-/// The dial has positions from 0 to 99 and wraps around.  
-/// Commands are strings starting with "R" (rotate right / increment) or "L" (rotate left / decrement)
-/// followed by a positive integer count.
+/// # Returns
+/// An [`Answer`] holding how many times any of the dials ended up at 0,
+/// summed over all dials.
 ///
-/// # Parameters
-/// - `start_position`: Current dial position (0..=99).
-/// - `command`: Rotation command string, e.g., "R5" or "L12".
+/// # Examples
+/// ```
+/// use aoc2025::day01::named::parse_named_rotations;
+/// use aoc2025::day01::part1::solve_named;
+///
+/// let rotations = parse_named_rotations("A: L50\nB: L50\nA: R100").unwrap();
+/// assert_eq!(solve_named(&rotations).to_string(), "3");
+/// ```
+pub fn solve_named(rotations: &[NamedRotation]) -> Answer {
+    dial_states(rotations)
+        .values()
+        .map(|state| state.zero_stops)
+        .sum::<usize>()
+        .into()
+}
+
+/// Like [`solve`], but reads the rotations line by line from `reader`, so
+/// even huge inputs never have to be loaded into memory at once.
 ///
 /// # Returns
-/// The new dial position after applying the rotation command.
-fn rotate_dial(start_position: i32, command: &str) -> i32 {
-    let right: bool = command.starts_with("R");
-    let mut count: i32 = command[1..].parse().unwrap();
-    let mut updated: i32 = start_position;
-    while count > 0 {
-        if right {
-            updated += 1;
-        } else {
-            updated -= 1;
-        }
+/// The answer, or an error if reading failed or a line is not a rotation.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::part1::solve_reader;
+///
+/// let answer = solve_reader("L50\nR5\nL5".as_bytes()).unwrap();
+/// assert_eq!(answer.to_string(), "2");
+/// ```
+pub fn solve_reader(reader: impl BufRead) -> io::Result<Answer> {
+    try_consume(read_rotations(reader), |rotations| {
+        count_zero_stops(rotations)
+    })
+    .map(Answer::from)
+}
 
-        if updated > 99 {
-            updated = 0;
-        }
-        if updated < 0 {
-            updated = 99;
-        }
+/// Counts how often the dial stops at 0 after a rotation, starting at 50.
+fn count_zero_stops(rotations: impl Iterator<Item = i32>) -> usize {
+    dial_positions(rotations)
+        .filter(|&position| position == 0)
+        .count()
+}
 
-        count -= 1;
-    }
-    return updated;
+/// Lazily yields the position the dial stops at after each rotation.
+///
+/// The [`Dial`](super::dial::Dial) starts at 50, and the starting position itself is not yielded.
+///
+/// # Parameters
+/// - `rotations`: The rotations as signed distances, e.g. from
+///   [`Day01::parse`](super::Day01) or [`read_rotations`](super::read_rotations).
+///
+/// # Returns
+/// An iterator over the dial positions (0..=99), one per rotation. See
+/// [`trace`] for the zero counts along the way.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::part1::dial_positions;
+///
+/// let positions: Vec<i32> = dial_positions([-68, -30, 48]).collect();
+/// assert_eq!(positions, vec![82, 52, 0]);
+/// ```
+pub fn dial_positions(rotations: impl IntoIterator<Item = i32>) -> impl Iterator<Item = i32> {
+    trace(rotations).map(|state| state.position)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day01::Day01;
+    use crate::day01::named::parse_named_rotations;
+    use crate::solver::Solver;
 
     #[test]
-    fn test_rotate_dial_right_without_overflow() {
-        let start_position = 50;
-        let command = "R5";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, 55);
+    fn test_dial_positions() {
+        let positions: Vec<i32> =
+            dial_positions(Day01::parse(Day01::METADATA.example).unwrap()).collect();
+        assert_eq!(positions, vec![82, 52, 0, 95, 55, 0, 99, 0, 14, 32]);
     }
 
     #[test]
-    fn test_rotate_dial_right_with_overflow() {
-        let start_position = 99;
-        let command = "R1";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, 0);
+    fn test_solve() {
+        let result = solve(&Day01::parse(Day01::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day01::METADATA.example_answer(1)
+        );
     }
 
     #[test]
-    fn test_rotate_dial_left_without_overflow() {
-        let start_position = 50;
-        let command = "L5";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, 45);
+    fn test_solve_reader() {
+        let result = solve_reader(Day01::METADATA.example.as_bytes()).unwrap();
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day01::METADATA.example_answer(1)
+        );
     }
 
     #[test]
-    fn test_rotate_dial_left_with_overflow() {
-        let start_position = 0;
-        let command = "L1";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, 99);
+    fn test_solve_reader_invalid_line() {
+        let err = solve_reader("R5\nforward 5\nL3".as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 2:"));
     }
 
     #[test]
-    fn test_solve() {
-        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
-        let result = solve(input);
-        assert_eq!(result, "3");
+    fn test_solve_huge_rotations() {
+        let result = solve(&Day01::parse("R1000000050\nL2000000000\nR1000000099").unwrap());
+        assert_eq!(result.to_string(), "2");
+    }
+
+    #[test]
+    fn test_solve_named_sums_dials() {
+        let rotations = parse_named_rotations("A: L68\nB: L50\nA: L30\nB: R10\nA: R48").unwrap();
+        assert_eq!(solve_named(&rotations).to_string(), "2");
     }
 }
@@ -1,171 +1,120 @@
+use std::io::{self, BufRead};
+
+use super::dial::Dial;
+use super::named::{NamedRotation, dial_states};
+use super::read_rotations;
+
+use crate::parsing::try_consume;
+use crate::solver::Answer;
+
 /// Solves Day 01 Part 2 puzzle.
 ///
 /// Processes a list of dial rotation commands and counts how many times
 /// the dial passes through position 0 during all rotations.
 ///
 /// # Parameters
-/// - `input`: A string slice containing commands, one per line.  
-///   Each command starts with `"R"` or `"L"` followed by a number, e.g., `"R5"` or `"L12"`.
+/// - `rotations`: The rotations parsed by [`Day01`](super::Day01), as signed
+///   distances: positive to the right, negative to the left.
 ///
 /// # Returns
-/// A `String` representing the total number of times the dial passed through 0.
+/// An [`Answer`] holding the total number of times the dial passed through 0.
 ///
 /// # Examples
 /// ```
+/// use aoc2025::day01::Day01;
 /// use aoc2025::day01::part2::solve;
+/// use aoc2025::solver::Solver;
 ///
-/// let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
-/// let result = solve(input);
-/// assert_eq!(result, "6");
+/// let rotations = Day01::parse("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82").unwrap();
+/// let result = solve(&rotations);
+/// assert_eq!(result.to_string(), "6");
 /// ```
-pub fn solve(input: &str) -> String {
-    let commands = input.split("\n");
-    let mut dial = 50;
-    let mut dial_zero_count = 0;
-    for command in commands {
-        let update: (i32, i32) = rotate_dial(dial, command);
-        dial = update.0;
-        dial_zero_count += update.1;
-    }
-    return dial_zero_count.to_string();
+pub fn solve(rotations: &[i32]) -> Answer {
+    count_zero_passes(rotations.iter().copied()).into()
 }
 
-/// Rotates a dial from a starting position based on a command,
-/// counting how often position 0 is passed.
-///
-/// # Parameters
-/// - `start_position`: Current dial position (0..=99)
-/// - `command`: Rotation command string starting with `"R"` or `"L"`
-///   followed by a positive integer count.
+/// Solves Day 01 Part 2 for several independent dials, see
+/// [`named`](super::named).
 ///
 /// # Returns
-/// A tuple `(new_position, zero_passes)`
-/// - `new_position`: the dial position after applying the command
-/// - `zero_passes`: number of times position 0 was passed during this rotation
+/// An [`Answer`] holding how many times any of the dials passed through 0,
+/// summed over all dials.
 ///
 /// # Examples
 /// ```
-/// use aoc2025::day01::part2::rotate_dial;
+/// use aoc2025::day01::named::parse_named_rotations;
+/// use aoc2025::day01::part2::solve_named;
 ///
-/// let result = rotate_dial(99, "R5");
-/// assert_eq!(result, (4, 1)); // Wraps around once
+/// let rotations = parse_named_rotations("A: L50\nB: L150\nA: R100").unwrap();
+/// assert_eq!(solve_named(&rotations).to_string(), "4");
 /// ```
-fn rotate_dial(start_position: i32, command: &str) -> (i32, i32) {
-    let right: bool = command.starts_with("R");
-    let mut count: i32 = command[1..].parse().unwrap();
-    let mut updated: i32 = start_position;
-    let mut zero_passes: i32 = 0;
-    while count > 0 {
-        if right {
-            updated += 1;
-        } else {
-            updated -= 1;
-        }
-
-        if updated > 99 {
-            updated = 0;
-        }
-        if updated < 0 {
-            updated = 99;
-        }
+pub fn solve_named(rotations: &[NamedRotation]) -> Answer {
+    dial_states(rotations)
+        .values()
+        .map(|state| state.zero_passes)
+        .sum::<i64>()
+        .into()
+}
 
-        if updated == 0 {
-            zero_passes += 1;
-        }
+/// Like [`solve`], but reads the rotations line by line from `reader`, so
+/// even huge inputs never have to be loaded into memory at once.
+///
+/// # Returns
+/// The answer, or an error if reading failed or a line is not a rotation.
+pub fn solve_reader(reader: impl BufRead) -> io::Result<Answer> {
+    try_consume(read_rotations(reader), |rotations| {
+        count_zero_passes(rotations)
+    })
+    .map(Answer::from)
+}
 
-        count -= 1;
-    }
-    return (updated, zero_passes);
+/// Counts how often the dial passes 0 during all rotations, starting at 50.
+fn count_zero_passes(rotations: impl Iterator<Item = i32>) -> i64 {
+    let mut dial = Dial::new();
+    rotations.map(|distance| dial.rotate(distance) as i64).sum()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day01::Day01;
+    use crate::day01::named::parse_named_rotations;
+    use crate::solver::Solver;
 
     #[test]
-    fn test_rotate_dial_right_without_overflow() {
-        let start_position = 50;
-        let command = "R5";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (55, 0));
-    }
-
-    #[test]
-    fn test_rotate_dial_right_with_small_overflow() {
-        let start_position = 99;
-        let command = "R1";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (0, 1));
-    }
-
-    #[test]
-    fn test_rotate_dial_right_with_large_overflow() {
-        let start_position = 99;
-        let command = "R5";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (4, 1));
-    }
-
-    #[test]
-    fn test_rotate_dial_right_with_extra_large_overflow() {
-        let start_position = 50;
-        let command = "R1000";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (50, 10));
-    }
-
-    #[test]
-    fn test_rotate_dial_left_without_overflow() {
-        let start_position = 50;
-        let command = "L5";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (45, 0));
-    }
-
-    #[test]
-    fn test_rotate_dial_left_without_overflow_to_zero() {
-        let start_position = 5;
-        let command = "L5";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (0, 1));
-    }
-
-    #[test]
-    fn test_rotate_dial_left_with_small_overflow() {
-        let start_position = 0;
-        let command = "L1";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (99, 0));
+    fn test_solve() {
+        let result = solve(&Day01::parse(Day01::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day01::METADATA.example_answer(2)
+        );
     }
 
     #[test]
-    fn test_rotate_dial_left_with_large_overflow_and_start_at_zero() {
-        let start_position = 0;
-        let command = "L5";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (95, 0));
+    fn test_solve_reader() {
+        let result = solve_reader(Day01::METADATA.example.as_bytes()).unwrap();
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day01::METADATA.example_answer(2)
+        );
     }
 
     #[test]
-    fn test_rotate_dial_left_with_large_overflow_and_start_above_zero() {
-        let start_position = 1;
-        let command = "L5";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (96, 1));
+    fn test_solve_huge_rotations() {
+        let result = solve(&Day01::parse("R1000000000\nL1000000050\nR150").unwrap());
+        assert_eq!(result.to_string(), "20000002");
     }
 
     #[test]
-    fn test_rotate_dial_left_with_extra_large_overflow() {
-        let start_position = 50;
-        let command = "L1000";
-        let result = rotate_dial(start_position, command);
-        assert_eq!(result, (50, 10));
+    fn test_solve_named_sums_dials() {
+        let rotations = parse_named_rotations("A: L68\nB: L50\nA: L30\nB: R10\nA: R48").unwrap();
+        assert_eq!(solve_named(&rotations).to_string(), "3");
     }
 
     #[test]
-    fn test_solve() {
-        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82";
-        let result = solve(input);
-        assert_eq!(result, "6");
+    fn test_solve_reader_long_stream() {
+        let input = "R150\r\nL50\n\n".repeat(100_000);
+        let result = solve_reader(input.as_bytes()).unwrap();
+        assert_eq!(result.to_string(), "200000");
     }
 }
@@ -0,0 +1,165 @@
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+use super::dial::{Dial, DialState, trace};
+use super::format_rotation;
+
+use crate::cancel::CancelToken;
+
+/// The time every frame of [`animate`] stays on screen, slow enough to follow
+/// single rotations.
+pub const FRAME_DELAY: Duration = Duration::from_millis(150);
+
+/// The number of lines of a frame, see [`render`].
+const FRAME_LINES: usize = 4;
+
+/// Renders the dial after a rotation as a frame of [`FRAME_LINES`] lines.
+///
+/// The dial is unrolled into a scale of [`Dial::POSITIONS`] characters with
+/// `'0'` marking position 0 and `'|'` every tenth position, and a pointer
+/// below it. With `use_color`, the pointer turns green whenever the dial
+/// stops at 0.
+///
+/// # Parameters
+/// - `state`: The state after the rotation, e.g. from [`trace`].
+/// - `step`: The number of rotations done, `0` for the starting position.
+/// - `total`: The number of rotations of the whole input.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::dial::trace;
+/// use aoc2025::day01::visualize::render;
+///
+/// let state = trace([-68]).next().unwrap();
+/// let frame = render(&state, 1, 10, false);
+/// assert!(frame.starts_with("Rotation 1/10: L68\n"));
+/// assert!(frame.ends_with("Stops at 0: 0   Passes through 0: 1"));
+/// ```
+pub fn render(state: &DialState, step: usize, total: usize, use_color: bool) -> String {
+    let heading = if step == 0 {
+        format!("Start: pointing at {}", state.position)
+    } else {
+        format!(
+            "Rotation {}/{}: {}",
+            step,
+            total,
+            format_rotation(state.rotation)
+        )
+    };
+    let scale: String = (0..Dial::POSITIONS)
+        .map(|position| match position {
+            0 => '0',
+            _ if position % 10 == 0 => '|',
+            _ => '·',
+        })
+        .collect();
+    let mut pointer = format!("^ {}", state.position);
+    if use_color && step > 0 && state.position == 0 {
+        pointer = format!("\x1b[32m{}\x1b[0m", pointer);
+    }
+    format!(
+        "{}\n{}\n{}{}\nStops at 0: {}   Passes through 0: {}",
+        heading,
+        scale,
+        " ".repeat(state.position as usize),
+        pointer,
+        state.zero_stops,
+        state.zero_passes
+    )
+}
+
+/// Animates the dial in the terminal while it performs the rotations,
+/// redrawing a [`render`]ed frame in place every `frame_delay`.
+///
+/// # Returns
+/// An error if writing failed, or an `Interrupted` error once `cancel` was
+/// cancelled, e.g. by Ctrl-C.
+pub fn animate(
+    rotations: &[i32],
+    out: &mut impl Write,
+    frame_delay: Duration,
+    use_color: bool,
+    cancel: &CancelToken,
+) -> io::Result<()> {
+    write_frame(
+        out,
+        &render(&DialState::start(), 0, rotations.len(), use_color),
+        false,
+    )?;
+    for (index, state) in trace(rotations.iter().copied()).enumerate() {
+        thread::sleep(frame_delay);
+        cancel.check()?;
+        let frame = render(&state, index + 1, rotations.len(), use_color);
+        write_frame(out, &frame, true)?;
+    }
+    Ok(())
+}
+
+/// Writes a frame, first moving the cursor back over the previous one if
+/// `redraw` is set and clearing every line before writing it.
+fn write_frame(out: &mut impl Write, frame: &str, redraw: bool) -> io::Result<()> {
+    if redraw {
+        write!(out, "\x1b[{}A", FRAME_LINES)?;
+    }
+    for line in frame.lines() {
+        writeln!(out, "\x1b[2K{}", line)?;
+    }
+    out.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_start() {
+        let frame = render(&DialState::start(), 0, 3, false);
+        let lines: Vec<&str> = frame.lines().collect();
+        assert_eq!(lines.len(), FRAME_LINES);
+        assert_eq!(lines[0], "Start: pointing at 50");
+        assert_eq!(lines[1].chars().count(), 100);
+        assert!(lines[1].starts_with("0·········|"));
+        assert_eq!(lines[2], format!("{}^ 50", " ".repeat(50)));
+    }
+
+    #[test]
+    fn test_render_zero_in_color() {
+        let state = trace([-50]).next().unwrap();
+        let frame = render(&state, 1, 1, true);
+        assert!(frame.starts_with("Rotation 1/1: L50\n"));
+        assert!(frame.contains("\n\x1b[32m^ 0\x1b[0m\n"));
+        assert!(frame.ends_with("Stops at 0: 1   Passes through 0: 1"));
+        assert!(!render(&state, 1, 1, false).contains('\x1b'));
+    }
+
+    #[test]
+    fn test_animate() {
+        let mut out: Vec<u8> = Vec::new();
+        animate(
+            &[-68, -30, 48],
+            &mut out,
+            Duration::ZERO,
+            false,
+            &CancelToken::new(),
+        )
+        .unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(out.matches("\x1b[4A").count(), 3);
+        assert!(out.ends_with("\x1b[2KStops at 0: 1   Passes through 0: 2\n"));
+    }
+
+    #[test]
+    fn test_animate_cancelled() {
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        let mut out: Vec<u8> = Vec::new();
+        let err = animate(&[5, 5], &mut out, Duration::ZERO, false, &cancel).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert!(
+            String::from_utf8(out)
+                .unwrap()
+                .starts_with("\x1b[2KStart: pointing at 50\n")
+        );
+    }
+}
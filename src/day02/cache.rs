@@ -0,0 +1,217 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+use std::slice;
+use std::sync::{Mutex, PoisonError};
+
+use super::ids::{InvalidIdIter, Rule};
+use super::sum_ids;
+
+use crate::cancel::{CancelToken, Cancelled};
+
+/// The file the `aoc` binary caches the range sums in with `--cache-ranges`,
+/// next to the cached responses of the website.
+pub const CACHE_FILE: &str = ".aoc/cache/day02_ranges.csv";
+
+/// The cache [`solve_rule_cancellable`](super::solve_rule_cancellable) uses
+/// for both parts, once set by [`enable`].
+static GLOBAL: Mutex<Option<RangeCache>> = Mutex::new(None);
+
+/// The sums of the invalid IDs of single ranges, persisted to a file.
+///
+/// The sums are keyed by the range and the [`Rule`], so both parts share a
+/// file. Summing the invalid IDs of a range is pure, so a cached sum never
+/// becomes stale.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RangeCache {
+    path: PathBuf,
+    sums: BTreeMap<(u128, u128, Rule), u128>,
+}
+
+impl RangeCache {
+    /// Reads the cache stored at `path`, or starts an empty one if there is
+    /// no such file. Lines that are no cache entry are skipped.
+    ///
+    /// # Returns
+    /// The cache, or an error if the file exists but cannot be read.
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<RangeCache> {
+        let path = path.into();
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(err) => return Err(err),
+        };
+        let sums = content.lines().filter_map(parse_entry).collect();
+        Ok(RangeCache { path, sums })
+    }
+
+    /// Returns the cached sum of the IDs in `range` invalid by `rule`.
+    pub fn get(&self, range: &RangeInclusive<u128>, rule: Rule) -> Option<u128> {
+        self.sums
+            .get(&(*range.start(), *range.end(), rule))
+            .copied()
+    }
+
+    /// Caches the sum of the IDs in `range` invalid by `rule`.
+    pub fn insert(&mut self, range: &RangeInclusive<u128>, rule: Rule, sum: u128) {
+        self.sums.insert((*range.start(), *range.end(), rule), sum);
+    }
+
+    /// Writes the cache to its file, one `start,end,rule,sum` line per range,
+    /// creating the directory if needed.
+    pub fn save(&self) -> io::Result<()> {
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let content: String = self
+            .sums
+            .iter()
+            .map(|((start, end, rule), sum)| {
+                format!("{},{},{},{}\n", start, end, rule_name(*rule), sum)
+            })
+            .collect();
+        fs::write(&self.path, content)
+    }
+}
+
+/// Returns the name of a rule in the cache file.
+fn rule_name(rule: Rule) -> &'static str {
+    match rule {
+        Rule::EqualHalves => "equal-halves",
+        Rule::Repetition => "repetition",
+    }
+}
+
+/// Parses a line of the cache file written by [`RangeCache::save`].
+fn parse_entry(line: &str) -> Option<((u128, u128, Rule), u128)> {
+    let mut fields = line.split(',');
+    let start = fields.next()?.parse().ok()?;
+    let end = fields.next()?.parse().ok()?;
+    let rule = match fields.next()? {
+        "equal-halves" => Rule::EqualHalves,
+        "repetition" => Rule::Repetition,
+        _ => return None,
+    };
+    let sum = fields.next()?.parse().ok()?;
+    fields.next().is_none().then_some(((start, end, rule), sum))
+}
+
+/// Sums the IDs within the given ranges invalid by `rule`, taking the sums of
+/// ranges summed before from `cache` and adding the others to it.
+///
+/// # Returns
+/// The sum, `None` if it overflows `u128`, or [`Cancelled`].
+///
+/// # Examples
+/// ```
+/// use aoc2025::cancel::CancelToken;
+/// use aoc2025::day02::cache::{RangeCache, sum_cached};
+/// use aoc2025::day02::ids::Rule;
+///
+/// let mut cache = RangeCache::open("no/such/cache.csv").unwrap();
+/// let ranges = [11..=22, 95..=115];
+/// let sum = sum_cached(&ranges, Rule::Repetition, &mut cache, &CancelToken::new());
+/// assert_eq!(sum, Ok(Some(243)));
+/// assert_eq!(cache.get(&(95..=115), Rule::Repetition), Some(210));
+/// ```
+pub fn sum_cached(
+    ranges: &[RangeInclusive<u128>],
+    rule: Rule,
+    cache: &mut RangeCache,
+    cancel: &CancelToken,
+) -> Result<Option<u128>, Cancelled> {
+    let mut total: u128 = 0;
+    for range in ranges {
+        let sum = match cache.get(range, rule) {
+            Some(sum) => sum,
+            None => {
+                let ids = |range| InvalidIdIter::new(range, rule);
+                let Some(sum) = sum_ids(slice::from_ref(range), cancel, ids)? else {
+                    return Ok(None);
+                };
+                cache.insert(range, rule, sum);
+                sum
+            }
+        };
+        let Some(next) = total.checked_add(sum) else {
+            return Ok(None);
+        };
+        total = next;
+    }
+    Ok(Some(total))
+}
+
+/// Makes both parts take the range sums from the cache stored at `path` and
+/// save new ones to it, see [`sum_cached`].
+///
+/// # Returns
+/// An error if the cache file exists but cannot be read.
+pub fn enable(path: impl Into<PathBuf>) -> io::Result<()> {
+    let cache = RangeCache::open(path)?;
+    *GLOBAL.lock().unwrap_or_else(PoisonError::into_inner) = Some(cache);
+    Ok(())
+}
+
+/// Sums like [`sum_cached`] with the cache set by [`enable`] and saves it.
+/// A cache that cannot be saved is logged and otherwise ignored.
+///
+/// # Returns
+/// `None` if no cache is enabled.
+pub(super) fn sum_with_global(
+    ranges: &[RangeInclusive<u128>],
+    rule: Rule,
+    cancel: &CancelToken,
+) -> Option<Result<Option<u128>, Cancelled>> {
+    let mut global = GLOBAL.lock().unwrap_or_else(PoisonError::into_inner);
+    let cache = global.as_mut()?;
+    let result = sum_cached(ranges, rule, cache, cancel);
+    if let Err(err) = cache.save() {
+        tracing::warn!("Could not save the range cache: {}", err);
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_round_trip() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc2025-day02-cache-{}", std::process::id()))
+            .join("ranges.csv");
+        let cancel = CancelToken::new();
+        let mut cache = RangeCache::open(&path).unwrap();
+        let ranges = [11..=22, 998..=1012];
+        assert_eq!(
+            sum_cached(&ranges, Rule::EqualHalves, &mut cache, &cancel),
+            Ok(Some(1043))
+        );
+        cache.save().unwrap();
+
+        let mut cache = RangeCache::open(&path).unwrap();
+        assert_eq!(cache.get(&(998..=1012), Rule::EqualHalves), Some(1010));
+        assert_eq!(cache.get(&(998..=1012), Rule::Repetition), None);
+        // A cached sum is taken as is, even after cancelling.
+        cache.insert(&(11..=22), Rule::EqualHalves, 7);
+        cancel.cancel();
+        assert_eq!(
+            sum_cached(&[11..=22], Rule::EqualHalves, &mut cache, &cancel),
+            Ok(Some(7))
+        );
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        assert_eq!(
+            parse_entry("11,22,repetition,33"),
+            Some(((11, 22, Rule::Repetition), 33))
+        );
+        assert_eq!(parse_entry("11,22,other,33"), None);
+        assert_eq!(parse_entry("11,22,repetition,33,1"), None);
+        assert_eq!(parse_entry(""), None);
+    }
+}
@@ -0,0 +1,296 @@
+use std::fs;
+use std::io;
+use std::ops::RangeInclusive;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+use tracing::{debug, warn};
+
+use super::CHECK_INTERVAL;
+use super::ids::{InvalidIdIter, Rule};
+
+use crate::cancel::{CancelToken, Cancelled};
+
+/// The file the `aoc` binary keeps the checkpoint in with `--checkpoint`.
+pub const CHECKPOINT_FILE: &str = ".aoc/cache/day02_checkpoint.txt";
+
+/// The time between two saves of the checkpoint during a scan.
+pub const SAVE_INTERVAL: Duration = Duration::from_secs(5);
+
+/// The checkpoint file [`solve_rule_cancellable`](super::solve_rule_cancellable)
+/// uses for both parts, once set by [`enable`].
+static GLOBAL: Mutex<Option<PathBuf>> = Mutex::new(None);
+
+/// How far a scan of ranges got, enough to resume it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The rule the IDs are tested for.
+    pub rule: Rule,
+    /// The scanned ranges like `"11-22,95-115"`, so a checkpoint of other
+    /// ranges is not resumed.
+    pub ranges: String,
+    /// The index of the range the scan is in.
+    pub range: usize,
+    /// The next ID to test within that range, `None` once the range is
+    /// finished up to `u128::MAX`, which has no next ID.
+    pub next: Option<u128>,
+    /// The sum of the invalid IDs before `next`.
+    pub sum: u128,
+}
+
+impl Checkpoint {
+    /// Reads a checkpoint written by [`Checkpoint::save`].
+    ///
+    /// # Returns
+    /// The checkpoint, or `None` if there is no such file or it is no checkpoint.
+    pub fn load(path: &Path) -> Option<Checkpoint> {
+        let content = fs::read_to_string(path).ok()?;
+        let mut fields = content.lines().map(|line| line.split_once('='));
+        let mut field = |name: &str| match fields.next()? {
+            Some((key, value)) if key == name => Some(value.to_string()),
+            _ => None,
+        };
+        let rule = match field("rule")?.as_str() {
+            "equal-halves" => Rule::EqualHalves,
+            "repetition" => Rule::Repetition,
+            _ => return None,
+        };
+        Some(Checkpoint {
+            rule,
+            ranges: field("ranges")?,
+            range: field("range")?.parse().ok()?,
+            next: match field("next")?.as_str() {
+                "end" => None,
+                next => Some(next.parse().ok()?),
+            },
+            sum: field("sum")?.parse().ok()?,
+        })
+    }
+
+    /// Writes the checkpoint to `path`, one `key=value` line per field,
+    /// creating the directory if needed. A finished range is written as
+    /// `next=end`.
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let rule = match self.rule {
+            Rule::EqualHalves => "equal-halves",
+            Rule::Repetition => "repetition",
+        };
+        let next = self
+            .next
+            .map_or_else(|| "end".to_string(), |next| next.to_string());
+        fs::write(
+            path,
+            format!(
+                "rule={}\nranges={}\nrange={}\nnext={}\nsum={}\n",
+                rule, self.ranges, self.range, next, self.sum
+            ),
+        )
+    }
+}
+
+/// Sums the IDs within the given ranges invalid by `rule`, saving a
+/// [`Checkpoint`] to `path` every [`SAVE_INTERVAL`] and when `cancel` is
+/// cancelled, and resuming from a checkpoint of the same ranges and rule
+/// found there. The file is removed once the sum is complete or overflows.
+///
+/// A checkpoint that cannot be saved is logged and the scan goes on.
+///
+/// # Returns
+/// The sum, `None` if it overflows `u128`, or [`Cancelled`].
+pub fn sum_resumable(
+    ranges: &[RangeInclusive<u128>],
+    rule: Rule,
+    path: &Path,
+    cancel: &CancelToken,
+) -> Result<Option<u128>, Cancelled> {
+    let fingerprint: Vec<String> = ranges
+        .iter()
+        .map(|range| format!("{}-{}", range.start(), range.end()))
+        .collect();
+    let fingerprint = fingerprint.join(",");
+    let mut state = match Checkpoint::load(path) {
+        Some(state) if state.rule == rule && state.ranges == fingerprint => {
+            debug!(range = state.range, next = ?state.next, "resuming from checkpoint");
+            state
+        }
+        _ => Checkpoint {
+            rule,
+            ranges: fingerprint,
+            range: 0,
+            next: Some(0),
+            sum: 0,
+        },
+    };
+    let save = |state: &Checkpoint| {
+        if let Err(err) = state.save(path) {
+            warn!("Could not save the checkpoint: {}", err);
+        }
+    };
+
+    let mut last_save = Instant::now();
+    while let Some(range) = ranges.get(state.range) {
+        let ids = state
+            .next
+            .map(|next| InvalidIdIter::new(next.max(*range.start())..=*range.end(), rule));
+        for (index, id) in ids.into_iter().flatten().enumerate() {
+            if index.is_multiple_of(CHECK_INTERVAL as usize) {
+                if cancel.is_cancelled() {
+                    save(&state);
+                    return Err(Cancelled);
+                }
+                if last_save.elapsed() >= SAVE_INTERVAL {
+                    save(&state);
+                    last_save = Instant::now();
+                }
+            }
+            let Some(sum) = state.sum.checked_add(id) else {
+                // Summing again would overflow just the same.
+                remove(path);
+                return Ok(None);
+            };
+            state.sum = sum;
+            state.next = id.checked_add(1);
+        }
+        state.range += 1;
+        state.next = Some(0);
+    }
+
+    remove(path);
+    Ok(Some(state.sum))
+}
+
+/// Removes the checkpoint of a finished sum, logging a failure.
+fn remove(path: &Path) {
+    if let Err(err) = fs::remove_file(path)
+        && err.kind() != io::ErrorKind::NotFound
+    {
+        warn!("Could not remove the checkpoint: {}", err);
+    }
+}
+
+/// Makes both parts sum with checkpoints in the file at `path`, see
+/// [`sum_resumable`].
+pub fn enable(path: impl Into<PathBuf>) {
+    *GLOBAL.lock().unwrap_or_else(PoisonError::into_inner) = Some(path.into());
+}
+
+/// Sums like [`sum_resumable`] with the file set by [`enable`].
+///
+/// # Returns
+/// `None` if no checkpoint file is enabled.
+pub(super) fn sum_with_global(
+    ranges: &[RangeInclusive<u128>],
+    rule: Rule,
+    cancel: &CancelToken,
+) -> Option<Result<Option<u128>, Cancelled>> {
+    let path = GLOBAL
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .clone()?;
+    Some(sum_resumable(ranges, rule, &path, cancel))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_resume_after_cancel() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc2025-day02-checkpoint-{}", std::process::id()))
+            .join("checkpoint.txt");
+        let ranges = [11..=22, 95..=115, 998..=1012];
+        let cancel = CancelToken::new();
+        cancel.cancel();
+        assert_eq!(
+            sum_resumable(&ranges, Rule::Repetition, &path, &cancel),
+            Err(Cancelled)
+        );
+        let saved = Checkpoint::load(&path).unwrap();
+        assert_eq!((saved.range, saved.sum), (0, 0));
+        assert_eq!(saved.ranges, "11-22,95-115,998-1012");
+
+        // Pretend the first range and 99 of the second were summed before.
+        let state = Checkpoint {
+            range: 1,
+            next: Some(100),
+            sum: 33 + 99,
+            ..saved
+        };
+        state.save(&path).unwrap();
+        assert_eq!(Checkpoint::load(&path), Some(state));
+        assert_eq!(
+            sum_resumable(&ranges, Rule::Repetition, &path, &CancelToken::new()),
+            Ok(Some(33 + 99 + 111 + 999 + 1010))
+        );
+        assert!(!path.exists());
+
+        // A checkpoint of other ranges or another rule is not resumed.
+        Checkpoint {
+            rule: Rule::EqualHalves,
+            ranges: "11-22,95-115,998-1012".to_string(),
+            range: 2,
+            next: Some(0),
+            sum: 1,
+        }
+        .save(&path)
+        .unwrap();
+        assert_eq!(
+            sum_resumable(&ranges, Rule::Repetition, &path, &CancelToken::new()),
+            Ok(Some(2252))
+        );
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_resume_finished_range() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc2025-day02-finished-{}", std::process::id()))
+            .join("checkpoint.txt");
+        let ranges = [11..=22, 95..=115];
+        // The first range was scanned up to u128::MAX, so nothing of it is left.
+        let state = Checkpoint {
+            rule: Rule::Repetition,
+            ranges: "11-22,95-115".to_string(),
+            range: 0,
+            next: None,
+            sum: 33,
+        };
+        state.save(&path).unwrap();
+        assert!(fs::read_to_string(&path).unwrap().contains("next=end\n"));
+        assert_eq!(Checkpoint::load(&path), Some(state));
+        assert_eq!(
+            sum_resumable(&ranges, Rule::Repetition, &path, &CancelToken::new()),
+            Ok(Some(33 + 99 + 111))
+        );
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_overflow_removes_checkpoint() {
+        let path = std::env::temp_dir()
+            .join(format!("aoc2025-day02-overflow-{}", std::process::id()))
+            .join("checkpoint.txt");
+        let ranges = [11..=22, 95..=115];
+        // The first range was summed, leaving no room for the second.
+        Checkpoint {
+            rule: Rule::Repetition,
+            ranges: "11-22,95-115".to_string(),
+            range: 1,
+            next: Some(0),
+            sum: u128::MAX - 100,
+        }
+        .save(&path)
+        .unwrap();
+        assert_eq!(
+            sum_resumable(&ranges, Rule::Repetition, &path, &CancelToken::new()),
+            Ok(None)
+        );
+        assert!(!path.exists());
+        fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
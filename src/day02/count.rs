@@ -0,0 +1,146 @@
+use super::ids::Rule;
+
+/// Counts the IDs from 1 to `n` invalid by `rule` without enumerating them.
+///
+/// For every number of digits, the IDs made of a block of `b` digits repeated
+/// are counted from the bounds of their blocks alone, like in
+/// [`repeated_block_ids`](super::repeated_block_ids). For
+/// [`Rule::Repetition`], an ID like `111111` repeats the blocks `1`, `11` and
+/// `111`, so only the IDs whose shortest block has `b` digits are added,
+/// found by subtracting the counts of the shorter blocks dividing `b`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day02::count::count_invalid_up_to;
+/// use aoc2025::day02::ids::Rule;
+///
+/// assert_eq!(count_invalid_up_to(1_000, Rule::EqualHalves), 9);
+/// assert_eq!(count_invalid_up_to(1_000, Rule::Repetition), 18);
+/// // How many invalid IDs exist below 10^12.
+/// assert_eq!(count_invalid_up_to(999_999_999_999, Rule::EqualHalves), 999_999);
+/// ```
+pub fn count_invalid_up_to(n: u128, rule: Rule) -> u128 {
+    let digits = n.checked_ilog10().map_or(0, |log| log + 1);
+    (1..=digits)
+        .map(|digits| match rule {
+            Rule::EqualHalves if digits % 2 == 0 => count_repeated_blocks(n, digits, digits / 2),
+            Rule::EqualHalves => 0,
+            Rule::Repetition => count_repetitions(n, digits),
+        })
+        .sum()
+}
+
+/// Returns the invalid ID by `rule` at position `k`, counting from 0 like
+/// [`Iterator::nth`], by a binary search over [`count_invalid_up_to`].
+///
+/// # Returns
+/// The ID, or `None` if there are at most `k` invalid IDs up to `u128::MAX`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day02::count::nth_invalid_id;
+/// use aoc2025::day02::ids::Rule;
+///
+/// assert_eq!(nth_invalid_id(0, Rule::EqualHalves), Some(11));
+/// assert_eq!(nth_invalid_id(9, Rule::EqualHalves), Some(1010));
+/// assert_eq!(nth_invalid_id(9, Rule::Repetition), Some(111));
+/// assert_eq!(nth_invalid_id(u128::MAX, Rule::Repetition), None);
+/// ```
+pub fn nth_invalid_id(k: u128, rule: Rule) -> Option<u128> {
+    if count_invalid_up_to(u128::MAX, rule) <= k {
+        return None;
+    }
+    // The smallest ID with more than `k` invalid IDs up to it.
+    let (mut low, mut high) = (0, u128::MAX);
+    while low < high {
+        let middle = low + (high - low) / 2;
+        if count_invalid_up_to(middle, rule) > k {
+            high = middle;
+        } else {
+            low = middle + 1;
+        }
+    }
+    Some(low)
+}
+
+/// Counts the IDs of `digits` digits up to `n` that are a repetition by
+/// [`Rule::Repetition`].
+fn count_repetitions(n: u128, digits: u32) -> u128 {
+    // The IDs whose shortest block has as many digits as the key.
+    let mut by_shortest_block: Vec<(u32, u128)> = Vec::new();
+    for block_digits in (1..digits).filter(|block_digits| digits.is_multiple_of(*block_digits)) {
+        let shorter: u128 = by_shortest_block
+            .iter()
+            .filter(|(shorter_digits, _)| block_digits.is_multiple_of(*shorter_digits))
+            .map(|(_, count)| count)
+            .sum();
+        let count = count_repeated_blocks(n, digits, block_digits) - shorter;
+        by_shortest_block.push((block_digits, count));
+    }
+    by_shortest_block.iter().map(|(_, count)| count).sum()
+}
+
+/// Counts the IDs up to `n` of `digits` digits made of a block of
+/// `block_digits` digits repeated.
+fn count_repeated_blocks(n: u128, digits: u32, block_digits: u32) -> u128 {
+    // 1, 1001, 1001001, ... or `None` once the IDs have more digits than a u128.
+    let multiplier: Option<u128> = (1..digits / block_digits).try_fold(1_u128, |multiplier, _| {
+        multiplier
+            .checked_mul(10_u128.pow(block_digits))?
+            .checked_add(1)
+    });
+    let Some(multiplier) = multiplier else {
+        return 0;
+    };
+    let block_min = 10_u128.pow(block_digits - 1);
+    let block_max = 10_u128.pow(block_digits) - 1;
+    match block_max.min(n / multiplier) {
+        last if last >= block_min => last - block_min + 1,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day02::ids::InvalidIdIter;
+
+    #[test]
+    fn test_count_matches_enumeration() {
+        for rule in [Rule::EqualHalves, Rule::Repetition] {
+            let ids: Vec<u128> = InvalidIdIter::new(0..=1_200_000, rule).collect();
+            for n in [
+                0, 10, 11, 12, 99, 100, 1_000, 123_456, 999_999, 1_000_000, 1_200_000,
+            ] {
+                let count = ids.iter().filter(|id| **id <= n).count();
+                assert_eq!(
+                    count_invalid_up_to(n, rule),
+                    count as u128,
+                    "{:?} {}",
+                    rule,
+                    n
+                );
+            }
+            for k in [0, 1, 50, 500, ids.len() - 1] {
+                assert_eq!(nth_invalid_id(k as u128, rule), Some(ids[k]));
+            }
+        }
+    }
+
+    #[test]
+    fn test_count_huge() {
+        assert_eq!(
+            count_invalid_up_to(9_999_999_999, Rule::EqualHalves),
+            99_999
+        );
+        assert_eq!(
+            count_invalid_up_to(9_999_999_999, Rule::Repetition),
+            101_088
+        );
+        let top = u128::MAX - 10_u128.pow(27)..=u128::MAX;
+        let last = InvalidIdIter::new(top, Rule::Repetition).last();
+        let all = count_invalid_up_to(u128::MAX, Rule::Repetition);
+        assert_eq!(nth_invalid_id(all - 1, Rule::Repetition), last);
+        assert_eq!(nth_invalid_id(all, Rule::Repetition), None);
+    }
+}
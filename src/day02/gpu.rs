@@ -0,0 +1,409 @@
+use std::future::Future;
+use std::ops::RangeInclusive;
+use std::pin::pin;
+use std::slice;
+use std::sync::{OnceLock, mpsc};
+use std::task::{Context, Poll, Waker};
+use std::thread;
+
+use tracing::debug;
+
+use super::ids::{InvalidIdIter, Rule};
+use super::sum_ids;
+
+use crate::cancel::{CancelToken, Cancelled};
+use crate::progress;
+
+/// The shortest range tested on the GPU, shorter ones are summed on the CPU
+/// where the dispatch would cost more than it saves.
+pub const GPU_MIN_RANGE: u128 = 1 << 20;
+
+/// The largest ID the shader can test. It splits an ID into two `u32` of 9
+/// decimal digits each, as WGSL has no 64-bit integers.
+pub const GPU_MAX_ID: u128 = 999_999_999_999_999_999;
+
+/// The number of invocations per workgroup, as in the shader.
+const WORKGROUP_SIZE: u32 = 256;
+
+/// The number of IDs tested per dispatch, the most workgroups a dimension
+/// may have by default times [`WORKGROUP_SIZE`].
+const CHUNK_IDS: u32 = 65_535 * WORKGROUP_SIZE;
+
+/// The number of invalid IDs a dispatch can report. Invalid IDs are rare, so
+/// this is only exceeded by ranges of short IDs, which fall back to the CPU.
+const CAPACITY: u32 = 1 << 16;
+
+/// The IDs are split into a high and a low part at this power of ten.
+const LOW_LIMIT: u128 = 1_000_000_000;
+
+/// Tests every ID of a chunk, one per invocation, and appends the invalid
+/// ones to `ids`.
+const SHADER: &str = r#"
+struct Params {
+    base_high: u32,
+    base_low: u32,
+    count: u32,
+    rule: u32,
+    capacity: u32,
+}
+
+@group(0) @binding(0) var<uniform> params: Params;
+@group(0) @binding(1) var<storage, read_write> found: atomic<u32>;
+@group(0) @binding(2) var<storage, read_write> ids: array<vec2<u32>>;
+
+const LOW_LIMIT: u32 = 1000000000u;
+
+@compute @workgroup_size(256)
+fn main(@builtin(global_invocation_id) invocation: vec3<u32>) {
+    if (invocation.x >= params.count) {
+        return;
+    }
+    let sum = params.base_low + invocation.x;
+    let high = params.base_high + sum / LOW_LIMIT;
+    let low = sum % LOW_LIMIT;
+
+    // The digits from the least significant one, which does not matter for
+    // comparing repeated blocks.
+    var digits: array<u32, 18>;
+    var length = 0u;
+    var rest = low;
+    if (high > 0u) {
+        for (var i = 0u; i < 9u; i++) {
+            digits[length] = rest % 10u;
+            rest = rest / 10u;
+            length++;
+        }
+        rest = high;
+    }
+    while (rest > 0u) {
+        digits[length] = rest % 10u;
+        rest = rest / 10u;
+        length++;
+    }
+
+    var invalid = false;
+    for (var block = 1u; block < length && !invalid; block++) {
+        // Rule 0 only allows two halves, rule 1 any number of blocks.
+        if (length % block != 0u || (params.rule == 0u && block * 2u != length)) {
+            continue;
+        }
+        var repeated = true;
+        for (var i = block; i < length; i++) {
+            if (digits[i] != digits[i - block]) {
+                repeated = false;
+                break;
+            }
+        }
+        invalid = repeated;
+    }
+
+    if (invalid) {
+        let slot = atomicAdd(&found, 1u);
+        if (slot < params.capacity) {
+            ids[slot] = vec2<u32>(high, low);
+        }
+    }
+}
+"#;
+
+/// Tests every ID of a range for a [`Rule`] in a wgpu compute shader.
+///
+/// This is a brute force like [`matching_ids`](super::ids::matching_ids).
+/// With the `gpu` feature, the day 2 solvers use it through
+/// [`sum_invalid_ids`] for ranges of at least [`GPU_MIN_RANGE`] IDs; the
+/// constructive [`InvalidIdIter`] skips the valid IDs instead of testing them.
+pub struct GpuScanner {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+    pipeline: wgpu::ComputePipeline,
+    bind_group: wgpu::BindGroup,
+    params: wgpu::Buffer,
+    found: wgpu::Buffer,
+    ids: wgpu::Buffer,
+    readback: wgpu::Buffer,
+}
+
+impl GpuScanner {
+    /// Sets up the compute shader on the default adapter.
+    ///
+    /// # Returns
+    /// The scanner, or `None` if there is no usable adapter, e.g. in a
+    /// container without a GPU.
+    pub fn new() -> Option<GpuScanner> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..Default::default()
+        }))?;
+        let (device, queue) = block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: Some("day02"),
+                ..Default::default()
+            },
+            None,
+        ))
+        .ok()?;
+        debug!(adapter = ?adapter.get_info().name, "GPU scanner ready");
+
+        let module = device.create_shader_module(wgpu::ShaderModuleDescriptor {
+            label: Some("day02"),
+            source: wgpu::ShaderSource::Wgsl(SHADER.into()),
+        });
+        let pipeline = device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+            label: Some("day02"),
+            layout: None,
+            module: &module,
+            entry_point: Some("main"),
+            compilation_options: Default::default(),
+            cache: None,
+        });
+
+        let buffer = |size: u64, usage: wgpu::BufferUsages| {
+            device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("day02"),
+                size,
+                usage,
+                mapped_at_creation: false,
+            })
+        };
+        let ids_size = u64::from(CAPACITY) * 8;
+        let params = buffer(
+            32,
+            wgpu::BufferUsages::UNIFORM | wgpu::BufferUsages::COPY_DST,
+        );
+        let found = buffer(
+            4,
+            wgpu::BufferUsages::STORAGE
+                | wgpu::BufferUsages::COPY_DST
+                | wgpu::BufferUsages::COPY_SRC,
+        );
+        let ids = buffer(
+            ids_size,
+            wgpu::BufferUsages::STORAGE | wgpu::BufferUsages::COPY_SRC,
+        );
+        let readback = buffer(
+            4 + ids_size,
+            wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+        );
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: Some("day02"),
+            layout: &pipeline.get_bind_group_layout(0),
+            entries: &[
+                wgpu::BindGroupEntry {
+                    binding: 0,
+                    resource: params.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 1,
+                    resource: found.as_entire_binding(),
+                },
+                wgpu::BindGroupEntry {
+                    binding: 2,
+                    resource: ids.as_entire_binding(),
+                },
+            ],
+        });
+
+        Some(GpuScanner {
+            device,
+            queue,
+            pipeline,
+            bind_group,
+            params,
+            found,
+            ids,
+            readback,
+        })
+    }
+
+    /// Returns the IDs within `range` invalid by `rule` in ascending order,
+    /// checking `cancel` before every dispatch of [`CHUNK_IDS`] IDs.
+    ///
+    /// # Returns
+    /// The IDs, `None` if the range exceeds [`GPU_MAX_ID`] or a dispatch
+    /// found more invalid IDs than it can report, or [`Cancelled`].
+    pub fn invalid_ids(
+        &self,
+        range: RangeInclusive<u128>,
+        rule: Rule,
+        cancel: &CancelToken,
+    ) -> Result<Option<Vec<u128>>, Cancelled> {
+        if *range.end() > GPU_MAX_ID {
+            return Ok(None);
+        }
+        let mut ids: Vec<u128> = Vec::new();
+        let mut start = *range.start();
+        while start <= *range.end() {
+            cancel.check()?;
+            let count = (range.end() - start + 1).min(u128::from(CHUNK_IDS)) as u32;
+            let Some(chunk) = self.dispatch(start, count, rule) else {
+                return Ok(None);
+            };
+            ids.extend(chunk);
+            start += u128::from(count);
+        }
+        ids.sort_unstable();
+        Ok(Some(ids))
+    }
+
+    /// Tests the `count` IDs from `start` in one dispatch.
+    ///
+    /// # Returns
+    /// The invalid IDs in any order, or `None` if there are more than [`CAPACITY`].
+    fn dispatch(&self, start: u128, count: u32, rule: Rule) -> Option<Vec<u128>> {
+        let rule = match rule {
+            Rule::EqualHalves => 0,
+            Rule::Repetition => 1,
+        };
+        let params: Vec<u8> = [
+            (start / LOW_LIMIT) as u32,
+            (start % LOW_LIMIT) as u32,
+            count,
+            rule,
+            CAPACITY,
+            0,
+            0,
+            0,
+        ]
+        .iter()
+        .flat_map(|value| value.to_le_bytes())
+        .collect();
+        self.queue.write_buffer(&self.params, 0, &params);
+        self.queue
+            .write_buffer(&self.found, 0, &0_u32.to_le_bytes());
+
+        let mut encoder = self.device.create_command_encoder(&Default::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&Default::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &self.bind_group, &[]);
+            pass.dispatch_workgroups(count.div_ceil(WORKGROUP_SIZE), 1, 1);
+        }
+        encoder.copy_buffer_to_buffer(&self.found, 0, &self.readback, 0, 4);
+        encoder.copy_buffer_to_buffer(&self.ids, 0, &self.readback, 4, self.ids.size());
+        self.queue.submit([encoder.finish()]);
+
+        let slice = self.readback.slice(..);
+        let (sender, receiver) = mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = sender.send(result);
+        });
+        self.device.poll(wgpu::Maintain::Wait);
+        receiver.recv().ok()?.ok()?;
+
+        let ids = {
+            let bytes = slice.get_mapped_range();
+            let word = |index: usize| {
+                let bytes = &bytes[index * 4..index * 4 + 4];
+                u128::from(u32::from_le_bytes(bytes.try_into().unwrap()))
+            };
+            let found = word(0) as u32;
+            (found <= CAPACITY).then(|| {
+                (0..found as usize)
+                    .map(|slot| word(1 + 2 * slot) * LOW_LIMIT + word(2 + 2 * slot))
+                    .collect()
+            })
+        };
+        self.readback.unmap();
+        ids
+    }
+}
+
+/// Returns the process-wide scanner, set up on first use.
+fn global() -> Option<&'static GpuScanner> {
+    static SCANNER: OnceLock<Option<GpuScanner>> = OnceLock::new();
+    SCANNER.get_or_init(GpuScanner::new).as_ref()
+}
+
+/// Sums the IDs within the given ranges invalid by `rule`, testing ranges of
+/// at least [`GPU_MIN_RANGE`] IDs on the GPU and all others, or all ranges
+/// without a usable adapter, with the constructive [`InvalidIdIter`].
+///
+/// # Returns
+/// The sum, `None` if it overflows `u128`, or [`Cancelled`].
+///
+/// # Examples
+/// ```
+/// use aoc2025::cancel::CancelToken;
+/// use aoc2025::day02::gpu::sum_invalid_ids;
+/// use aoc2025::day02::ids::Rule;
+///
+/// let sum = sum_invalid_ids(&[11..=22, 95..=115], Rule::Repetition, &CancelToken::new());
+/// assert_eq!(sum, Ok(Some(243)));
+/// ```
+pub fn sum_invalid_ids(
+    ranges: &[RangeInclusive<u128>],
+    rule: Rule,
+    cancel: &CancelToken,
+) -> Result<Option<u128>, Cancelled> {
+    let mut total: u128 = 0;
+    for (index, range) in ranges.iter().enumerate() {
+        let on_gpu = match global() {
+            Some(scanner) if range.end().saturating_sub(*range.start()) >= GPU_MIN_RANGE => {
+                scanner.invalid_ids(range.clone(), rule, cancel)?
+            }
+            _ => None,
+        };
+        let sum = match on_gpu {
+            Some(ids) => ids.iter().try_fold(0_u128, |sum, id| sum.checked_add(*id)),
+            None => {
+                let ids = |range| InvalidIdIter::new(range, rule);
+                sum_ids(slice::from_ref(range), cancel, ids)?
+            }
+        };
+        let Some(next) = sum.and_then(|sum| total.checked_add(sum)) else {
+            return Ok(None);
+        };
+        total = next;
+        progress::report(index as u64 + 1, ranges.len() as u64);
+    }
+    Ok(Some(total))
+}
+
+/// Drives a future to completion on the current thread. The futures of wgpu
+/// on native backends are ready right away or after polling the device, so
+/// no executor is needed.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+        thread::yield_now();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gpu_matches_cpu() {
+        let Some(scanner) = global() else {
+            // Without an adapter, e.g. in CI, only the CPU fallback is tested.
+            let cancel = CancelToken::new();
+            let sum = sum_invalid_ids(&[0..=2_000_000], Rule::EqualHalves, &cancel);
+            let expected: u128 = InvalidIdIter::new(0..=2_000_000, Rule::EqualHalves).sum();
+            assert_eq!(sum, Ok(Some(expected)));
+            return;
+        };
+        let cancel = CancelToken::new();
+        for rule in [Rule::EqualHalves, Rule::Repetition] {
+            for range in [
+                1_000_000..=40_000_000,
+                999_990_000..=1_000_010_000,
+                GPU_MAX_ID - 10_000_000..=GPU_MAX_ID,
+            ] {
+                assert_eq!(
+                    scanner.invalid_ids(range.clone(), rule, &cancel),
+                    Ok(Some(InvalidIdIter::new(range, rule).collect()))
+                );
+            }
+        }
+        assert_eq!(
+            scanner.invalid_ids(0..=GPU_MAX_ID + 1, Rule::Repetition, &cancel),
+            Ok(None)
+        );
+    }
+}
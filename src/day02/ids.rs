@@ -0,0 +1,361 @@
+use std::iter::FusedIterator;
+use std::ops::RangeInclusive;
+
+use super::{digit_counts, repeated_block_ids, sum_ids};
+
+use crate::cancel::{CancelToken, Cancelled};
+use crate::integer::Integer;
+
+/// Checks whether the first half of the digits of an ID is identical to the
+/// second half, the rule of part 1.
+///
+/// # Arguments
+///
+/// * `id` - The ID as a string
+///
+/// # Returns
+///
+/// `true` if the ID is invalid by the rule of part 1, `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::ids::has_equal_halves;
+///
+/// assert!(has_equal_halves("1010"));
+/// assert!(!has_equal_halves("111"));
+/// ```
+pub fn has_equal_halves(id: &str) -> bool {
+    id[0..(id.len() / 2)] == id[(id.len() / 2)..(id.len())]
+}
+
+/// Checks whether an ID is made only of some sequence of digits repeated at
+/// least twice, the rule of part 2.
+///
+/// So, 12341234 (1234 two times), 123123123 (123 three times), 1212121212
+/// (12 five times), and 1111111 (1 seven times) are all invalid IDs.
+///
+/// # Arguments
+///
+/// * `id` - The ID as a string slice
+///
+/// # Returns
+///
+/// `true` if the ID is invalid by the rule of part 2, `false` otherwise.
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::ids::is_repetition;
+///
+/// assert!(is_repetition("123123123"));
+/// assert!(!is_repetition("1231"));
+/// ```
+pub fn is_repetition(id: &str) -> bool {
+    let length: usize = id.len();
+
+    'elements_loop: for elements in 2..=length {
+        if !length.is_multiple_of(elements) {
+            continue;
+        }
+
+        let element_length = length / elements;
+
+        for test_element in 1..elements {
+            if id[0..element_length]
+                != id[(element_length * test_element)
+                    ..((element_length * test_element) + element_length)]
+            {
+                continue 'elements_loop;
+            }
+        }
+
+        return true;
+    }
+
+    false
+}
+
+/// The rule deciding which IDs are invalid, one per part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Rule {
+    /// The first half of the digits equals the second half, see [`has_equal_halves`].
+    EqualHalves,
+    /// The digits are a block repeated at least twice, see [`is_repetition`].
+    Repetition,
+}
+
+impl Rule {
+    /// Checks whether an ID, given as its digits, is invalid by this rule.
+    pub fn matches(self, id: &str) -> bool {
+        match self {
+            Rule::EqualHalves => has_equal_halves(id),
+            Rule::Repetition => is_repetition(id),
+        }
+    }
+
+    /// Returns how often a block may be repeated in an invalid ID of `digits` digits.
+    fn repeats(self, digits: u32) -> impl Iterator<Item = u32> {
+        let most = match self {
+            Rule::EqualHalves => 2,
+            Rule::Repetition => digits,
+        };
+        (2..=most).filter(move |repeats| digits.is_multiple_of(*repeats))
+    }
+}
+
+/// A lazy iterator over the invalid IDs within a range in ascending order,
+/// returned by `invalid_ids_in` of both parts.
+///
+/// Every call to `next` constructs the smallest invalid ID above the last one
+/// from repeated digit blocks with [`repeated_block_ids`], trying every
+/// number of repeats the [`Rule`] allows, and stops at the end of the range.
+/// Nothing is collected, so `take`, `count` or `sum` on a range holding
+/// millions of invalid IDs needs no memory for them.
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::ids::{InvalidIdIter, Rule};
+///
+/// let ids = InvalidIdIter::new(95..=1_000_000, Rule::Repetition);
+/// assert_eq!(ids.take(3).collect::<Vec<u128>>(), vec![99, 111, 222]);
+/// assert_eq!(InvalidIdIter::new(1..=u128::MAX, Rule::EqualHalves).nth(8), Some(99));
+/// ```
+#[derive(Debug, Clone)]
+pub struct InvalidIdIter {
+    /// The smallest ID not yielded yet, `None` once the range is exhausted.
+    next: Option<u128>,
+    end: u128,
+    rule: Rule,
+}
+
+impl InvalidIdIter {
+    /// Creates an iterator over the IDs within `range` invalid by `rule`.
+    pub fn new(range: RangeInclusive<u128>, rule: Rule) -> InvalidIdIter {
+        InvalidIdIter {
+            next: Some(*range.start()),
+            end: *range.end(),
+            rule,
+        }
+    }
+}
+
+impl Iterator for InvalidIdIter {
+    type Item = u128;
+
+    fn next(&mut self) -> Option<u128> {
+        let range = self.next?..=self.end;
+        // The digit counts ascend, so the first one with an invalid ID holds the smallest.
+        let id = digit_counts(&range).find_map(|digits| {
+            self.rule
+                .repeats(digits)
+                .filter_map(|repeats| {
+                    repeated_block_ids(range.clone(), digits / repeats, repeats).next()
+                })
+                .min()
+        });
+        self.next = id.and_then(|id| id.checked_add(1));
+        id
+    }
+}
+
+impl FusedIterator for InvalidIdIter {}
+
+/// Lazily yields the IDs within the given ranges invalid by `rule`, range by
+/// range, with an [`InvalidIdIter`] per range.
+///
+/// For the ranges parsed by [`Day02`](super::Day02), which are merged and
+/// sorted, the IDs come in ascending order, so the first and last are the
+/// smallest and largest invalid ID.
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::ids::{Rule, invalid_ids};
+///
+/// let ranges = [11..=22, 95..=115];
+/// assert_eq!(invalid_ids(&ranges, Rule::Repetition).last(), Some(111));
+/// ```
+pub fn invalid_ids(ranges: &[RangeInclusive<u128>], rule: Rule) -> impl Iterator<Item = u128> + '_ {
+    ranges
+        .iter()
+        .flat_map(move |range| InvalidIdIter::new(range.clone(), rule))
+}
+
+/// Counts the IDs within the given ranges invalid by `rule`, like [`invalid_ids`].
+pub fn count_invalid_ids(ranges: &[RangeInclusive<u128>], rule: Rule) -> usize {
+    invalid_ids(ranges, rule).count()
+}
+
+/// Sums the IDs within the given ranges invalid by `rule` in any [`Integer`]
+/// type, checking `cancel` before every range and every
+/// [`CHECK_INTERVAL`](super::CHECK_INTERVAL) IDs.
+///
+/// With the `parallel` feature, every range is split into chunks summed on
+/// all cores; the partial sums are added up in input order, so the result
+/// is the same as without it.
+///
+/// # Returns
+///
+/// The sum, `None` if it overflows `N`, or [`Cancelled`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::cancel::CancelToken;
+/// use aoc2025::day02::ids::{Rule, sum_invalid_ids};
+///
+/// let sum = sum_invalid_ids::<i64>(&[95..=115], Rule::EqualHalves, &CancelToken::new());
+/// assert_eq!(sum, Ok(Some(99)));
+/// ```
+pub fn sum_invalid_ids<N: Integer>(
+    ranges: &[RangeInclusive<u128>],
+    rule: Rule,
+    cancel: &CancelToken,
+) -> Result<Option<N>, Cancelled> {
+    sum_ids(ranges, cancel, |range| InvalidIdIter::new(range, rule))
+}
+
+/// Lazily yields the IDs within a range whose digits match `predicate`, in
+/// ascending order.
+///
+/// Unlike the constructive [`InvalidIdIter`], this tests every
+/// ID of the range, so it works with any predicate but takes as long as the
+/// range is long.
+///
+/// The digits of every ID are written to a buffer on the stack, so testing
+/// an ID allocates nothing.
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::ids::{has_equal_halves, matching_ids};
+///
+/// let ids: Vec<u128> = matching_ids(95..=1012, has_equal_halves).collect();
+/// assert_eq!(ids, vec![99, 1010]);
+/// ```
+pub fn matching_ids<P>(range: RangeInclusive<u128>, predicate: P) -> impl Iterator<Item = u128>
+where
+    P: Fn(&str) -> bool,
+{
+    let mut buffer = [0; MAX_DIGITS];
+    range.filter(move |id| predicate(write_digits(*id, &mut buffer)))
+}
+
+/// The number of digits of `u128::MAX`, the longest ID.
+const MAX_DIGITS: usize = 39;
+
+/// Writes the decimal digits of `id` to the end of `buffer`, without leading zeros.
+///
+/// # Returns
+/// The digits, borrowed from `buffer`.
+fn write_digits(id: u128, buffer: &mut [u8; MAX_DIGITS]) -> &str {
+    let mut start = MAX_DIGITS;
+    // Dividing a u128 is much slower than a u64, so only the digits beyond
+    // the range of a u64 are split off with it.
+    let mut wide = id;
+    while wide > u128::from(u64::MAX) {
+        start -= 1;
+        buffer[start] = b'0' + (wide % 10) as u8;
+        wide /= 10;
+    }
+    let mut rest = wide as u64;
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (rest % 10) as u8;
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    std::str::from_utf8(&buffer[start..]).expect("only ASCII digits were written")
+}
+
+/// Sums the IDs within the given ranges whose digits match `predicate`, in
+/// any [`Integer`] type, stopping as soon as `cancel` is cancelled.
+///
+/// Like [`matching_ids`], every ID is tested, which makes this a driver for
+/// custom rules rather than for the two parts, whose `sum_invalid_ids` are
+/// much faster.
+///
+/// # Returns
+///
+/// The sum, `None` if it overflows `N`, or [`Cancelled`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::cancel::CancelToken;
+/// use aoc2025::day02::ids::sum_matching;
+///
+/// let palindromes = |id: &str| id.bytes().eq(id.bytes().rev());
+/// let sum = sum_matching::<u128, _>(&[95..=125], &CancelToken::new(), palindromes);
+/// assert_eq!(sum, Ok(Some(99 + 101 + 111 + 121)));
+/// ```
+pub fn sum_matching<N, P>(
+    ranges: &[RangeInclusive<u128>],
+    cancel: &CancelToken,
+    predicate: P,
+) -> Result<Option<N>, Cancelled>
+where
+    N: Integer,
+    P: Fn(&str) -> bool + Sync,
+{
+    sum_ids(ranges, cancel, |range| matching_ids(range, &predicate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day02::{Day02, part1, part2};
+    use crate::solver::Solver;
+
+    #[test]
+    fn test_write_digits() {
+        let mut buffer = [0; MAX_DIGITS];
+        for id in [0, 7, 10, 1188511885, u128::from(u64::MAX), u128::MAX] {
+            assert_eq!(write_digits(id, &mut buffer), id.to_string());
+        }
+        assert_eq!(write_digits(42, &mut buffer), "42");
+    }
+
+    #[test]
+    fn test_invalid_id_iter_matches_rule() {
+        for rule in [Rule::EqualHalves, Rule::Repetition] {
+            for range in [
+                0..=120_000,
+                998..=1012,
+                RangeInclusive::new(5, 4),
+                u128::MAX - 10_000..=u128::MAX,
+            ] {
+                assert_eq!(
+                    InvalidIdIter::new(range.clone(), rule).collect::<Vec<u128>>(),
+                    matching_ids(range, |id| rule.matches(id)).collect::<Vec<u128>>()
+                );
+            }
+        }
+        let mut ids = InvalidIdIter::new(u128::MAX - 1..=u128::MAX, Rule::Repetition);
+        assert_eq!(ids.next(), None);
+        assert_eq!(ids.next(), None);
+    }
+
+    #[test]
+    fn test_sum_matching_agrees_with_parts() {
+        let ranges = Day02::parse("11-22,95-115,998-1012,222220-222224").unwrap();
+        let cancel = CancelToken::new();
+        assert_eq!(
+            sum_matching::<u128, _>(&ranges, &cancel, has_equal_halves),
+            part1::sum_invalid_ids(&ranges, &cancel)
+        );
+        assert_eq!(
+            sum_matching::<u128, _>(&ranges, &cancel, is_repetition),
+            part2::sum_invalid_ids(&ranges, &cancel)
+        );
+        cancel.cancel();
+        assert_eq!(
+            sum_matching::<u128, _>(&ranges, &cancel, |_| true),
+            Err(Cancelled)
+        );
+    }
+}
@@ -1,2 +1,689 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use tracing::debug;
+
+use crate::cancel::{self, CancelToken, Cancelled};
+use crate::check::Diagnostic;
+use crate::integer::Integer;
+use crate::progress;
+use crate::solver::{Answer, Metadata, Part2, Solver};
+
+use ids::Rule;
+
+#[cfg(feature = "cli")]
+pub mod cache;
+#[cfg(feature = "cli")]
+pub mod checkpoint;
+pub mod count;
+#[cfg(feature = "gpu")]
+pub mod gpu;
+pub mod ids;
 pub mod part1;
 pub mod part2;
+
+/// The number of invalid IDs both parts generate between two checks of their
+/// [`CancelToken`](crate::cancel::CancelToken).
+pub const CHECK_INTERVAL: i64 = 1 << 16;
+
+/// The solution of day 2: summing the invalid IDs in a list of ID ranges.
+pub struct Day02;
+
+impl Solver for Day02 {
+    /// The inclusive ID ranges, merged and sorted by [`parse_ranges`].
+    ///
+    /// IDs are `u128`, so custom inputs may hold IDs far beyond the 10 digits
+    /// of the puzzle input.
+    type Parsed = Vec<RangeInclusive<u128>>;
+
+    const METADATA: Metadata = Metadata {
+        title: "Gift Shop",
+        example: "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124",
+        example_answers: [Some("1227775554"), Some("4174379265")],
+        complexity: "Both parts construct the invalid IDs from repeated digit blocks instead of testing every ID: O(invalid IDs), about the square root of the range length.",
+    };
+
+    /// Parses comma-separated ranges like `"11-22,95-115"`, merging
+    /// overlapping ones, see [`parse_ranges`].
+    fn parse(input: &str) -> Result<Vec<RangeInclusive<u128>>, Diagnostic> {
+        parse_ranges(input, ParseOptions::default()).map_err(Diagnostic::from)
+    }
+
+    fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+        validate(input)
+    }
+
+    fn part1(ranges: &Vec<RangeInclusive<u128>>) -> Answer {
+        part1::solve(ranges)
+    }
+}
+
+impl Part2 for Day02 {
+    fn part2(ranges: &Vec<RangeInclusive<u128>>) -> Answer {
+        part2::solve(ranges)
+    }
+}
+
+crate::register_day!(
+    year: 2025,
+    day: 2,
+    solver: Day02,
+    parts: [1, 2],
+    generator: crate::generators::day02,
+);
+
+/// Sums the IDs within the given ranges invalid by `rule`, the solution of
+/// both parts, stopping as soon as the process-wide [`cancel::global`] token
+/// is cancelled (e.g. by Ctrl-C in the `aoc` binary).
+///
+/// # Returns
+///
+/// An [`Answer`] holding the sum, or an empty answer if the token was
+/// cancelled before the scan finished. The runner reports such a run as
+/// cancelled, and the fallible API, e.g. [`crate::solve`], returns
+/// [`SolveError::Cancelled`](crate::SolveError::Cancelled) instead; use
+/// [`solve_rule_cancellable`] to get [`Cancelled`] directly.
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::ids::Rule;
+/// use aoc2025::day02::solve_rule;
+///
+/// assert_eq!(solve_rule(&[95..=115], Rule::Repetition).to_string(), "210");
+/// ```
+pub fn solve_rule(ranges: &[RangeInclusive<u128>], rule: Rule) -> Answer {
+    solve_rule_cancellable(ranges, rule, cancel::global())
+        .unwrap_or_else(|Cancelled| Answer::Text(String::new()))
+}
+
+/// Like [`solve_rule`], but stops scanning as soon as `cancel` is cancelled.
+///
+/// The token is checked before every block of [`CHECK_INTERVAL`] IDs, so
+/// even a single huge range can be cancelled quickly. Once a range cache is
+/// enabled with `cache::enable`, ranges summed before are taken from it, or
+/// once checkpoints are enabled with `checkpoint::enable`, an interrupted sum
+/// is resumed. With the `gpu` feature, ranges of at least
+/// [`gpu::GPU_MIN_RANGE`] IDs are tested on the GPU, see
+/// [`gpu::sum_invalid_ids`], falling back to the CPU without a usable adapter.
+///
+/// The sum is taken in `u128`. Only IDs of about 39 digits can make it
+/// overflow; the sum is then taken again as a `BigUint` with the `bigint`
+/// feature, and reported as the text [`OVERFLOW`] without it.
+///
+/// # Returns
+///
+/// An [`Answer`] holding the sum, or [`Cancelled`].
+pub fn solve_rule_cancellable(
+    ranges: &[RangeInclusive<u128>],
+    rule: Rule,
+    cancel: &CancelToken,
+) -> Result<Answer, Cancelled> {
+    #[cfg(feature = "cli")]
+    let sum = match cache::sum_with_global(ranges, rule, cancel)
+        .or_else(|| checkpoint::sum_with_global(ranges, rule, cancel))
+    {
+        Some(sum) => sum?,
+        None => sum_u128(ranges, rule, cancel)?,
+    };
+    #[cfg(not(feature = "cli"))]
+    let sum = sum_u128(ranges, rule, cancel)?;
+    if let Some(sum) = sum {
+        return Ok(sum.into());
+    }
+
+    #[cfg(feature = "bigint")]
+    if let Some(sum) = ids::sum_invalid_ids::<num_bigint::BigUint>(ranges, rule, cancel)? {
+        return Ok(sum.into());
+    }
+    Ok(Answer::Text(OVERFLOW.to_string()))
+}
+
+/// Sums the IDs within the given ranges invalid by `rule` in `u128`, on the
+/// GPU for long ranges with the `gpu` feature.
+///
+/// # Returns
+/// The sum, `None` if it overflows `u128`, or [`Cancelled`].
+fn sum_u128(
+    ranges: &[RangeInclusive<u128>],
+    rule: Rule,
+    cancel: &CancelToken,
+) -> Result<Option<u128>, Cancelled> {
+    #[cfg(feature = "gpu")]
+    return gpu::sum_invalid_ids(ranges, rule, cancel);
+    #[cfg(not(feature = "gpu"))]
+    return ids::sum_invalid_ids::<u128>(ranges, rule, cancel);
+}
+
+/// The answer of [`solve_rule_cancellable`] if the sum overflows `u128`
+/// without the `bigint` feature.
+pub const OVERFLOW: &str = "the sum of the invalid IDs overflows u128";
+
+/// How [`parse_ranges`] treats IDs contained in several ranges.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overlaps {
+    /// Overlapping and adjacent ranges are merged, so every ID is counted once.
+    #[default]
+    Merge,
+    /// The ranges are kept as given, so an ID is counted once per range
+    /// containing it, like in earlier versions.
+    Keep,
+}
+
+/// Options of [`parse_ranges`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// How overlapping ranges are treated, [`Overlaps::Merge`] by default.
+    pub overlaps: Overlaps,
+}
+
+/// A range of the input that is not two IDs like `"11-22"`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// The position of the range among the comma-separated entries of the
+    /// input, starting at 1. Empty entries are counted as well.
+    pub index: usize,
+    /// The one-based line the range starts on.
+    pub line: usize,
+    /// The one-based column within the line the range starts at.
+    pub column: usize,
+    /// The range as found in the input, without surrounding whitespace.
+    pub range: String,
+    /// Why the range is invalid.
+    pub reason: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "range {} '{}': {}", self.index, self.range, self.reason)
+    }
+}
+
+impl Error for ParseError {}
+
+impl From<ParseError> for Diagnostic {
+    fn from(err: ParseError) -> Diagnostic {
+        Diagnostic::at_column(
+            err.line,
+            err.column,
+            format!("range '{}': {}", err.range, err.reason),
+        )
+    }
+}
+
+/// Parses comma-separated ranges like `"11-22,95-115"`.
+///
+/// Whitespace around the ranges and their IDs, including line breaks, and a
+/// trailing comma are ignored.
+///
+/// # Returns
+/// With [`Overlaps::Merge`] the merged ranges sorted by their start, with
+/// [`Overlaps::Keep`] the ranges in input order, or a [`ParseError`] for the
+/// first range that is not two IDs separated by `'-'` or ends before it starts.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day02::{Overlaps, ParseOptions, parse_ranges};
+///
+/// let input = "95-115, 11-22,100-120,";
+/// assert_eq!(parse_ranges(input, ParseOptions::default()).unwrap(), vec![11..=22, 95..=120]);
+///
+/// let keep = ParseOptions { overlaps: Overlaps::Keep };
+/// assert_eq!(parse_ranges(input, keep).unwrap(), vec![95..=115, 11..=22, 100..=120]);
+///
+/// let err = parse_ranges("11-22,22-11", keep).unwrap_err();
+/// assert_eq!(err.to_string(), "range 2 '22-11': ends before it starts");
+/// ```
+pub fn parse_ranges(
+    input: &str,
+    options: ParseOptions,
+) -> Result<Vec<RangeInclusive<u128>>, ParseError> {
+    let mut ranges: Vec<RangeInclusive<u128>> = Vec::new();
+    let mut offset = 0;
+    for (index, entry) in input.split(',').enumerate() {
+        let start = offset + entry.len() - entry.trim_start().len();
+        offset += entry.len() + 1;
+        let range = entry.trim();
+        if range.is_empty() {
+            continue;
+        }
+        let range = parse_range(range).map_err(|reason| {
+            let line_start = input[..start].rfind('\n').map_or(0, |newline| newline + 1);
+            ParseError {
+                index: index + 1,
+                line: input[..start].matches('\n').count() + 1,
+                column: start - line_start + 1,
+                range: range.to_string(),
+                reason: reason.to_string(),
+            }
+        })?;
+        ranges.push(range);
+    }
+    Ok(match options.overlaps {
+        Overlaps::Merge => merge_ranges(ranges),
+        Overlaps::Keep => ranges,
+    })
+}
+
+/// Parses a single range like `"11-22"`.
+///
+/// # Returns
+/// The range, or why it is invalid.
+fn parse_range(range: &str) -> Result<RangeInclusive<u128>, &'static str> {
+    let is_id = |id: &str| !id.is_empty() && id.bytes().all(|byte| byte.is_ascii_digit());
+    let (start, end) = range
+        .split_once('-')
+        .map(|(start, end)| (start.trim(), end.trim()))
+        .filter(|(start, end)| is_id(start) && is_id(end))
+        .ok_or("expected two IDs separated by '-'")?;
+    let (Ok(start), Ok(end)) = (start.parse::<u128>(), end.parse::<u128>()) else {
+        return Err("exceeds the largest supported ID");
+    };
+    if start > end {
+        return Err("ends before it starts");
+    }
+    Ok(start..=end)
+}
+
+/// Merges overlapping and adjacent ranges, e.g. `3..=5` and `6..=8` into `3..=8`.
+///
+/// # Returns
+/// The merged ranges sorted by their start, without empty ranges.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day02::merge_ranges;
+///
+/// assert_eq!(merge_ranges(vec![10..=14, 3..=5, 12..=18, 6..=8]), vec![3..=8, 10..=18]);
+/// ```
+pub fn merge_ranges(mut ranges: Vec<RangeInclusive<u128>>) -> Vec<RangeInclusive<u128>> {
+    ranges.retain(|range| !range.is_empty());
+    ranges.sort_by_key(|range| *range.start());
+    let mut merged: Vec<RangeInclusive<u128>> = Vec::with_capacity(ranges.len());
+    for range in ranges {
+        match merged.last_mut() {
+            Some(last) if *range.start() <= last.end().saturating_add(1) => {
+                if range.end() > last.end() {
+                    *last = *last.start()..=*range.end();
+                }
+            }
+            _ => merged.push(range),
+        }
+    }
+    merged
+}
+
+/// Validates that the input consists of comma-separated ranges like `"11-22"`.
+///
+/// The input is split and trimmed exactly like in [`parse_ranges`], so
+/// whitespace including line breaks and a trailing comma are accepted, and
+/// every validated input parses.
+pub fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+    let options = ParseOptions {
+        overlaps: Overlaps::Keep,
+    };
+    parse_ranges(input, options)
+        .map(|_| ())
+        .map_err(|err| vec![err.into()])
+}
+
+/// Lazily yields the IDs in `range` made of a block of `block_digits` digits
+/// repeated `repeats` times, in ascending order.
+///
+/// Such an ID is the block times `1` followed by `repeats - 1` groups of
+/// `block_digits - 1` zeros and a `1`, e.g. `123123 = 123 × 1001`, so the
+/// IDs are generated from the blocks within the range instead of testing
+/// every ID in it. Blocks have no leading zeros.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day02::repeated_block_ids;
+///
+/// let ids: Vec<u128> = repeated_block_ids(1000..=1300, 2, 2).collect();
+/// assert_eq!(ids, vec![1010, 1111, 1212]);
+/// assert_eq!(repeated_block_ids(0..=1000, 1, 3).last(), Some(999));
+/// assert_eq!(repeated_block_ids(0..=u128::MAX, 20, 2).next(), None);
+/// ```
+pub fn repeated_block_ids(
+    range: RangeInclusive<u128>,
+    block_digits: u32,
+    repeats: u32,
+) -> impl Iterator<Item = u128> {
+    // 1, 1001, 1001001, ... or `None` once the IDs have more digits than a u128.
+    let multiplier: Option<u128> = (1..repeats).try_fold(1_u128, |multiplier, _| {
+        multiplier
+            .checked_mul(10_u128.checked_pow(block_digits)?)?
+            .checked_add(1)
+    });
+    let blocks = match (multiplier, 10_u128.checked_pow(block_digits)) {
+        (Some(multiplier), Some(block_end)) if !range.is_empty() => {
+            let first = (block_end / 10).max(range.start().div_ceil(multiplier));
+            let last = (block_end - 1).min(range.end() / multiplier);
+            first..=last
+        }
+        _ => RangeInclusive::new(1, 0),
+    };
+    let multiplier = multiplier.unwrap_or(1);
+    // Every ID is at most the end of the range, so it cannot overflow.
+    blocks.map(move |block| block * multiplier)
+}
+
+/// Returns the numbers of digits the positive IDs in `range` may have.
+fn digit_counts(range: &RangeInclusive<u128>) -> RangeInclusive<u32> {
+    let digits = |id: u128| id.checked_ilog10().map_or(0, |log| log + 1);
+    digits((*range.start()).max(1))..=digits(*range.end())
+}
+
+/// Sums the IDs `ids` yields for every range, checking `cancel` before every
+/// range and every [`CHECK_INTERVAL`] IDs.
+///
+/// # Returns
+/// The sum, `None` if it overflows `N`, or [`Cancelled`].
+fn sum_ids<N, I, F>(
+    ranges: &[RangeInclusive<u128>],
+    cancel: &CancelToken,
+    ids: F,
+) -> Result<Option<N>, Cancelled>
+where
+    N: Integer,
+    I: Iterator<Item = u128>,
+    F: Fn(RangeInclusive<u128>) -> I + Sync,
+{
+    let mut result: N = N::zero();
+
+    for (index, range) in ranges.iter().enumerate() {
+        let (start, end): (u128, u128) = (*range.start(), *range.end());
+        cancel.check()?;
+        #[cfg(feature = "parallel")]
+        let summed = sum_range_parallel(range, cancel, &ids)?;
+        #[cfg(not(feature = "parallel"))]
+        let summed = sum_range(range.clone(), cancel, &ids)?;
+        let Some((invalid, range_sum)) = summed else {
+            return Ok(None);
+        };
+        let Some(sum) = result.checked_add(&range_sum) else {
+            return Ok(None);
+        };
+        result = sum;
+        debug!(start, end, invalid, "generated invalid IDs");
+        progress::report(index as u64 + 1, ranges.len() as u64);
+    }
+
+    Ok(Some(result))
+}
+
+/// Sums the IDs `ids` yields for a single range.
+///
+/// # Returns
+/// The number of IDs and their sum, `None` if the sum overflows `N`, or [`Cancelled`].
+fn sum_range<N, I, F>(
+    range: RangeInclusive<u128>,
+    cancel: &CancelToken,
+    ids: &F,
+) -> Result<Option<(usize, N)>, Cancelled>
+where
+    N: Integer,
+    I: Iterator<Item = u128>,
+    F: Fn(RangeInclusive<u128>) -> I,
+{
+    let mut sum: N = N::zero();
+    let mut count: usize = 0;
+    for id in ids(range) {
+        count += 1;
+        if count.is_multiple_of(CHECK_INTERVAL as usize) {
+            cancel.check()?;
+        }
+        let Some(next) = N::from_u128(id).and_then(|id| sum.checked_add(&id)) else {
+            return Ok(None);
+        };
+        sum = next;
+    }
+    Ok(Some((count, sum)))
+}
+
+/// Like [`sum_range`], but splits the range into chunks summed in parallel.
+///
+/// The partial sums are added up in the order of the chunks, so the result
+/// does not depend on the number of threads or on which chunk finishes first.
+#[cfg(feature = "parallel")]
+fn sum_range_parallel<N, I, F>(
+    range: &RangeInclusive<u128>,
+    cancel: &CancelToken,
+    ids: &F,
+) -> Result<Option<(usize, N)>, Cancelled>
+where
+    N: Integer,
+    I: Iterator<Item = u128>,
+    F: Fn(RangeInclusive<u128>) -> I + Sync,
+{
+    use rayon::prelude::*;
+
+    let chunks = split_range(range, rayon::current_num_threads() * CHUNKS_PER_THREAD);
+    let partial_sums: Vec<Option<(usize, N)>> = chunks
+        .into_par_iter()
+        .map(|chunk| sum_range(chunk, cancel, ids))
+        .collect::<Result<_, Cancelled>>()?;
+
+    let mut total: (usize, N) = (0, N::zero());
+    for partial_sum in partial_sums {
+        let Some((count, sum)) = partial_sum else {
+            return Ok(None);
+        };
+        let Some(sum) = total.1.checked_add(&sum) else {
+            return Ok(None);
+        };
+        total = (total.0 + count, sum);
+    }
+    Ok(Some(total))
+}
+
+/// The number of chunks per thread a range is split into with the
+/// `parallel` feature, so threads finishing early pick up more work.
+#[cfg(feature = "parallel")]
+const CHUNKS_PER_THREAD: usize = 4;
+
+/// Splits a range into at most `parts` consecutive ranges of about equal length.
+#[cfg(feature = "parallel")]
+fn split_range(range: &RangeInclusive<u128>, parts: usize) -> Vec<RangeInclusive<u128>> {
+    let (start, end) = (*range.start(), *range.end());
+    if end < start {
+        return Vec::new();
+    }
+    let step = (end - start) / (parts.max(1) as u128) + 1;
+    (0..parts.max(1) as u128)
+        .map_while(|part| {
+            let chunk_start = start
+                .checked_add(step.checked_mul(part)?)
+                .filter(|chunk_start| *chunk_start <= end)?;
+            Some(chunk_start..=chunk_start.saturating_add(step - 1).min(end))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge_ranges() {
+        assert_eq!(merge_ranges(vec![11..=22, 11..=22]), vec![11..=22]);
+        assert_eq!(merge_ranges(vec![1..=10, 2..=3]), vec![1..=10]);
+        assert_eq!(merge_ranges(vec![5..=6, 1..=3]), vec![1..=3, 5..=6]);
+        assert_eq!(
+            merge_ranges(vec![RangeInclusive::new(5, 4), 0..=u128::MAX, 7..=9]),
+            vec![0..=u128::MAX]
+        );
+        assert_eq!(merge_ranges(Vec::new()), Vec::<RangeInclusive<u128>>::new());
+    }
+
+    #[test]
+    fn test_overlapping_ranges_are_counted_once() {
+        let merged = Day02::parse("11-22,15-22,11-22").unwrap();
+        assert_eq!(merged, vec![11..=22]);
+        assert_eq!(part1::solve(&merged).to_string(), "33");
+
+        let keep = ParseOptions {
+            overlaps: Overlaps::Keep,
+        };
+        let kept = parse_ranges("11-22,15-22,11-22", keep).unwrap();
+        assert_eq!(part1::solve(&kept).to_string(), "88");
+    }
+
+    #[test]
+    fn test_parse_ranges_reports_invalid_range() {
+        let options = ParseOptions::default();
+        assert_eq!(
+            parse_ranges(" 11-22 ,\n95 - 115,\n", options).unwrap(),
+            vec![11..=22, 95..=115]
+        );
+        assert_eq!(parse_ranges("", options).unwrap(), Vec::new());
+        let err = parse_ranges("11-22,,\n  95", options).unwrap_err();
+        assert_eq!(
+            err,
+            ParseError {
+                index: 3,
+                line: 2,
+                column: 3,
+                range: "95".to_string(),
+                reason: "expected two IDs separated by '-'".to_string(),
+            }
+        );
+        for (input, reason) in [
+            ("11-22,-5", "expected two IDs separated by '-'"),
+            ("11-22,5-x", "expected two IDs separated by '-'"),
+            ("11-22,30-29", "ends before it starts"),
+        ] {
+            assert_eq!(parse_ranges(input, options).unwrap_err().reason, reason);
+        }
+        let huge = format!("1-{}0", u128::MAX);
+        assert_eq!(
+            parse_ranges(&huge, options).unwrap_err().to_string(),
+            format!("range 1 '{}': exceeds the largest supported ID", huge)
+        );
+    }
+
+    #[test]
+    fn test_ids_beyond_i64() {
+        let id = "12".repeat(19);
+        let ranges = Day02::parse(&format!("11-22,{0}-{0}", id)).unwrap();
+        assert_eq!(part1::solve(&ranges).to_string(), "33");
+        assert_eq!(
+            part2::solve(&ranges).to_string(),
+            (id.parse::<u128>().unwrap() + 33).to_string()
+        );
+        let cancel = CancelToken::new();
+        assert_eq!(
+            sum_ids::<i64, _, _>(&ranges, &cancel, part2::invalid_ids_in),
+            Ok(None)
+        );
+        assert_eq!(
+            sum_ids::<u128, _, _>(&[u128::MAX - 1..=u128::MAX], &cancel, part2::invalid_ids_in),
+            Ok(Some(0))
+        );
+        let ones = "1".repeat(39).parse::<u128>().unwrap();
+        assert_eq!(part2::invalid_ids_in(ones..=u128::MAX).next(), Some(ones));
+        assert_eq!(
+            part2::invalid_ids_in(u128::MAX - 10_u128.pow(27)..=u128::MAX).last(),
+            Some("3402823669209".repeat(3).parse().unwrap())
+        );
+    }
+
+    #[test]
+    fn test_sum_beyond_u128() {
+        let (threes, twos) = ("3".repeat(39), "2".repeat(39));
+        let repetitions = format!("{0}-{0},{1}-{1}", threes, twos);
+        let halves: Vec<String> = (5..=9)
+            .map(|digit| format!("{0}-{0}", digit.to_string().repeat(38)))
+            .collect();
+        for (part, input, sum) in [
+            (2, repetitions, "5".repeat(39)),
+            (1, halves.join(","), format!("3{}5", "8".repeat(37))),
+        ] {
+            assert!(validate(&input).is_ok());
+            let answer = crate::solve(2, part, &input).unwrap();
+            if cfg!(feature = "bigint") {
+                assert_eq!(answer, Answer::parse(&sum));
+            } else {
+                assert_eq!(answer, Answer::from(OVERFLOW));
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "gpu")]
+    fn test_solve_on_gpu_matches_cpu() {
+        // Ranges this long go to the GPU, or to the CPU fallback without an adapter.
+        let ranges = [
+            11..=22,
+            95..=115,
+            1_000_000..=1_000_000 + 4 * gpu::GPU_MIN_RANGE,
+            999_000_000..=1_004_000_000,
+        ];
+        let cancel = CancelToken::new();
+        for rule in [Rule::EqualHalves, Rule::Repetition] {
+            let expected = ids::sum_invalid_ids::<u128>(&ranges, rule, &cancel);
+            assert_eq!(
+                solve_rule_cancellable(&ranges, rule, &cancel),
+                Ok(Answer::from(expected.unwrap().unwrap()))
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_rejects_ids_beyond_u128() {
+        let input = format!("1-{}", "9".repeat(40));
+        assert_eq!(
+            validate(&input).unwrap_err()[0],
+            Diagnostic::at_column(
+                1,
+                1,
+                format!("range '{}': exceeds the largest supported ID", input)
+            )
+        );
+        assert!(validate(&format!("1-{}", u128::MAX)).is_ok());
+    }
+
+    #[test]
+    fn test_validate_splits_like_parse_ranges() {
+        for input in ["11-22, 95-115,", "11-22,\n95-115\n", " 11-22 ,,95-115"] {
+            assert!(validate(input).is_ok(), "{:?}", input);
+            assert_eq!(crate::solve(2, 1, input), Ok(Answer::I64(132)));
+        }
+        assert_eq!(
+            validate("11-22,\n 95-115, 22-11").unwrap_err(),
+            vec![Diagnostic::at_column(
+                2,
+                10,
+                "range '22-11': ends before it starts"
+            )]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_split_range() {
+        assert_eq!(split_range(&(1..=10), 3), vec![1..=4, 5..=8, 9..=10]);
+        assert_eq!(split_range(&(5..=6), 4), vec![5..=5, 6..=6]);
+        assert_eq!(
+            split_range(&RangeInclusive::new(5, 4), 4),
+            Vec::<RangeInclusive<u128>>::new()
+        );
+        assert_eq!(
+            split_range(&(0..=u128::MAX), 2),
+            vec![0..=u128::MAX / 2, u128::MAX / 2 + 1..=u128::MAX]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "parallel")]
+    fn test_sum_range_parallel_matches_sequential() {
+        let cancel = CancelToken::new();
+        for range in [0..=1_000_000, 11..=22, 998..=1012, 7..=7] {
+            assert_eq!(
+                sum_range_parallel::<u128, _, _>(&range, &cancel, &part2::invalid_ids_in),
+                sum_range(range.clone(), &cancel, &part2::invalid_ids_in)
+            );
+        }
+    }
+}
@@ -1,163 +1,228 @@
-/// Calculates the sum of all "invalid IDs" within the ranges specified in the input string.
+use std::ops::RangeInclusive;
+
+use super::ids::{self, InvalidIdIter, Rule};
+
+use crate::cancel::{CancelToken, Cancelled};
+use crate::integer::Integer;
+use crate::solver::Answer;
+
+/// Calculates the sum of all "invalid IDs" within the given ranges.
 ///
 /// # Arguments
 ///
-/// * `input` - A string containing ranges separated by commas, e.g. `"11-22,95-115"`.
-///   Each range is specified with a dash, e.g. `"11-22"`.
+/// * `ranges` - The inclusive ID ranges parsed by [`Day02`](super::Day02),
+///   e.g. `11..=22` for `"11-22"`.
 ///
 /// # Returns
 ///
-/// A `String` containing the sum of all found "invalid IDs".
-pub fn solve(input: &str) -> String {
-    let mut result: i64 = 0;
-
-    let ranges = input.split(",");
-    for range in ranges {
-        let ids: Vec<&str> = range.split('-').collect();
-        for id in collect_invalid_ids_in_range(ids[0].parse().unwrap(), ids[1].parse().unwrap()) {
-            result += id;
-        }
-    }
+/// An [`Answer`] holding the sum of all found "invalid IDs", see
+/// [`solve_rule`](super::solve_rule).
+pub fn solve(ranges: &[RangeInclusive<u128>]) -> Answer {
+    super::solve_rule(ranges, Rule::EqualHalves)
+}
 
-    result.to_string()
+/// Like [`solve`], but stops scanning as soon as `cancel` is cancelled, see
+/// [`solve_rule_cancellable`](super::solve_rule_cancellable).
+pub fn solve_cancellable(
+    ranges: &[RangeInclusive<u128>],
+    cancel: &CancelToken,
+) -> Result<Answer, Cancelled> {
+    super::solve_rule_cancellable(ranges, Rule::EqualHalves, cancel)
 }
 
-/// Returns a vector of all "invalid IDs" within a given range.
+/// Sums all "invalid IDs" within the given ranges in any [`Integer`] type,
+/// see [`ids::sum_invalid_ids`].
 ///
-/// # Arguments
-///
-/// * `start` - The start of the range (inclusive)
-/// * `end` - The end of the range (inclusive)
+/// # Examples
 ///
-/// # Returns
+/// ```
+/// use aoc2025::cancel::CancelToken;
+/// use aoc2025::day02::part1::sum_invalid_ids;
 ///
-/// A `Vec<i64>` containing all IDs in the range that are considered "invalid".
-fn collect_invalid_ids_in_range(start: i64, end: i64) -> Vec<i64> {
-    let mut numbers: Vec<i64> = Vec::new();
+/// let ranges = [11..=22, 95..=115];
+/// assert_eq!(sum_invalid_ids::<i64>(&ranges, &CancelToken::new()), Ok(Some(132)));
+/// ```
+pub fn sum_invalid_ids<N: Integer>(
+    ranges: &[RangeInclusive<u128>],
+    cancel: &CancelToken,
+) -> Result<Option<N>, Cancelled> {
+    ids::sum_invalid_ids(ranges, Rule::EqualHalves, cancel)
+}
 
-    for id in start..=end {
-        if is_invalid_id(&id.to_string()) {
-            numbers.push(id);
-        }
-    }
+/// Lazily yields all "invalid IDs" within the given ranges, see
+/// [`ids::invalid_ids`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::part1::invalid_ids;
+///
+/// let ranges = [11..=22, 95..=115];
+/// assert_eq!(invalid_ids(&ranges).collect::<Vec<u128>>(), vec![11, 22, 99]);
+/// assert_eq!(invalid_ids(&ranges).last(), Some(99));
+/// ```
+pub fn invalid_ids(ranges: &[RangeInclusive<u128>]) -> impl Iterator<Item = u128> + '_ {
+    ids::invalid_ids(ranges, Rule::EqualHalves)
+}
 
-    numbers
+/// Counts all "invalid IDs" within the given ranges, like [`invalid_ids`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::part1::count_invalid_ids;
+///
+/// assert_eq!(count_invalid_ids(&[11..=22, 95..=115]), 3);
+/// ```
+pub fn count_invalid_ids(ranges: &[RangeInclusive<u128>]) -> usize {
+    ids::count_invalid_ids(ranges, Rule::EqualHalves)
 }
 
-/// Checks if a given ID is considered "invalid".
+/// Lazily yields all "invalid IDs" within a range in ascending order.
 ///
-/// An ID is "invalid" if the first half of its digits is identical to the second half.
+/// An ID is invalid if the first half of its digits is identical to the second
+/// half. Instead of testing every ID, the invalid IDs of every even number of
+/// digits are constructed from their first half by an [`InvalidIdIter`], so
+/// even ranges of billions of IDs take only as long as they hold invalid IDs.
 ///
 /// # Arguments
 ///
-/// * `id` - The ID as a string
+/// * `range` - The inclusive range of IDs to scan
 ///
 /// # Returns
 ///
-/// `true` if the ID is invalid, `false` otherwise.
-fn is_invalid_id(id: &str) -> bool {
-    id[0..(id.len() / 2)] == id[(id.len() / 2)..(id.len())]
+/// An iterator over the IDs in the range that are considered "invalid".
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::part1::invalid_ids_in;
+///
+/// assert_eq!(invalid_ids_in(11..=22).collect::<Vec<u128>>(), vec![11, 22]);
+/// ```
+pub fn invalid_ids_in(range: RangeInclusive<u128>) -> InvalidIdIter {
+    InvalidIdIter::new(range, Rule::EqualHalves)
+}
+
+/// Like [`invalid_ids_in`], but tests every ID of the range with
+/// [`has_equal_halves`](super::ids::has_equal_halves), kept as reference for
+/// the constructive generation.
+#[cfg(any(test, feature = "reference"))]
+pub fn invalid_ids_scan(range: RangeInclusive<u128>) -> impl Iterator<Item = u128> {
+    super::ids::matching_ids(range, super::ids::has_equal_halves)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day02::Day02;
+    use crate::day02::ids::has_equal_halves;
+    use crate::solver::Solver;
 
     #[test]
     fn test_invalid_id_11() {
-        assert!(is_invalid_id(&11.to_string()));
+        assert!(has_equal_halves(&11.to_string()));
     }
 
     #[test]
     fn test_invalid_id_22() {
-        assert!(is_invalid_id(&22.to_string()));
+        assert!(has_equal_halves(&22.to_string()));
     }
 
     #[test]
     fn test_invalid_id_99() {
-        assert!(is_invalid_id(&99.to_string()));
+        assert!(has_equal_halves(&99.to_string()));
     }
 
     #[test]
     fn test_invalid_id_1010() {
-        assert!(is_invalid_id(&1010.to_string()));
+        assert!(has_equal_halves(&1010.to_string()));
     }
 
     #[test]
     fn test_invalid_id_1188511885() {
-        assert!(is_invalid_id(&1188511885.to_string()));
+        assert!(has_equal_halves(&1188511885.to_string()));
     }
 
     #[test]
     fn test_invalid_id_222222() {
-        assert!(is_invalid_id(&222222.to_string()));
+        assert!(has_equal_halves(&222222.to_string()));
     }
 
     #[test]
     fn test_invalid_id_446446() {
-        assert!(is_invalid_id(&446446.to_string()));
+        assert!(has_equal_halves(&446446.to_string()));
     }
 
     #[test]
     fn test_invalid_id_38593859() {
-        assert!(is_invalid_id(&38593859.to_string()));
+        assert!(has_equal_halves(&38593859.to_string()));
     }
 
     #[test]
     fn test_valid_id_12() {
-        assert!(!is_invalid_id(&12.to_string()));
+        assert!(!has_equal_halves(&12.to_string()));
     }
 
     #[test]
     fn test_valid_id_123() {
-        assert!(!is_invalid_id(&123.to_string()));
+        assert!(!has_equal_halves(&123.to_string()));
     }
 
     #[test]
     fn test_range_11_22() {
-        assert_eq!(collect_invalid_ids_in_range(11, 22), vec![11, 22]);
+        assert_eq!(invalid_ids_in(11..=22).collect::<Vec<u128>>(), vec![11, 22]);
     }
 
     #[test]
     fn test_range_95_115() {
-        assert_eq!(collect_invalid_ids_in_range(95, 115), vec![99]);
+        assert_eq!(invalid_ids_in(95..=115).collect::<Vec<u128>>(), vec![99]);
     }
 
     #[test]
     fn test_range_998_1012() {
-        assert_eq!(collect_invalid_ids_in_range(998, 1012), vec![1010]);
+        assert_eq!(
+            invalid_ids_in(998..=1012).collect::<Vec<u128>>(),
+            vec![1010]
+        );
     }
 
     #[test]
     fn test_range_1188511880_1188511890() {
         assert_eq!(
-            collect_invalid_ids_in_range(1188511880, 1188511890),
+            invalid_ids_in(1188511880..=1188511890).collect::<Vec<u128>>(),
             vec![1188511885]
         );
     }
 
     #[test]
     fn test_range_222220_222224() {
-        assert_eq!(collect_invalid_ids_in_range(222220, 222224), vec![222222]);
+        assert_eq!(
+            invalid_ids_in(222220..=222224).collect::<Vec<u128>>(),
+            vec![222222]
+        );
     }
 
     #[test]
     fn test_range_1698522_1698528() {
         assert_eq!(
-            collect_invalid_ids_in_range(1698522, 1698528),
-            Vec::<i64>::new()
+            invalid_ids_in(1698522..=1698528).collect::<Vec<u128>>(),
+            Vec::<u128>::new()
         );
     }
 
     #[test]
     fn test_range_446443_446449() {
-        assert_eq!(collect_invalid_ids_in_range(446443, 446449), vec![446446]);
+        assert_eq!(
+            invalid_ids_in(446443..=446449).collect::<Vec<u128>>(),
+            vec![446446]
+        );
     }
 
     #[test]
     fn test_range_38593856_38593862() {
         assert_eq!(
-            collect_invalid_ids_in_range(38593856, 38593862),
+            invalid_ids_in(38593856..=38593862).collect::<Vec<u128>>(),
             vec![38593859]
         );
     }
@@ -165,31 +230,81 @@ mod tests {
     #[test]
     fn test_range_565653_565659() {
         assert_eq!(
-            collect_invalid_ids_in_range(565653, 565659),
-            Vec::<i64>::new()
+            invalid_ids_in(565653..=565659).collect::<Vec<u128>>(),
+            Vec::<u128>::new()
         );
     }
 
     #[test]
     fn test_range_824824821_824824827() {
         assert_eq!(
-            collect_invalid_ids_in_range(824824821, 824824827),
-            Vec::<i64>::new()
+            invalid_ids_in(824824821..=824824827).collect::<Vec<u128>>(),
+            Vec::<u128>::new()
         );
     }
 
     #[test]
     fn test_range_2121212118_2121212124() {
         assert_eq!(
-            collect_invalid_ids_in_range(2121212118, 2121212124),
-            Vec::<i64>::new()
+            invalid_ids_in(2121212118..=2121212124).collect::<Vec<u128>>(),
+            Vec::<u128>::new()
         );
     }
 
     #[test]
     fn test_solve() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        let result = solve(input);
-        assert_eq!(result, "1227775554");
+        let result = solve(&Day02::parse(Day02::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day02::METADATA.example_answer(1)
+        );
+    }
+
+    #[test]
+    fn test_count_and_list_match_sum() {
+        let ranges = Day02::parse(Day02::METADATA.example).unwrap();
+        let ids: Vec<u128> = invalid_ids(&ranges).collect();
+        assert_eq!(ids.len(), count_invalid_ids(&ranges));
+        assert!(ids.is_sorted());
+        assert_eq!(
+            Some(ids.iter().sum::<u128>().to_string().as_str()),
+            Day02::METADATA.example_answer(1)
+        );
+    }
+
+    #[test]
+    fn test_solve_cancellable() {
+        let ranges = Day02::parse(Day02::METADATA.example).unwrap();
+        let token = CancelToken::new();
+        assert_eq!(solve_cancellable(&ranges, &token), Ok(solve(&ranges)));
+        token.cancel();
+        assert_eq!(solve_cancellable(&ranges, &token), Err(Cancelled));
+    }
+
+    #[test]
+    fn test_invalid_ids_in_matches_scan() {
+        for start in (0..20_000).step_by(997) {
+            let range = start..=start + 3_000;
+            assert_eq!(
+                invalid_ids_in(range.clone()).collect::<Vec<u128>>(),
+                invalid_ids_scan(range).collect::<Vec<u128>>()
+            );
+        }
+        let top = u128::MAX - 100_000..=u128::MAX;
+        assert_eq!(
+            invalid_ids_in(top.clone()).collect::<Vec<u128>>(),
+            invalid_ids_scan(top).collect::<Vec<u128>>()
+        );
+    }
+
+    #[test]
+    fn test_invalid_ids_in_huge_range() {
+        // 9 IDs with 2 digits, 90 with 4, ..., 90000 with 10.
+        assert_eq!(invalid_ids_in(1..=9_999_999_999).count(), 99_999);
+        // 11 × (1 + ... + 9) + 101 × (10 + ... + 99)
+        assert_eq!(
+            sum_invalid_ids::<i64>(&[1..=9_999], &CancelToken::new()),
+            Ok(Some(495_900))
+        );
     }
 }
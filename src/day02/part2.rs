@@ -1,224 +1,273 @@
-/// Calculates the sum of all "invalid IDs" within the ranges specified in the input string.
+use std::ops::RangeInclusive;
+
+use super::ids::{self, InvalidIdIter, Rule};
+
+use crate::cancel::{CancelToken, Cancelled};
+use crate::integer::Integer;
+use crate::solver::Answer;
+
+/// Calculates the sum of all "invalid IDs" within the given ranges.
 ///
 /// # Arguments
 ///
-/// * `input` - A string containing ranges separated by commas, e.g. `"11-22,95-115"`.
-///   Each range is specified with a dash, e.g. `"11-22"`.
+/// * `ranges` - The inclusive ID ranges parsed by [`Day02`](super::Day02),
+///   e.g. `11..=22` for `"11-22"`.
 ///
 /// # Returns
 ///
-/// A `String` containing the sum of all found "invalid IDs".
-pub fn solve(input: &str) -> String {
-    let mut result: i64 = 0;
-
-    let ranges = input.split(",");
-    for range in ranges {
-        let ids: Vec<&str> = range.split('-').collect();
-        for id in collect_invalid_ids_in_range(ids[0].parse().unwrap(), ids[1].parse().unwrap()) {
-            result += id;
-        }
-    }
+/// An [`Answer`] holding the sum of all found "invalid IDs", see
+/// [`solve_rule`](super::solve_rule).
+pub fn solve(ranges: &[RangeInclusive<u128>]) -> Answer {
+    super::solve_rule(ranges, Rule::Repetition)
+}
 
-    result.to_string()
+/// Like [`solve`], but stops scanning as soon as `cancel` is cancelled, see
+/// [`solve_rule_cancellable`](super::solve_rule_cancellable).
+pub fn solve_cancellable(
+    ranges: &[RangeInclusive<u128>],
+    cancel: &CancelToken,
+) -> Result<Answer, Cancelled> {
+    super::solve_rule_cancellable(ranges, Rule::Repetition, cancel)
 }
 
-/// Returns a vector of all "invalid IDs" within a given range.
-///
-/// # Arguments
+/// Sums all "invalid IDs" within the given ranges in any [`Integer`] type,
+/// see [`ids::sum_invalid_ids`].
 ///
-/// * `start` - The start of the range (inclusive)
-/// * `end` - The end of the range (inclusive)
+/// # Examples
 ///
-/// # Returns
+/// ```
+/// use aoc2025::cancel::CancelToken;
+/// use aoc2025::day02::part2::sum_invalid_ids;
 ///
-/// A `Vec<i64>` containing all IDs in the range that are considered "invalid".
-fn collect_invalid_ids_in_range(start: i64, end: i64) -> Vec<i64> {
-    let mut numbers: Vec<i64> = Vec::new();
+/// let ranges = [11..=22, 95..=115];
+/// assert_eq!(sum_invalid_ids::<i64>(&ranges, &CancelToken::new()), Ok(Some(243)));
+/// ```
+pub fn sum_invalid_ids<N: Integer>(
+    ranges: &[RangeInclusive<u128>],
+    cancel: &CancelToken,
+) -> Result<Option<N>, Cancelled> {
+    ids::sum_invalid_ids(ranges, Rule::Repetition, cancel)
+}
 
-    for id in start..=end {
-        if is_invalid_id(&id.to_string()) {
-            numbers.push(id);
-        }
-    }
+/// Lazily yields all "invalid IDs" within the given ranges, see
+/// [`ids::invalid_ids`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::part2::invalid_ids;
+///
+/// let ranges = [11..=22, 95..=115];
+/// assert_eq!(invalid_ids(&ranges).collect::<Vec<u128>>(), vec![11, 22, 99, 111]);
+/// assert_eq!(invalid_ids(&ranges).last(), Some(111));
+/// ```
+pub fn invalid_ids(ranges: &[RangeInclusive<u128>]) -> impl Iterator<Item = u128> + '_ {
+    ids::invalid_ids(ranges, Rule::Repetition)
+}
 
-    numbers
+/// Counts all "invalid IDs" within the given ranges, like [`invalid_ids`].
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::part2::count_invalid_ids;
+///
+/// assert_eq!(count_invalid_ids(&[11..=22, 95..=115]), 4);
+/// ```
+pub fn count_invalid_ids(ranges: &[RangeInclusive<u128>]) -> usize {
+    ids::count_invalid_ids(ranges, Rule::Repetition)
 }
 
-/// Checks whether a given ID is considered "invalid".
+/// Lazily yields all "invalid IDs" within a range in ascending order.
 ///
-/// An ID is considered invalid if it is made only of some sequence of digits
-/// repeated at least twice. So, 12341234 (1234 two times), 123123123 (123 three times),
-/// 1212121212 (12 five times), and 1111111 (1 seven times) are all invalid IDs.
+/// An ID is invalid if it is made only of some sequence of digits repeated at
+/// least twice. Instead of testing every ID, the invalid IDs are constructed
+/// from their blocks by an [`InvalidIdIter`] for every block length dividing
+/// the number of digits, taking the smallest, so an ID like `111111` built
+/// from the blocks `1`, `11` and `111` is yielded once.
 ///
 /// # Arguments
 ///
-/// * `id` - The ID as a string slice
+/// * `range` - The inclusive range of IDs to scan
 ///
 /// # Returns
 ///
-/// `true` if the ID is invalid, `false` otherwise
-fn is_invalid_id(id: &str) -> bool {
-    let length: usize = id.len();
-
-    'elements_loop: for elements in 2..=length {
-        if length % elements != 0 {
-            continue;
-        }
-
-        let element_length = length / elements;
-
-        for test_element in 1..elements {
-            if id[0..element_length]
-                != id[(element_length * test_element)
-                    ..((element_length * test_element) + element_length)]
-            {
-                continue 'elements_loop;
-            }
-        }
-
-        return true;
-    }
+/// An iterator over the IDs in the range that are considered "invalid".
+///
+/// # Examples
+///
+/// ```
+/// use aoc2025::day02::part2::invalid_ids_in;
+///
+/// assert_eq!(invalid_ids_in(95..=115).collect::<Vec<u128>>(), vec![99, 111]);
+/// ```
+pub fn invalid_ids_in(range: RangeInclusive<u128>) -> InvalidIdIter {
+    InvalidIdIter::new(range, Rule::Repetition)
+}
 
-    false
+/// Like [`invalid_ids_in`], but tests every ID of the range with
+/// [`is_repetition`](super::ids::is_repetition), kept as reference for the
+/// constructive generation.
+#[cfg(any(test, feature = "reference"))]
+pub fn invalid_ids_scan(range: RangeInclusive<u128>) -> impl Iterator<Item = u128> {
+    super::ids::matching_ids(range, super::ids::is_repetition)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day02::Day02;
+    use crate::day02::ids::is_repetition;
+    use crate::solver::Solver;
 
     #[test]
     fn test_invalid_id_11() {
-        assert!(is_invalid_id(&11.to_string()));
+        assert!(is_repetition(&11.to_string()));
     }
 
     #[test]
     fn test_invalid_id_22() {
-        assert!(is_invalid_id(&22.to_string()));
+        assert!(is_repetition(&22.to_string()));
     }
 
     #[test]
     fn test_invalid_id_99() {
-        assert!(is_invalid_id(&99.to_string()));
+        assert!(is_repetition(&99.to_string()));
     }
 
     #[test]
     fn test_invalid_id_111() {
-        assert!(is_invalid_id(&111.to_string()));
+        assert!(is_repetition(&111.to_string()));
     }
 
     #[test]
     fn test_invalid_id_999() {
-        assert!(is_invalid_id(&999.to_string()));
+        assert!(is_repetition(&999.to_string()));
     }
 
     #[test]
     fn test_invalid_id_1010() {
-        assert!(is_invalid_id(&1010.to_string()));
+        assert!(is_repetition(&1010.to_string()));
     }
 
     #[test]
     fn test_invalid_id_1188511885() {
-        assert!(is_invalid_id(&1188511885.to_string()));
+        assert!(is_repetition(&1188511885.to_string()));
     }
 
     #[test]
     fn test_invalid_id_222222() {
-        assert!(is_invalid_id(&222222.to_string()));
+        assert!(is_repetition(&222222.to_string()));
     }
 
     #[test]
     fn test_invalid_id_446446() {
-        assert!(is_invalid_id(&446446.to_string()));
+        assert!(is_repetition(&446446.to_string()));
     }
 
     #[test]
     fn test_invalid_id_38593859() {
-        assert!(is_invalid_id(&38593859.to_string()));
+        assert!(is_repetition(&38593859.to_string()));
     }
 
     #[test]
     fn test_invalid_id_565656() {
-        assert!(is_invalid_id(&565656.to_string()));
+        assert!(is_repetition(&565656.to_string()));
     }
 
     #[test]
     fn test_invalid_id_824824824() {
-        assert!(is_invalid_id(&824824824.to_string()));
+        assert!(is_repetition(&824824824.to_string()));
     }
 
     #[test]
     fn test_invalid_id_2121212121() {
-        assert!(is_invalid_id(&2121212121.to_string()));
+        assert!(is_repetition(&2121212121.to_string()));
     }
 
     #[test]
     fn test_valid_id_12() {
-        assert!(!is_invalid_id(&12.to_string()));
+        assert!(!is_repetition(&12.to_string()));
     }
 
     #[test]
     fn test_valid_id_123() {
-        assert!(!is_invalid_id(&123.to_string()));
+        assert!(!is_repetition(&123.to_string()));
     }
 
     #[test]
     fn test_range_11_22() {
-        assert_eq!(collect_invalid_ids_in_range(11, 22), vec![11, 22]);
+        assert_eq!(invalid_ids_in(11..=22).collect::<Vec<u128>>(), vec![11, 22]);
     }
 
     #[test]
     fn test_range_95_115() {
-        assert_eq!(collect_invalid_ids_in_range(95, 115), vec![99, 111]);
+        assert_eq!(
+            invalid_ids_in(95..=115).collect::<Vec<u128>>(),
+            vec![99, 111]
+        );
     }
 
     #[test]
     fn test_range_998_1012() {
-        assert_eq!(collect_invalid_ids_in_range(998, 1012), vec![999, 1010]);
+        assert_eq!(
+            invalid_ids_in(998..=1012).collect::<Vec<u128>>(),
+            vec![999, 1010]
+        );
     }
 
     #[test]
     fn test_range_1188511880_1188511890() {
         assert_eq!(
-            collect_invalid_ids_in_range(1188511880, 1188511890),
+            invalid_ids_in(1188511880..=1188511890).collect::<Vec<u128>>(),
             vec![1188511885]
         );
     }
 
     #[test]
     fn test_range_222220_222224() {
-        assert_eq!(collect_invalid_ids_in_range(222220, 222224), vec![222222]);
+        assert_eq!(
+            invalid_ids_in(222220..=222224).collect::<Vec<u128>>(),
+            vec![222222]
+        );
     }
 
     #[test]
     fn test_range_1698522_1698528() {
         assert_eq!(
-            collect_invalid_ids_in_range(1698522, 1698528),
-            Vec::<i64>::new()
+            invalid_ids_in(1698522..=1698528).collect::<Vec<u128>>(),
+            Vec::<u128>::new()
         );
     }
 
     #[test]
     fn test_range_446443_446449() {
-        assert_eq!(collect_invalid_ids_in_range(446443, 446449), vec![446446]);
+        assert_eq!(
+            invalid_ids_in(446443..=446449).collect::<Vec<u128>>(),
+            vec![446446]
+        );
     }
 
     #[test]
     fn test_range_38593856_38593862() {
         assert_eq!(
-            collect_invalid_ids_in_range(38593856, 38593862),
+            invalid_ids_in(38593856..=38593862).collect::<Vec<u128>>(),
             vec![38593859]
         );
     }
 
     #[test]
     fn test_range_565653_565659() {
-        assert_eq!(collect_invalid_ids_in_range(565653, 565659), vec![565656]);
+        assert_eq!(
+            invalid_ids_in(565653..=565659).collect::<Vec<u128>>(),
+            vec![565656]
+        );
     }
 
     #[test]
     fn test_range_824824821_824824827() {
         assert_eq!(
-            collect_invalid_ids_in_range(824824821, 824824827),
+            invalid_ids_in(824824821..=824824827).collect::<Vec<u128>>(),
             vec![824824824]
         );
     }
@@ -226,15 +275,70 @@ mod tests {
     #[test]
     fn test_range_2121212118_2121212124() {
         assert_eq!(
-            collect_invalid_ids_in_range(2121212118, 2121212124),
+            invalid_ids_in(2121212118..=2121212124).collect::<Vec<u128>>(),
             vec![2121212121]
         );
     }
 
     #[test]
     fn test_solve() {
-        let input = "11-22,95-115,998-1012,1188511880-1188511890,222220-222224,1698522-1698528,446443-446449,38593856-38593862,565653-565659,824824821-824824827,2121212118-2121212124";
-        let result = solve(input);
-        assert_eq!(result, "4174379265");
+        let result = solve(&Day02::parse(Day02::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day02::METADATA.example_answer(2)
+        );
+    }
+
+    #[test]
+    fn test_count_and_list_match_sum() {
+        let ranges = Day02::parse(Day02::METADATA.example).unwrap();
+        let ids: Vec<u128> = invalid_ids(&ranges).collect();
+        assert_eq!(ids.len(), count_invalid_ids(&ranges));
+        assert!(ids.is_sorted());
+        assert_eq!(
+            Some(ids.iter().sum::<u128>().to_string().as_str()),
+            Day02::METADATA.example_answer(2)
+        );
+    }
+
+    #[test]
+    fn test_solve_cancellable() {
+        let ranges = Day02::parse(Day02::METADATA.example).unwrap();
+        let token = CancelToken::new();
+        assert_eq!(solve_cancellable(&ranges, &token), Ok(solve(&ranges)));
+        token.cancel();
+        assert_eq!(solve_cancellable(&ranges, &token), Err(Cancelled));
+    }
+
+    #[test]
+    fn test_invalid_ids_in_matches_scan() {
+        for start in (0..200_000).step_by(9_973) {
+            let range = start..=start + 3_000;
+            assert_eq!(
+                invalid_ids_in(range.clone()).collect::<Vec<u128>>(),
+                invalid_ids_scan(range).collect::<Vec<u128>>()
+            );
+        }
+        let all = 0..=1_200_000;
+        assert_eq!(
+            invalid_ids_in(all.clone()).collect::<Vec<u128>>(),
+            invalid_ids_scan(all).collect::<Vec<u128>>()
+        );
+        let top = u128::MAX - 100_000..=u128::MAX;
+        assert_eq!(
+            invalid_ids_in(top.clone()).collect::<Vec<u128>>(),
+            invalid_ids_scan(top).collect::<Vec<u128>>()
+        );
+    }
+
+    #[test]
+    fn test_invalid_ids_in_yields_repetitions_once() {
+        assert_eq!(
+            invalid_ids_in(111_110..=111_112).collect::<Vec<u128>>(),
+            vec![111_111]
+        );
+        // Counted by the number of shortest blocks without repetitions of every
+        // length, e.g. 9 with 1 digit and 90 - 9 with 2 digits.
+        assert_eq!(invalid_ids_in(1..=9_999_999_999).count(), 101_088);
     }
 }
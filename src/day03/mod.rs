@@ -1,2 +1,196 @@
+use std::io::{self, BufRead};
+
+use crate::check::{Diagnostic, into_result};
+use crate::parsing::read_lines;
+use crate::solver::{Answer, Metadata, Part2, Solver};
+
 pub mod part1;
 pub mod part2;
+
+/// The solution of day 3: picking the highest joltage from battery banks.
+pub struct Day03;
+
+impl Solver for Day03 {
+    /// The battery banks as strings of digits.
+    type Parsed = Vec<String>;
+
+    const METADATA: Metadata = Metadata {
+        title: "Lobby",
+        example: "987654321111111\n811111111111119\n234234234234278\n818181911112111",
+        example_answers: [Some("357"), Some("3121910778619")],
+        complexity: "Every bank is scanned once per picked digit, 2 in part 1 and 12 in part 2: O(banks × bank length × digits).",
+    };
+
+    /// Parses one bank per line.
+    ///
+    /// The input is split with [`str::lines`], so a trailing line break does
+    /// not yield an empty bank.
+    ///
+    /// # Returns
+    /// The banks, or a [`Diagnostic`] for the first line that is not a bank
+    /// of at least twelve digits, like [`validate`] reports it.
+    fn parse(input: &str) -> Result<Vec<String>, Diagnostic> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| match check_bank(line) {
+                Some((column, message)) => Err(Diagnostic::at_column(index + 1, column, message)),
+                None => Ok(line.to_string()),
+            })
+            .collect()
+    }
+
+    fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+        validate(input)
+    }
+
+    fn part1(banks: &Vec<String>) -> Answer {
+        part1::solve(banks)
+    }
+}
+
+impl Part2 for Day03 {
+    fn part2(banks: &Vec<String>) -> Answer {
+        part2::solve(banks)
+    }
+}
+
+crate::register_day!(
+    year: 2025,
+    day: 3,
+    solver: Day03,
+    parts: [1, 2],
+    generator: crate::generators::day03,
+);
+
+/// Validates that every line is a bank of at least twelve digits.
+///
+/// Twelve digits are needed by part 2. The lines are split like in
+/// [`Day03::parse`], so a trailing line break is accepted.
+pub fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+    into_result(
+        input
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                check_bank(line)
+                    .map(|(column, message)| Diagnostic::at_column(index + 1, column, message))
+            })
+            .collect(),
+    )
+}
+
+/// The answer of both parts if the total joltage overflows the integer type
+/// the part sums in, which takes far more banks than any real input has.
+pub const OVERFLOW: &str = "the total joltage overflows";
+
+/// Reads the banks line by line, without holding the whole input in memory.
+///
+/// Every line is checked like in [`validate`] before it is passed on, so the
+/// `solve_reader` functions of both parts never see a malformed bank.
+///
+/// # Returns
+/// An iterator over the banks; an item is an `InvalidData` error naming the
+/// line if it is not a bank of at least twelve digits.
+pub fn read_banks(reader: impl BufRead) -> impl Iterator<Item = io::Result<String>> {
+    read_lines(reader, |line| match check_bank(line) {
+        Some((column, message)) => Err(format!("column {}: {}", column, message)),
+        None => Ok(line.to_string()),
+    })
+}
+
+/// Checks that a line is a bank of at least twelve digits.
+///
+/// # Returns
+/// The column and message of the problem, or `None` if the bank is valid.
+fn check_bank(line: &str) -> Option<(usize, String)> {
+    if let Some(index) = line.bytes().position(|byte| !byte.is_ascii_digit()) {
+        Some((
+            index + 1,
+            format!(
+                "expected only digits, found '{}'",
+                line.as_bytes()[index].escape_ascii()
+            ),
+        ))
+    } else if line.len() < 12 {
+        Some((
+            line.len() + 1,
+            format!("expected at least 12 digits, found {}", line.len()),
+        ))
+    } else {
+        None
+    }
+}
+
+/// Returns the numeric value of the digit at `index` in a digit string.
+///
+/// The byte is converted directly (`byte - b'0'`) instead of slicing and
+/// parsing a one-character string.
+///
+/// # Parameters
+/// - `range`: A string slice consisting only of digit characters.
+/// - `index`: The zero-based byte index of the digit.
+///
+/// # Returns
+/// The digit value in `0..=9`.
+///
+/// # Panics
+/// - If the byte at `index` is not an ASCII digit.
+fn digit_at(range: &str, index: usize) -> u8 {
+    let byte: u8 = range.as_bytes()[index];
+    assert!(
+        byte.is_ascii_digit(),
+        "expected a digit, found '{}'",
+        byte as char
+    );
+    byte - b'0'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::try_solve_both;
+
+    #[test]
+    fn test_digit_at() {
+        assert_eq!(digit_at("0579", 0), 0);
+        assert_eq!(digit_at("0579", 3), 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected a digit")]
+    fn test_digit_at_non_digit() {
+        digit_at("1x3", 1);
+    }
+
+    #[test]
+    fn test_parse_rejects_invalid_bank() {
+        assert_eq!(
+            Day03::parse("987654321111111\n12x4"),
+            Err(Diagnostic::at_column(
+                2,
+                3,
+                "expected only digits, found 'x'"
+            ))
+        );
+        assert_eq!(
+            Day03::parse("987654321111111\n12345"),
+            Err(Diagnostic::at_column(
+                2,
+                6,
+                "expected at least 12 digits, found 5"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_trailing_line_break() {
+        let input = format!("{}\n", Day03::METADATA.example);
+        assert!(validate(&input).is_ok());
+        assert_eq!(Day03::parse(&input).unwrap().len(), 4);
+        assert_eq!(
+            try_solve_both::<Day03>(&input),
+            Ok((Answer::I64(357), Answer::I64(3121910778619)))
+        );
+    }
+}
@@ -1,29 +1,70 @@
+use std::io::{self, BufRead};
+
+use super::{OVERFLOW, digit_at, read_banks};
+
+use crate::integer::Integer;
+use crate::parsing::try_consume;
+use crate::solver::Answer;
+
 /// Computes the total joltage value for all battery banks in the input.
 ///
-/// Each line in the input represents a single battery bank.
 /// For each bank, the maximum possible two-digit joltage value
 /// is derived from that bank. The resulting joltages are summed
-/// and returned as a string.
+/// and returned as an [`Answer`].
 ///
 /// # Parameters
-/// - `input`: A string containing one bank per line.
+/// - `banks`: The battery banks parsed by [`Day03`](super::Day03).
 ///
 /// # Returns
-/// A string containing the total sum of all computed joltages.
+/// An [`Answer`] holding the total sum of all computed joltages.
 ///
 /// # Panics
-/// This function will panic if any line contains non-numeric characters
-/// or if joltage construction/parsing fails internally.
-pub fn solve(input: &str) -> String {
-    let mut result: i32 = 0;
+/// Panics if a bank contains anything but digits or has fewer than 2
+/// digits. [`Day03::parse`](super::Day03) rejects such banks, so the banks it
+/// returns never panic.
+pub fn solve(banks: &[String]) -> Answer {
+    total(banks.iter())
+}
+
+/// Like [`solve`], but reads the banks line by line from `reader`, so even
+/// huge inputs never have to be loaded into memory at once.
+///
+/// # Returns
+/// The answer, or an error if reading failed or a line is not a valid bank.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day03::{Day03, part1::solve_reader};
+/// use aoc2025::solver::Solver;
+///
+/// let answer = solve_reader(Day03::METADATA.example.as_bytes()).unwrap();
+/// assert_eq!(answer.to_string(), "357");
+/// ```
+pub fn solve_reader(reader: impl BufRead) -> io::Result<Answer> {
+    try_consume(read_banks(reader), |banks| total(banks))
+}
+
+/// Sums the best joltage of every bank in `i64`.
+///
+/// # Returns
+/// The sum, or the text [`OVERFLOW`] if it overflows `i64`.
+fn total(banks: impl Iterator<Item = impl AsRef<str>>) -> Answer {
+    total_joltage::<i64>(banks).map_or_else(|| Answer::from(OVERFLOW), Answer::from)
+}
+
+/// Sums the best joltage of every bank in any [`Integer`] type.
+///
+/// # Returns
+/// The sum, or `None` if it overflows `N`.
+pub fn total_joltage<N: Integer>(banks: impl Iterator<Item = impl AsRef<str>>) -> Option<N> {
+    let mut result: N = N::zero();
 
-    let banks = input.split("\n");
     for bank in banks {
-        let joltage: i32 = find_best_joltage(bank);
-        result += joltage;
+        let joltage: i32 = find_best_joltage(bank.as_ref());
+        result = result.checked_add(&N::from_i64(joltage.into()))?;
     }
 
-    result.to_string()
+    Some(result)
 }
 
 /// Finds the best possible two-digit joltage in a digit string.
@@ -31,7 +72,7 @@ pub fn solve(input: &str) -> String {
 /// The algorithm selects the highest digit among all but the final
 /// position, and then selects the highest digit found to the *right*
 /// of that position. The original order of digits is always preserved.
-/// The two selected digits are combined into a two-digit number.
+/// The two selected digits are combined numerically into a two-digit number.
 ///
 /// # Parameters
 /// - `bank`: A string slice representing a sequence of digit characters (`'0'`–`'9'`).
@@ -42,17 +83,22 @@ pub fn solve(input: &str) -> String {
 /// # Panics
 /// - If `bank` contains any non-digit characters.
 /// - If the string has length < 2.
-/// - If parsing the constructed two-digit number fails.
 fn find_best_joltage(bank: &str) -> i32 {
+    assert!(
+        bank.len() >= 2,
+        "expected at least 2 digits, found {}",
+        bank.len()
+    );
+
     let first_slice: &str = &bank[0..(bank.len() - 1)];
     let first_index: usize = find_highest_number(first_slice);
 
     let second_slice: &str = &bank[(first_index + 1)..(bank.len())];
     let second_index: usize = find_highest_number(second_slice);
 
-    (first_slice[first_index..=first_index].to_owned() + &second_slice[second_index..=second_index])
-        .parse()
-        .unwrap()
+    let first_digit: i32 = digit_at(first_slice, first_index) as i32;
+    let second_digit: i32 = digit_at(second_slice, second_index) as i32;
+    first_digit * 10 + second_digit
 }
 
 /// Returns the index of the highest digit within a digit substring.
@@ -69,14 +115,13 @@ fn find_best_joltage(bank: &str) -> i32 {
 ///
 /// # Panics
 /// - If any character in the range is not a digit.
-/// - If indexing into the string fails (e.g., non-ASCII digits).
 fn find_highest_number(range: &str) -> usize {
     let mut index = 0;
     let mut value = 0;
     for i in 0..range.len() {
-        let digit_value: i32 = range[i..(i + 1)].parse().unwrap();
-        if value < digit_value {
-            value = digit_value;
+        let digit: u8 = digit_at(range, i);
+        if value < digit {
+            value = digit;
             index = i;
         }
     }
@@ -86,6 +131,8 @@ fn find_highest_number(range: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day03::Day03;
+    use crate::solver::Solver;
 
     #[test]
     fn test_find_highest_number_simple() {
@@ -132,10 +179,27 @@ mod tests {
         assert_eq!(find_best_joltage("818181911112111"), 92);
     }
 
+    #[test]
+    #[should_panic(expected = "expected at least 2 digits, found 0")]
+    fn test_find_best_joltage_empty_bank() {
+        find_best_joltage("");
+    }
+
     #[test]
     fn test_solve() {
-        let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
-        let result = solve(input);
-        assert_eq!(result, "357");
+        let result = solve(&Day03::parse(Day03::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day03::METADATA.example_answer(1)
+        );
+    }
+
+    #[test]
+    fn test_solve_reader() {
+        let result = solve_reader(Day03::METADATA.example.as_bytes()).unwrap();
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day03::METADATA.example_answer(1)
+        );
     }
 }
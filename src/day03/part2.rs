@@ -1,29 +1,61 @@
+use std::io::{self, BufRead};
+
+use super::{OVERFLOW, digit_at, read_banks};
+
+use crate::integer::Integer;
+use crate::parsing::try_consume;
+use crate::solver::Answer;
+
 /// Computes the total joltage value for all battery banks in the input.
 ///
-/// Each line in the input represents a single battery bank.
 /// For each bank, the maximum possible twelve-digit joltage value
 /// is derived from that bank. The resulting joltages are summed
-/// and returned as a string.
+/// and returned as an [`Answer`].
 ///
 /// # Parameters
-/// - `input`: A string containing one bank per line.
+/// - `banks`: The battery banks parsed by [`Day03`](super::Day03).
 ///
 /// # Returns
-/// A string containing the total sum of all computed joltages.
+/// An [`Answer`] holding the total sum of all computed joltages.
 ///
 /// # Panics
-/// This function will panic if any line contains non-numeric characters
-/// or if joltage construction/parsing fails internally.
-pub fn solve(input: &str) -> String {
-    let mut result: i64 = 0;
+/// Panics if a bank contains anything but digits or has fewer than 12
+/// digits. [`Day03::parse`](super::Day03) rejects such banks, so the banks it
+/// returns never panic.
+pub fn solve(banks: &[String]) -> Answer {
+    total(banks.iter())
+}
+
+/// Like [`solve`], but reads the banks line by line from `reader`, so even
+/// huge inputs never have to be loaded into memory at once.
+///
+/// # Returns
+/// The answer, or an error if reading failed or a line is not a valid bank.
+pub fn solve_reader(reader: impl BufRead) -> io::Result<Answer> {
+    try_consume(read_banks(reader), |banks| total(banks))
+}
+
+/// Sums the best joltage of every bank in `i128`.
+///
+/// # Returns
+/// The sum, or the text [`OVERFLOW`] if it overflows `i128`.
+fn total(banks: impl Iterator<Item = impl AsRef<str>>) -> Answer {
+    total_joltage::<i128>(banks).map_or_else(|| Answer::from(OVERFLOW), Answer::from)
+}
+
+/// Sums the best joltage of every bank in any [`Integer`] type.
+///
+/// # Returns
+/// The sum, or `None` if it overflows `N`.
+pub fn total_joltage<N: Integer>(banks: impl Iterator<Item = impl AsRef<str>>) -> Option<N> {
+    let mut result: N = N::zero();
 
-    let banks = input.split("\n");
     for bank in banks {
-        let joltage: i64 = find_best_joltage(bank);
-        result += joltage;
+        let joltage: i64 = find_best_joltage(bank.as_ref());
+        result = result.checked_add(&N::from_i64(joltage))?;
     }
 
-    result.to_string()
+    Some(result)
 }
 
 /// Computes the maximum twelve-digit joltage that can be obtained from a battery bank.
@@ -33,7 +65,7 @@ pub fn solve(input: &str) -> String {
 /// 1. A slice of the bank is taken from the current `start_index` up to the end of
 ///    the remaining window needed to complete 12 digits.
 /// 2. The highest digit in that slice is found using [`find_highest_number`].
-/// 3. That digit is appended to the result string.
+/// 3. That digit is appended to the result (`result * 10 + digit`).
 /// 4. `start_index` is advanced to the next position after the chosen digit.
 ///
 /// The order of digits in the original bank is always preserved.
@@ -47,20 +79,30 @@ pub fn solve(input: &str) -> String {
 /// # Panics
 /// - If `bank` contains non-digit characters.
 /// - If the bank is too short to construct a 12-digit joltage.
-/// - If parsing the constructed string as `i64` fails.
+/// - If the constructed joltage overflows `i64`.
 fn find_best_joltage(bank: &str) -> i64 {
-    let mut result: String = "".to_string();
+    assert!(
+        bank.len() >= 12,
+        "expected at least 12 digits, found {}",
+        bank.len()
+    );
+
+    let mut result: i64 = 0;
 
     let mut start_index: usize = 0;
     for i in 1..=12 {
         let end_index: usize = bank.len() - 12 + i;
         let slice: &str = &bank[start_index..end_index];
         let found_index: usize = find_highest_number(slice);
-        result = result.to_owned() + &slice[found_index..=found_index];
+        let digit: i64 = digit_at(slice, found_index) as i64;
+        result = result
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(digit))
+            .expect("joltage overflows i64");
         start_index = start_index + found_index + 1;
     }
 
-    result.parse().unwrap()
+    result
 }
 
 /// Returns the index of the highest digit within a digit substring.
@@ -77,14 +119,13 @@ fn find_best_joltage(bank: &str) -> i64 {
 ///
 /// # Panics
 /// - If any character in the range is not a digit.
-/// - If indexing into the string fails (e.g., non-ASCII digits).
 fn find_highest_number(range: &str) -> usize {
     let mut index = 0;
     let mut value = 0;
     for i in 0..range.len() {
-        let digit_value: i32 = range[i..(i + 1)].parse().unwrap();
-        if value < digit_value {
-            value = digit_value;
+        let digit: u8 = digit_at(range, i);
+        if value < digit {
+            value = digit;
             index = i;
         }
     }
@@ -94,6 +135,8 @@ fn find_highest_number(range: &str) -> usize {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day03::Day03;
+    use crate::solver::Solver;
 
     #[test]
     fn test_find_highest_number_simple() {
@@ -140,10 +183,37 @@ mod tests {
         assert_eq!(find_best_joltage("818181911112111"), 888911112111);
     }
 
+    #[test]
+    #[should_panic(expected = "expected at least 12 digits, found 5")]
+    fn test_find_best_joltage_short_bank() {
+        find_best_joltage("12345");
+    }
+
     #[test]
     fn test_solve() {
-        let input = "987654321111111\n811111111111119\n234234234234278\n818181911112111";
-        let result = solve(input);
-        assert_eq!(result, "3121910778619");
+        let result = solve(&Day03::parse(Day03::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day03::METADATA.example_answer(2)
+        );
+    }
+
+    #[test]
+    fn test_solve_reader() {
+        let result = solve_reader(Day03::METADATA.example.as_bytes()).unwrap();
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day03::METADATA.example_answer(2)
+        );
+    }
+
+    #[test]
+    fn test_solve_reader_short_bank() {
+        let err = solve_reader("987654321111111\n12345".as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            err.to_string(),
+            "line 2: column 6: expected at least 12 digits, found 5"
+        );
     }
 }
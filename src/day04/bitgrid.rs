@@ -0,0 +1,277 @@
+use crate::cancel::{CancelToken, Cancelled};
+use crate::grid::Grid;
+
+/// Number of cells stored in one packed word.
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A padded grid of rolls where each row is packed into `u64` words.
+///
+/// Cell `(x, y)` of the puzzle is stored at bit `x + 1` of row `y + 1`.
+/// The outermost rows and the first/last bit of every row act as padding
+/// and are always `false`, so neighbor lookups never go out of bounds.
+///
+/// Packing the rows allows all neighbor counts of 64 cells to be computed
+/// at once with shifts and bitwise adds instead of one `if` per neighbor.
+/// The words of all rows share a single flat [`Grid`] allocation.
+#[derive(Debug, Clone)]
+pub struct BitGrid {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    rows: Grid<u64>,
+}
+
+impl BitGrid {
+    /// Parses the puzzle input into a packed, padded grid.
+    ///
+    /// Each line becomes one row and every `'@'` is stored as a set bit.
+    /// The width of the grid is taken from the longest line; shorter lines
+    /// are treated as if they were filled up with empty cells.
+    ///
+    /// # Arguments
+    /// * `input` – The raw multiline string to parse.
+    #[tracing::instrument(name = "parse", level = "debug", skip_all)]
+    pub fn parse(input: &str) -> BitGrid {
+        let lines: Vec<&str> = input.lines().collect();
+        let width: usize = lines.iter().map(|line| line.len()).max().unwrap_or(0);
+        let height: usize = lines.len();
+        let words_per_row: usize = (width + 2).div_ceil(WORD_BITS);
+
+        let mut rows: Grid<u64> = Grid::new(words_per_row, height + 2, 0);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, byte) in line.bytes().enumerate() {
+                if byte == b'@' {
+                    let bit = x + 1;
+                    rows[y + 1][bit / WORD_BITS] |= 1 << (bit % WORD_BITS);
+                }
+            }
+        }
+
+        BitGrid {
+            width,
+            height,
+            words_per_row,
+            rows,
+        }
+    }
+
+    /// Returns the number of columns of the puzzle grid, without the padding.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows of the puzzle grid, without the padding.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns whether the cell at column `x` and row `y` contains a roll.
+    ///
+    /// Coordinates are given in puzzle space, i.e. without the padding.
+    pub fn get(&self, x: usize, y: usize) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        let bit = x + 1;
+        self.rows[y + 1][bit / WORD_BITS] >> (bit % WORD_BITS) & 1 == 1
+    }
+
+    /// Returns the number of rolls in the grid.
+    pub fn count(&self) -> usize {
+        self.rows
+            .cells()
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum()
+    }
+
+    /// Computes, for one packed word of a row, which rolls have fewer than
+    /// four rolls among their eight neighbors.
+    ///
+    /// The eight neighbor planes are built with shifts (carrying bits over
+    /// from the adjacent words) and summed with a bit-sliced ripple adder,
+    /// producing a 4-bit counter per cell. A count below four means that
+    /// neither bit 2 nor bit 3 of the counter is set.
+    ///
+    /// # Arguments
+    /// * `row` – The padded row index (`1..=height`).
+    /// * `word` – The word index within that row.
+    ///
+    /// # Returns
+    /// A mask with a bit set for every accessible roll in the word.
+    fn accessible_in_word(&self, row: usize, word: usize) -> u64 {
+        let mut counter: [u64; 4] = [0; 4];
+        for dy in 0..3 {
+            let neighbor_row: &[u64] = &self.rows[row + dy - 1];
+            let center: u64 = neighbor_row[word];
+            let previous: u64 = if word > 0 { neighbor_row[word - 1] } else { 0 };
+            let next: u64 = neighbor_row.get(word + 1).copied().unwrap_or(0);
+
+            let west: u64 = (center << 1) | (previous >> (WORD_BITS - 1));
+            let east: u64 = (center >> 1) | (next << (WORD_BITS - 1));
+
+            add_plane(&mut counter, west);
+            add_plane(&mut counter, east);
+            if dy != 1 {
+                add_plane(&mut counter, center);
+            }
+        }
+
+        self.rows[row][word] & !(counter[2] | counter[3])
+    }
+
+    /// Counts the rolls that have fewer than four neighboring rolls.
+    ///
+    /// # Returns
+    /// The number of currently accessible rolls.
+    pub fn count_accessible(&self) -> usize {
+        let mut result: usize = 0;
+        for row in 1..=self.height {
+            for word in 0..self.words_per_row {
+                result += self.accessible_in_word(row, word).count_ones() as usize;
+            }
+        }
+        result
+    }
+
+    /// Removes accessible rolls until no roll has fewer than four neighbors.
+    ///
+    /// Rows are updated in place while scanning. Removing a roll can only
+    /// lower the neighbor counts of other rolls, so the order of removals
+    /// does not influence the final result.
+    ///
+    /// # Returns
+    /// The total number of removed rolls.
+    pub fn remove_accessible(&mut self) -> usize {
+        self.remove_accessible_cancellable(&CancelToken::new())
+            .unwrap_or_else(|_| unreachable!("a new token is never cancelled"))
+    }
+
+    /// Like [`BitGrid::remove_accessible`], but checks `cancel` before every
+    /// removal pass and stops once it is cancelled.
+    ///
+    /// The rolls removed by the finished passes stay removed.
+    ///
+    /// # Returns
+    /// The total number of removed rolls, or [`Cancelled`].
+    pub fn remove_accessible_cancellable(
+        &mut self,
+        cancel: &CancelToken,
+    ) -> Result<usize, Cancelled> {
+        let mut result: usize = 0;
+        loop {
+            cancel.check()?;
+            let mut removed: usize = 0;
+            for row in 1..=self.height {
+                for word in 0..self.words_per_row {
+                    let accessible: u64 = self.accessible_in_word(row, word);
+                    self.rows[row][word] &= !accessible;
+                    removed += accessible.count_ones() as usize;
+                }
+            }
+            tracing::trace!(removed, "removal pass");
+            if removed == 0 {
+                break;
+            }
+            result += removed;
+        }
+        Ok(result)
+    }
+}
+
+/// Adds a single bit plane to a bit-sliced 4-bit counter.
+///
+/// Every bit position of `counter` forms an independent counter, with
+/// `counter[0]` holding the least significant bit. The carry is rippled
+/// through all four planes without any branching.
+fn add_plane(counter: &mut [u64; 4], plane: u64) {
+    let mut carry: u64 = plane;
+    for bit in counter.iter_mut() {
+        let sum: u64 = *bit ^ carry;
+        carry &= *bit;
+        *bit = sum;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day04::Day04;
+    use crate::solver::Solver;
+
+    const EXAMPLE: &str = Day04::METADATA.example;
+
+    /// Counts accessible rolls cell by cell, used as a reference.
+    fn count_accessible_per_cell(grid: &BitGrid) -> usize {
+        let mut result: usize = 0;
+        for y in 0..grid.height {
+            for x in 0..grid.width {
+                if !grid.get(x, y) {
+                    continue;
+                }
+                let mut neighbors: usize = 0;
+                for dy in -1i64..=1 {
+                    for dx in -1i64..=1 {
+                        let nx = x as i64 + dx;
+                        let ny = y as i64 + dy;
+                        if (dx != 0 || dy != 0)
+                            && nx >= 0
+                            && ny >= 0
+                            && grid.get(nx as usize, ny as usize)
+                        {
+                            neighbors += 1;
+                        }
+                    }
+                }
+                if neighbors < 4 {
+                    result += 1;
+                }
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn test_parse() {
+        let grid = BitGrid::parse(".@\n@.");
+        assert!(!grid.get(0, 0));
+        assert!(grid.get(1, 0));
+        assert!(grid.get(0, 1));
+        assert!(!grid.get(1, 1));
+        assert_eq!(grid.count(), 2);
+        assert_eq!((grid.width(), grid.height()), (2, 2));
+    }
+
+    #[test]
+    fn test_add_plane() {
+        let mut counter: [u64; 4] = [0; 4];
+        for _ in 0..8 {
+            add_plane(&mut counter, 0b10);
+        }
+        add_plane(&mut counter, 0b01);
+        assert_eq!(counter, [0b01, 0, 0, 0b10]);
+    }
+
+    #[test]
+    fn test_count_accessible_example() {
+        let grid = BitGrid::parse(EXAMPLE);
+        assert_eq!(grid.count_accessible(), 13);
+    }
+
+    #[test]
+    fn test_count_accessible_across_word_boundaries() {
+        let line: String = (0..150)
+            .map(|i| if i % 3 == 0 { '.' } else { '@' })
+            .collect();
+        let input: String = [line.as_str(); 5].join("\n");
+        let grid = BitGrid::parse(&input);
+        assert_eq!(grid.count_accessible(), count_accessible_per_cell(&grid));
+    }
+
+    #[test]
+    fn test_remove_accessible_example() {
+        let mut grid = BitGrid::parse(EXAMPLE);
+        assert_eq!(grid.remove_accessible(), 43);
+        assert_eq!(grid.count_accessible(), 0);
+    }
+}
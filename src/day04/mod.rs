@@ -1,2 +1,77 @@
+use crate::check::{Diagnostic, into_result};
+use crate::solver::{Answer, Metadata, Part2, Solver};
+
+use bitgrid::BitGrid;
+
+pub mod bitgrid;
 pub mod part1;
 pub mod part2;
+
+/// The solution of day 4: removing the paper rolls reachable by forklifts.
+pub struct Day04;
+
+impl Solver for Day04 {
+    /// The rolls packed into a padded bit grid.
+    type Parsed = BitGrid;
+
+    const METADATA: Metadata = Metadata {
+        title: "Printing Department",
+        example: "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.",
+        example_answers: [Some("13"), Some("43")],
+        complexity: "Part 1 counts the neighbours of every cell once: O(width × height); part 2 repeats that until nothing is removed: O(passes × width × height).",
+    };
+
+    fn parse(input: &str) -> Result<BitGrid, Diagnostic> {
+        Ok(BitGrid::parse(input))
+    }
+
+    fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+        validate(input)
+    }
+
+    fn part1(grid: &BitGrid) -> Answer {
+        part1::solve(grid)
+    }
+}
+
+impl Part2 for Day04 {
+    fn part2(grid: &BitGrid) -> Answer {
+        part2::solve(grid)
+    }
+}
+
+crate::register_day!(
+    year: 2025,
+    day: 4,
+    solver: Day04,
+    parts: [1, 2],
+    generator: crate::generators::day04,
+);
+
+/// Validates that the input is a grid of `'@'` and `'.'` cells.
+pub fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+    if input.trim().is_empty() {
+        return Err(vec![Diagnostic::input("input is empty")]);
+    }
+
+    into_result(
+        input
+            .lines()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let (column, cell) = line
+                    .chars()
+                    .enumerate()
+                    .find(|&(_, cell)| cell != '@' && cell != '.')?;
+                Some(Diagnostic::at_column(
+                    index + 1,
+                    column + 1,
+                    format!(
+                        "expected only '@' and '.', found '{}'",
+                        cell.escape_default()
+                    ),
+                ))
+            })
+            .collect(),
+    )
+}
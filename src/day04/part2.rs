@@ -1,144 +1,63 @@
+use super::bitgrid::BitGrid;
+
+use crate::cancel::{self, CancelToken, Cancelled};
+use crate::solver::Answer;
+
 /// Computes the total number of removed rolls based on the puzzle rules.
 ///
 /// The function performs the following steps:
-/// 1. Parses the input into a two-dimensional boolean grid, where `true`
-///    represents an `'@'`.
-/// 2. Adds a padding border around the grid to avoid bounds checking.
-/// 3. Repeatedly scans the inner area of the grid and removes any `true` cell
-///    whose number of adjacent `true` neighbors (8-directional) is below 4.
-///    Each removal increases the result counter.
-/// 4. The process continues until a full pass removes nothing.
+/// 1. Copies the packed [`BitGrid`], where each set bit represents an `'@'`
+///    and a padding border avoids bounds checking.
+/// 2. Repeatedly removes every roll whose number of adjacent rolls
+///    (8-directional) is below 4, processing a whole word of cells per
+///    operation. Each removal increases the result counter.
+/// 3. The process continues until a full pass removes nothing.
 ///
 /// # Arguments
-/// * `input` – A multiline string representing the raw puzzle input.
+/// * `grid` – The puzzle grid parsed by [`Day04`](super::Day04).
 ///
 /// # Returns
-/// A string containing the total number of removed rolls.
-pub fn solve(input: &str) -> String {
-    let mut result: i32 = 0;
-
-    let mut grid: Vec<Vec<bool>> = parse_input_to_bool_grid(input);
-    pad_grid(&mut grid);
-
-    let height: usize = grid.len();
-    let width: usize = grid[0].len();
-    loop {
-        let mut removed = false;
-        for h in 1..(height - 1) {
-            for w in 1..(width - 1) {
-                if !grid[h][w] {
-                    continue;
-                }
-
-                if count_rolls_around_position(&grid, h, w) < 4 {
-                    result += 1;
-                    grid[h][w] = false;
-                    removed = true;
-                }
-            }
-        }
-        if !removed {
-            break;
-        }
-    }
-
-    result.to_string()
-}
-
-/// Parses the given input string into a two-dimensional boolean grid.
-///
-/// Each line in the input becomes one row in the grid.  
-/// Each character in a line is converted to `true` if it is `'@'`
-/// and `false` otherwise.
-///
-/// The function returns a `Vec<Vec<bool>>` where all rows have the same
-/// length as their corresponding input lines.
-///
-/// # Arguments
-/// * `input` – The raw multiline string to parse.
-fn parse_input_to_bool_grid(input: &str) -> Vec<Vec<bool>> {
-    input
-        .lines()
-        .map(|line: &str| line.chars().map(|c: char| c == '@').collect::<Vec<bool>>())
-        .collect()
-}
-
-/// Adds a padding border around a two-dimensional boolean grid.
-///
-/// A new row is inserted at the top and bottom,
-/// and each existing row receives a new first and last element.
-/// All padding cells are filled with false.
-///
-/// This operation modifies the grid in place.
-///
-/// # Arguments
-/// * `grid` – The grid to be extended.
-fn pad_grid(grid: &mut Vec<Vec<bool>>) {
-    let width = grid[0].len();
-
-    grid.insert(0, vec![false; width]);
-
-    grid.push(vec![false; width]);
-
-    for row in grid.iter_mut() {
-        row.insert(0, false);
-        row.push(false);
-    }
+/// An [`Answer`] holding the total number of removed rolls, or an empty
+/// answer if the process-wide [`cancel::global`] token was cancelled (e.g. by
+/// Ctrl-C in the `aoc` binary) before the grid was stable. The runner reports
+/// such a run as cancelled, and the fallible API, e.g. [`crate::solve`],
+/// returns [`SolveError::Cancelled`](crate::SolveError::Cancelled) instead;
+/// use [`solve_cancellable`] to get [`Cancelled`] directly.
+pub fn solve(grid: &BitGrid) -> Answer {
+    solve_cancellable(grid, cancel::global())
+        .unwrap_or_else(|Cancelled| Answer::Text(String::new()))
 }
 
-/// Counts how many of the eight surrounding cells contain a roll (`true`).
-///
-/// The function looks at all 8 neighbors of the given position:
-/// top-left, top, top-right, left, right, bottom-left, bottom,
-/// and bottom-right.
-///
-/// The grid is expected to already contain padding, ensuring that all
-/// neighboring indices are valid.
-///
-/// # Arguments
-/// * `grid` – A two-dimensional boolean grid.
-/// * `h` – The row index of the cell.
-/// * `w` – The column index of the cell.
+/// Like [`solve`], but checks `cancel` before every removal pass.
 ///
 /// # Returns
-/// The number of surrounding cells that contain `true`.
-fn count_rolls_around_position(grid: &Vec<Vec<bool>>, h: usize, w: usize) -> i32 {
-    let mut count: i32 = 0;
-    if grid[h - 1][w - 1] {
-        count += 1;
-    }
-    if grid[h - 1][w] {
-        count += 1;
-    }
-    if grid[h - 1][w + 1] {
-        count += 1;
-    }
-    if grid[h][w - 1] {
-        count += 1;
-    }
-    if grid[h][w + 1] {
-        count += 1;
-    }
-    if grid[h + 1][w - 1] {
-        count += 1;
-    }
-    if grid[h + 1][w] {
-        count += 1;
-    }
-    if grid[h + 1][w + 1] {
-        count += 1;
-    }
-    count
+/// An [`Answer`] holding the total number of removed rolls, or [`Cancelled`].
+pub fn solve_cancellable(grid: &BitGrid, cancel: &CancelToken) -> Result<Answer, Cancelled> {
+    let mut grid: BitGrid = grid.clone();
+    grid.remove_accessible_cancellable(cancel).map(Answer::from)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day04::Day04;
+    use crate::solver::Solver;
 
     #[test]
     fn test_solve() {
-        let input = "..@@.@@@@.\n@@@.@.@.@@\n@@@@@.@.@@\n@.@@@@..@.\n@@.@@@@.@@\n.@@@@@@@.@\n.@.@.@.@@@\n@.@@@.@@@@\n.@@@@@@@@.\n@.@.@@@.@.";
-        let result = solve(input);
-        assert_eq!(result, "43");
+        let result = solve(&Day04::parse(Day04::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day04::METADATA.example_answer(2)
+        );
+    }
+
+    #[test]
+    fn test_solve_cancellable() {
+        let grid = Day04::parse(Day04::METADATA.example).unwrap();
+        let token = CancelToken::new();
+        assert_eq!(solve_cancellable(&grid, &token), Ok(solve(&grid)));
+        token.cancel();
+        assert_eq!(solve_cancellable(&grid, &token), Err(Cancelled));
     }
 }
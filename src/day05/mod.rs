@@ -1 +1,207 @@
+use std::ops::RangeInclusive;
+
+use crate::check::{Diagnostic, check_range, into_result, is_number};
+use crate::solver::{Answer, Metadata, Solver};
+
 pub mod part1;
+
+/// The inclusive ranges of fresh ingredient IDs, e.g. `3..=5` for `"3-5"`.
+///
+/// The ranges may overlap; an ID is fresh if any range contains it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FreshRanges {
+    ranges: Vec<RangeInclusive<i64>>,
+}
+
+impl FreshRanges {
+    /// Creates the fresh ranges from a list of inclusive ranges, kept in the given order.
+    pub fn new(ranges: Vec<RangeInclusive<i64>>) -> FreshRanges {
+        FreshRanges { ranges }
+    }
+
+    /// Returns the ranges in input order.
+    pub fn ranges(&self) -> &[RangeInclusive<i64>] {
+        &self.ranges
+    }
+
+    /// Determines whether an ID falls within at least one of the ranges.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::day05::FreshRanges;
+    ///
+    /// let ranges = FreshRanges::new(vec![3..=5, 10..=14]);
+    /// assert!(ranges.contains(5));
+    /// assert!(!ranges.contains(8));
+    /// ```
+    pub fn contains(&self, id: i64) -> bool {
+        self.ranges.iter().any(|range| range.contains(&id))
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for FreshRanges {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(ranges: I) -> FreshRanges {
+        FreshRanges::new(ranges.into_iter().collect())
+    }
+}
+
+/// The fresh ID ranges and the available IDs of the kitchen inventory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    /// The ranges of fresh IDs.
+    pub ranges: FreshRanges,
+    /// The available IDs.
+    pub ids: Vec<i64>,
+}
+
+/// The solution of day 5: checking which available ingredients are fresh.
+pub struct Day05;
+
+impl Solver for Day05 {
+    type Parsed = Inventory;
+
+    const METADATA: Metadata = Metadata {
+        title: "Cafeteria",
+        example: "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32",
+        example_answers: [Some("3"), None],
+        complexity: "Every ID is checked against every range: O(IDs × ranges).",
+    };
+
+    /// Parses the ranges, one per line, and the IDs after the first empty line.
+    ///
+    /// The input is split like in [`validate`], so every validated input parses.
+    fn parse(input: &str) -> Result<Inventory, Diagnostic> {
+        let mut lines = input.lines().enumerate();
+        let ranges = lines
+            .by_ref()
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(index, range)| {
+                parse_range(range).map_err(|message| Diagnostic::at_column(index + 1, 1, message))
+            })
+            .collect::<Result<_, Diagnostic>>()?;
+        let ids = lines
+            .filter(|(_, line)| !line.is_empty())
+            .map(|(index, id)| {
+                parse_id(id).map_err(|message| Diagnostic::at_column(index + 1, 1, message))
+            })
+            .collect::<Result<_, Diagnostic>>()?;
+        Ok(Inventory { ranges, ids })
+    }
+
+    fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+        validate(input)
+    }
+
+    fn part1(inventory: &Inventory) -> Answer {
+        part1::solve(inventory)
+    }
+}
+
+crate::register_day!(
+    year: 2025,
+    day: 5,
+    solver: Day05,
+    parts: [1],
+    generator: crate::generators::day05,
+);
+
+/// Validates that the input consists of ranges like `"3-5"`, an empty line, and one ID per line.
+pub fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some(divider_index) = lines.iter().position(|line| line.is_empty()) else {
+        return Err(vec![Diagnostic::input(
+            "expected an empty line between the ranges and the IDs",
+        )]);
+    };
+
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    for (index, range) in lines[..divider_index].iter().enumerate() {
+        if let Some(message) = check_range::<i64>(range) {
+            diagnostics.push(Diagnostic::at_column(index + 1, 1, message));
+        }
+    }
+    for (index, id) in lines.iter().enumerate().skip(divider_index + 1) {
+        if !is_number(id) {
+            diagnostics.push(Diagnostic::at_column(
+                index + 1,
+                1,
+                format!("expected an ID like '17', found '{}'", id),
+            ));
+        }
+    }
+    into_result(diagnostics)
+}
+
+/// Parses a fresh ID range like `"3-5"` into `3..=5`.
+///
+/// # Returns
+/// The range, or a message describing why it is invalid.
+fn parse_range(range: &str) -> Result<RangeInclusive<i64>, String> {
+    if let Some(message) = check_range::<i64>(range) {
+        return Err(message);
+    }
+    let (start, end) = range.split_once('-').unwrap();
+    let number = |text: &str| {
+        text.parse::<i64>()
+            .map_err(|err| format!("invalid ID '{}': {}", text, err))
+    };
+    Ok(number(start)?..=number(end)?)
+}
+
+/// Parses an available ID like `"17"`.
+///
+/// # Returns
+/// The ID, or a message describing why it is invalid.
+fn parse_id(id: &str) -> Result<i64, String> {
+    id.parse()
+        .map_err(|_| format!("expected an ID like '17', found '{}'", id))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_contains_case_1() {
+        assert!(!FreshRanges::new(vec![3..=5]).contains(1));
+    }
+
+    #[test]
+    fn test_contains_case_2() {
+        assert!(FreshRanges::new(vec![3..=5]).contains(3));
+    }
+
+    #[test]
+    fn test_contains_case_3() {
+        assert!(FreshRanges::new(vec![3..=5]).contains(4));
+    }
+
+    #[test]
+    fn test_contains_case_4() {
+        assert!(FreshRanges::new(vec![3..=5]).contains(5));
+    }
+
+    #[test]
+    fn test_contains_case_5() {
+        assert!(!FreshRanges::new(vec![3..=5]).contains(6));
+    }
+
+    #[test]
+    fn test_contains_overlapping_ranges() {
+        let ranges: FreshRanges = [10..=14, 12..=18].into_iter().collect();
+        assert!(ranges.contains(13));
+        assert!(ranges.contains(18));
+        assert!(!ranges.contains(19));
+        assert_eq!(ranges.ranges(), &[10..=14, 12..=18]);
+    }
+    #[test]
+    fn test_parse_reports_line() {
+        let err = Day05::parse("3-5\n5-x\n\n1").unwrap_err();
+        assert_eq!((err.line, err.column), (Some(2), Some(1)));
+        let err = Day05::parse("3-5\n\n1\n\n17\nx").unwrap_err();
+        assert_eq!(
+            err,
+            Diagnostic::at_column(6, 1, "expected an ID like '17', found 'x'")
+        );
+    }
+}
@@ -1,114 +1,147 @@
+use std::io::{self, BufRead};
+use std::ops::RangeInclusive;
+
+use super::{FreshRanges, Inventory, parse_id, parse_range};
+
+use crate::parsing::{invalid_line, try_consume};
+use crate::solver::Answer;
+
 /// Counts how many IDs fall within at least one of the specified ranges.
 ///
-/// The input consists of two sections separated by an empty line:
-/// 1. A list of ranges in the format `"start-end"`
-/// 2. A list of numeric IDs
-///
 /// Each ID is checked against all ranges, and is counted once if it fits in **any**
 /// of the ranges.
 ///
 /// # Arguments
-/// * `input` – Full problem input containing ranges and IDs.
+/// * `inventory` – The fresh ID ranges and available IDs parsed by [`Day05`](super::Day05).
 ///
 /// # Returns
-/// The total count of IDs that are contained in any range, encoded as `String`.
-///
-/// # Panics
-/// Panics if the input format does not contain an empty line divider
-/// or if ranges/IDs fail to parse.
-pub fn solve(input: &str) -> String {
-    let mut result: i32 = 0;
+/// The total count of IDs that are contained in any range, as an [`Answer`].
+pub fn solve(inventory: &Inventory) -> Answer {
+    count_fresh(&inventory.ranges, inventory.ids.iter().copied()).into()
+}
 
-    let lines: Vec<&str> = input.lines().collect();
-    let divider_index: usize = lines.iter().position(|&x| x == "").unwrap();
+/// Like [`solve`], but reads the inventory line by line from `reader`.
+///
+/// Only the ranges are kept in memory; the IDs after the empty line are
+/// checked one at a time, so a huge list of IDs is never loaded at once.
+///
+/// # Returns
+/// The answer, or an error if reading failed, a line is malformed, or the
+/// empty line between the ranges and the IDs is missing.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day05::{Day05, part1::solve_reader};
+/// use aoc2025::solver::Solver;
+///
+/// let answer = solve_reader(Day05::METADATA.example.as_bytes()).unwrap();
+/// assert_eq!(answer.to_string(), "3");
+/// ```
+pub fn solve_reader(reader: impl BufRead) -> io::Result<Answer> {
+    let mut lines = reader.lines().enumerate();
 
-    'id: for id in lines[(divider_index + 1)..].iter() {
-        let value: i64 = id.parse().unwrap();
-        for range in lines[..divider_index].iter() {
-            if is_id_in_range(value, range) {
-                result += 1;
-                continue 'id;
-            }
+    let mut ranges: Vec<RangeInclusive<i64>> = Vec::new();
+    loop {
+        let Some((index, line)) = lines.next() else {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "expected an empty line between the ranges and the IDs",
+            ));
+        };
+        let line = line?;
+        if line.is_empty() {
+            break;
         }
+        ranges.push(parse_range(&line).map_err(|message| invalid_line(index + 1, message))?);
     }
 
-    result.to_string()
+    let ids = lines.filter_map(|(index, line)| match line {
+        Ok(line) if line.is_empty() => None,
+        Ok(line) => Some(parse_id(&line).map_err(|message| invalid_line(index + 1, message))),
+        Err(err) => Some(Err(err)),
+    });
+    let ranges = FreshRanges::new(ranges);
+    try_consume(ids, |ids| count_fresh(&ranges, ids)).map(Answer::from)
 }
 
-/// Determines whether a given `id` falls within a numeric range defined as `"start-end"`.
+/// Lazily yields the available IDs that fall within at least one fresh range,
+/// in input order.
 ///
 /// # Arguments
-/// * `id` – The value to check.
-/// * `range` – A string slice containing the range in the format `"start-end"`.
+/// * `inventory` – The fresh ID ranges and available IDs parsed by [`Day05`](super::Day05).
 ///
 /// # Returns
-/// `true` if `id` is within the inclusive range, otherwise `false`.
+/// An iterator over the fresh IDs; an ID in several ranges is yielded once.
 ///
-/// # Panics
-/// Panics if the range string cannot be split or parsed into valid integers.
-fn is_id_in_range(id: i64, range: &str) -> bool {
-    let values: Vec<&str> = range.split("-").collect();
-    let start: i64 = values[0].parse().unwrap();
-    let end: i64 = values[1].parse().unwrap();
-    id >= start && id <= end
+/// # Examples
+/// ```
+/// use aoc2025::day05::{Day05, part1::matching_ids};
+/// use aoc2025::solver::Solver;
+///
+/// let inventory = Day05::parse(Day05::METADATA.example).unwrap();
+/// assert_eq!(matching_ids(&inventory).collect::<Vec<i64>>(), vec![5, 11, 17]);
+/// ```
+pub fn matching_ids(inventory: &Inventory) -> impl Iterator<Item = i64> + '_ {
+    fresh_ids(&inventory.ranges, inventory.ids.iter().copied())
+}
+
+/// Counts the IDs that fall within at least one of the ranges.
+fn count_fresh(ranges: &FreshRanges, ids: impl Iterator<Item = i64>) -> usize {
+    fresh_ids(ranges, ids).count()
+}
+
+/// Filters the IDs down to those within at least one of the ranges.
+fn fresh_ids<'a>(
+    ranges: &'a FreshRanges,
+    ids: impl Iterator<Item = i64> + 'a,
+) -> impl Iterator<Item = i64> + 'a {
+    ids.filter(|&id| ranges.contains(id))
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day05::Day05;
+    use crate::solver::Solver;
 
     #[test]
-    fn test_is_id_in_range_case_1() {
-        let id = 1;
-        let range = "3-5";
-        let result = is_id_in_range(id, range);
-        assert_eq!(result, false);
+    fn test_matching_ids_overlapping_ranges() {
+        let inventory = Inventory {
+            ranges: FreshRanges::new(vec![3..=5, 4..=8]),
+            ids: vec![9, 4, 1, 8, 4],
+        };
+        assert_eq!(
+            matching_ids(&inventory).collect::<Vec<i64>>(),
+            vec![4, 8, 4]
+        );
     }
 
     #[test]
-    fn test_is_id_in_range_case_2() {
-        let id = 3;
-        let range = "3-5";
-        let result = is_id_in_range(id, range);
-        assert_eq!(result, true);
-    }
-
-    #[test]
-    fn test_is_id_in_range_case_3() {
-        let id = 4;
-        let range = "3-5";
-        let result = is_id_in_range(id, range);
-        assert_eq!(result, true);
-    }
-
-    #[test]
-    fn test_is_id_in_range_case_4() {
-        let id = 4;
-        let range = "3-5";
-        let result = is_id_in_range(id, range);
-        assert_eq!(result, true);
-    }
-
-    #[test]
-    fn test_is_id_in_range_case_5() {
-        let id = 5;
-        let range = "3-5";
-        let result = is_id_in_range(id, range);
-        assert_eq!(result, true);
+    fn test_solve() {
+        let result = solve(&Day05::parse(Day05::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day05::METADATA.example_answer(1)
+        );
     }
 
     #[test]
-    fn test_is_id_in_range_case_6() {
-        let id = 6;
-        let range = "3-5";
-        let result = is_id_in_range(id, range);
-        assert_eq!(result, false);
+    fn test_solve_reader() {
+        let result = solve_reader(Day05::METADATA.example.as_bytes()).unwrap();
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day05::METADATA.example_answer(1)
+        );
     }
 
     #[test]
-    fn test_solve() {
-        let input = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32";
-        let result = solve(input);
-        assert_eq!(result, "3");
+    fn test_solve_reader_errors() {
+        let err = solve_reader("3-5\n10-14".as_bytes()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        let err = solve_reader("3-5\n\n4\nfour".as_bytes()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "line 4: expected an ID like '17', found 'four'"
+        );
     }
 }
@@ -1,2 +1,204 @@
+use std::fmt;
+
+use crate::check::{Diagnostic, into_result};
+use crate::solver::{Answer, Metadata, Part2, Solver};
+
 pub mod part1;
 pub mod part2;
+
+/// A math worksheet: rows of numbers above a row of operators.
+///
+/// Part 1 reads the numbers along the rows and part 2 down the columns,
+/// so the rows are kept as text including their alignment.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Worksheet {
+    rows: Vec<String>,
+}
+
+impl Worksheet {
+    /// Creates a worksheet from its rows, the last one holding the operators.
+    pub fn new(rows: Vec<String>) -> Worksheet {
+        Worksheet { rows }
+    }
+
+    /// Parses one row per line, keeping all spaces.
+    pub fn parse(input: &str) -> Worksheet {
+        Worksheet::new(input.lines().map(str::to_string).collect())
+    }
+
+    /// Returns all rows, including the operator row.
+    pub fn rows(&self) -> &[String] {
+        &self.rows
+    }
+
+    /// Returns the rows of numbers above the operator row.
+    pub fn number_rows(&self) -> &[String] {
+        self.rows.split_last().map_or(&[], |(_, numbers)| numbers)
+    }
+
+    /// Returns the row of `'+'` and `'*'` operators, or `None` for an empty worksheet.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::day06::{Day06, Worksheet};
+    /// use aoc2025::solver::Solver;
+    ///
+    /// let worksheet = Worksheet::parse(Day06::METADATA.example);
+    /// assert_eq!(worksheet.operators(), Some("*   +   *   +  "));
+    /// assert_eq!(worksheet.number_rows().len(), 3);
+    /// ```
+    pub fn operators(&self) -> Option<&str> {
+        self.rows.last().map(String::as_str)
+    }
+}
+
+/// The solution of day 6: evaluating the problems of a math worksheet.
+pub struct Day06;
+
+impl Solver for Day06 {
+    type Parsed = Worksheet;
+
+    const METADATA: Metadata = Metadata {
+        title: "Trash Compactor",
+        example: "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ",
+        example_answers: [Some("4277556"), Some("3263827")],
+        complexity: "A single pass over the worksheet: O(input size).",
+    };
+
+    fn parse(input: &str) -> Result<Worksheet, Diagnostic> {
+        Ok(Worksheet::parse(input))
+    }
+
+    fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+        validate(input)
+    }
+
+    fn part1(worksheet: &Worksheet) -> Answer {
+        part1::solve(worksheet)
+    }
+}
+
+impl Part2 for Day06 {
+    fn part2(worksheet: &Worksheet) -> Answer {
+        part2::solve(worksheet)
+    }
+}
+
+crate::register_day!(
+    year: 2025,
+    day: 6,
+    solver: Day06,
+    parts: [1, 2],
+    generator: crate::generators::day06,
+);
+
+/// Validates that the input consists of rows of numbers followed by a row of operators.
+///
+/// Every row must have as many columns as the operator row (needed by part 1)
+/// and be at least as long (needed by part 2, which slices the rows at the
+/// positions of the operators).
+///
+/// Well-formed worksheets are also solved with checked arithmetic, so a
+/// problem whose result overflows `i64` in either part is reported at its
+/// operator instead of panicking later.
+pub fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+    let lines: Vec<&str> = input.lines().collect();
+    let Some((operators, rows)) = lines.split_last() else {
+        return Err(vec![Diagnostic::input("input is empty")]);
+    };
+
+    let mut diagnostics: Vec<Diagnostic> = Vec::new();
+    let columns: usize = operators.split(' ').filter(|x| !x.is_empty()).count();
+    if let Some(index) = operators
+        .bytes()
+        .position(|byte| byte != b' ' && byte != b'+' && byte != b'*')
+    {
+        let operator = operators[index..].split(' ').next().unwrap_or_default();
+        diagnostics.push(Diagnostic::at_column(
+            lines.len(),
+            index + 1,
+            format!("expected only '+' and '*' operators, found '{}'", operator),
+        ));
+    }
+    if rows.is_empty() {
+        diagnostics.push(Diagnostic::input("expected at least one row of numbers"));
+    }
+
+    for (index, row) in rows.iter().enumerate() {
+        let numbers = row.split(' ').filter(|x| !x.is_empty()).count();
+        let (column, message) =
+            if let Some(position) = row.bytes().position(|b| b != b' ' && !b.is_ascii_digit()) {
+                (
+                    position + 1,
+                    format!(
+                        "expected only digits and spaces, found '{}'",
+                        row.as_bytes()[position].escape_ascii()
+                    ),
+                )
+            } else if numbers != columns {
+                (
+                    1,
+                    format!("expected {} numbers, found {}", columns, numbers),
+                )
+            } else if row.len() < operators.len() {
+                (
+                    row.len() + 1,
+                    format!(
+                        "expected at least {} characters like the operator row, found {}",
+                        operators.len(),
+                        row.len()
+                    ),
+                )
+            } else {
+                continue;
+            };
+        diagnostics.push(Diagnostic::at_column(index + 1, column, message));
+    }
+    if !diagnostics.is_empty() {
+        return Err(diagnostics);
+    }
+
+    let worksheet = Worksheet::parse(input);
+    let totals = [
+        part1::checked_total::<i64>(&worksheet),
+        part2::checked_total::<i64>(&worksheet),
+    ];
+    for (part, total) in (1..).zip(totals) {
+        let Err(overflow) = total else {
+            continue;
+        };
+        diagnostics.push(match overflow {
+            Overflow::Problem(problem) => {
+                let column = operators
+                    .bytes()
+                    .enumerate()
+                    .filter(|&(_, byte)| byte != b' ')
+                    .nth(problem)
+                    .map_or(1, |(index, _)| index + 1);
+                Diagnostic::at_column(lines.len(), column, format!("part {}: {}", part, overflow))
+            }
+            Overflow::Total => Diagnostic::input(format!("part {}: {}", part, overflow)),
+        });
+    }
+    into_result(diagnostics)
+}
+
+/// Where the arithmetic of a part overflows `i64` on a worksheet.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Overflow {
+    /// The result of the problem with this index, counted from the left.
+    Problem(usize),
+    /// The sum of the results of all problems.
+    Total,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Overflow::Problem(problem) => {
+                write!(f, "the result of problem {} overflows i64", problem + 1)
+            }
+            Overflow::Total => write!(f, "the sum of all problems overflows i64"),
+        }
+    }
+}
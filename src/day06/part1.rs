@@ -1,42 +1,80 @@
+use super::{Overflow, Worksheet};
+
+use crate::integer::{Integer, parse_decimal};
+use crate::solver::Answer;
+
 /// Solves the puzzle by processing all columns and summing their results.
 ///
-/// The function splits the input into columns using `extract_columns`, then
+/// The function splits the rows into columns using `extract_columns`, then
 /// runs `perform_calculation` on each column. All results are summed and
-/// returned as a string.
+/// returned as an [`Answer`].
 ///
 /// # Arguments
-/// * `input` – The raw puzzle input.
+/// * `worksheet` – The worksheet parsed by [`Day06`](super::Day06).
 ///
 /// # Returns
-/// A string containing the final sum of all column computations.
-pub fn solve(input: &str) -> String {
-    let mut result: i64 = 0;
+/// An [`Answer`] holding the final sum of all column computations.
+///
+/// # Panics
+/// If a problem or the sum overflows `i64`, which [`validate`](super::validate) rejects.
+pub fn solve(worksheet: &Worksheet) -> Answer {
+    checked_total::<i64>(worksheet)
+        .unwrap_or_else(|overflow| panic!("{}", overflow))
+        .into()
+}
 
-    for column in extract_columns(input) {
-        result += perform_calculation(column);
+/// Sums the results of all problems in any [`Integer`] type, e.g. `i128`
+/// for custom worksheets whose results overflow `i64`.
+///
+/// # Returns
+/// The sum, or `None` if a problem or the sum overflows `N`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day06::{Worksheet, part1::total};
+///
+/// let worksheet = Worksheet::parse("9999999999 2\n9999999999 3\n*          +");
+/// assert_eq!(total::<i64>(&worksheet), None);
+/// assert_eq!(total::<i128>(&worksheet), Some(99999999980000000006));
+/// ```
+pub fn total<N: Integer>(worksheet: &Worksheet) -> Option<N> {
+    checked_total(worksheet).ok()
+}
+
+/// Sums the results of all problems, reading the numbers along the rows.
+///
+/// # Returns
+/// The sum, or where the arithmetic overflows `N`.
+pub(super) fn checked_total<N: Integer>(worksheet: &Worksheet) -> Result<N, Overflow> {
+    let mut result: N = N::zero();
+
+    for (index, column) in extract_columns(worksheet.rows()).into_iter().enumerate() {
+        let value: N = perform_calculation(column).ok_or(Overflow::Problem(index))?;
+        result = result.checked_add(&value).ok_or(Overflow::Total)?;
     }
 
-    result.to_string()
+    Ok(result)
 }
 
-/// Extracts columns from a whitespace-separated, row-oriented input string.
+/// Extracts columns from whitespace-separated rows.
 ///
-/// Each line in the input represents one row of values. The function splits all
-/// lines by spaces, normalizes multiple spaces, and collects each field into a
+/// Each row contains one value per column. The function splits all rows by
+/// spaces, normalizes multiple spaces, and collects each field into a
 /// temporary row matrix.  
 /// Afterwards the matrix is transposed so that the returned vector contains
 /// columns instead of rows.
 ///
 /// # Arguments
-/// * `input` – The raw puzzle input containing multiple rows of values.
+/// * `rows` – The worksheet rows, the last one holding the operators.
 ///
 /// # Returns
 /// A vector where each element is a column represented as a `Vec<String>`.
-fn extract_columns(input: &str) -> Vec<Vec<String>> {
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn extract_columns(rows: &[String]) -> Vec<Vec<String>> {
     let mut columns: Vec<Vec<String>> = Vec::new();
 
     let mut tmp_read: Vec<Vec<String>> = Vec::new();
-    for line in input.lines() {
+    for line in rows {
         tmp_read.push(
             line.split(" ")
                 .filter(|x| !x.is_empty())
@@ -45,7 +83,7 @@ fn extract_columns(input: &str) -> Vec<Vec<String>> {
         );
     }
 
-    for _ in 0..tmp_read.first().unwrap().len() {
+    for _ in 0..tmp_read.first().map_or(0, Vec::len) {
         columns.push(Vec::new());
     }
 
@@ -61,7 +99,7 @@ fn extract_columns(input: &str) -> Vec<Vec<String>> {
 /// Performs a calculation over a column of values.
 ///
 /// The column contains numbers followed by a trailing operator (`*` or `+`).  
-/// All values except the last element are parsed as `i64`.  
+/// All values except the last element are parsed as `N`.  
 /// If the final element is `"*"`, all numbers are multiplied.  
 /// If the final element is `"+"`, all numbers are added.
 ///
@@ -69,34 +107,34 @@ fn extract_columns(input: &str) -> Vec<Vec<String>> {
 /// * `column` – A vector of strings where the last element is the operator.
 ///
 /// # Returns
-/// The computed result as `i64`.
-///
-/// # Panics
-/// * If any number cannot be parsed.
-/// * If the column is empty.
-fn perform_calculation(column: Vec<String>) -> i64 {
-    let multiply: bool = column.last().unwrap() == "*";
-    let mut result: i64 = column[0].parse().unwrap();
-    for number in column[1..(column.len() - 1)].iter() {
-        let parsed: i64 = number.parse().unwrap();
-        if multiply {
-            result *= parsed;
+/// The computed result as `N`, or `None` if the column has no numbers,
+/// a number cannot be parsed, or the result overflows `N`.
+fn perform_calculation<N: Integer>(column: Vec<String>) -> Option<N> {
+    let (operator, numbers) = column.split_last()?;
+    let multiply: bool = operator == "*";
+    let (first, rest) = numbers.split_first()?;
+    let mut result: N = parse_decimal(first)?;
+    for number in rest {
+        let parsed: N = parse_decimal(number)?;
+        result = if multiply {
+            result.checked_mul(&parsed)?
         } else {
-            result += parsed;
-        }
+            result.checked_add(&parsed)?
+        };
     }
 
-    result
+    Some(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day06::Day06;
+    use crate::solver::Solver;
 
     #[test]
     fn test_extract_columns() {
-        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
-        let result = extract_columns(input);
+        let result = extract_columns(Day06::parse(Day06::METADATA.example).unwrap().rows());
         assert_eq!(
             result,
             vec![
@@ -136,8 +174,8 @@ mod tests {
             "6".to_string(),
             "*".to_string(),
         ];
-        let result = perform_calculation(input);
-        assert_eq!(result, 33210);
+        let result = perform_calculation::<i64>(input);
+        assert_eq!(result, Some(33210));
     }
 
     #[test]
@@ -148,8 +186,8 @@ mod tests {
             "98".to_string(),
             "+".to_string(),
         ];
-        let result = perform_calculation(input);
-        assert_eq!(result, 490);
+        let result = perform_calculation::<i64>(input);
+        assert_eq!(result, Some(490));
     }
 
     #[test]
@@ -160,8 +198,8 @@ mod tests {
             "215".to_string(),
             "*".to_string(),
         ];
-        let result = perform_calculation(input);
-        assert_eq!(result, 4243455);
+        let result = perform_calculation::<i64>(input);
+        assert_eq!(result, Some(4243455));
     }
 
     #[test]
@@ -172,14 +210,38 @@ mod tests {
             "314".to_string(),
             "+".to_string(),
         ];
-        let result = perform_calculation(input);
-        assert_eq!(result, 401);
+        let result = perform_calculation::<i64>(input);
+        assert_eq!(result, Some(401));
+    }
+
+    #[test]
+    fn test_perform_calculation_overflow() {
+        let input = vec![
+            "9999999999".to_string(),
+            "9999999999".to_string(),
+            "*".to_string(),
+        ];
+        assert_eq!(perform_calculation::<i64>(input.clone()), None);
+        assert_eq!(
+            perform_calculation::<i128>(input),
+            Some(99999999980000000001)
+        );
+    }
+
+    #[test]
+    fn test_checked_total_overflow() {
+        let worksheet = Day06::parse("9999999999 1\n9999999999 2\n*          +").unwrap();
+        assert_eq!(checked_total::<i64>(&worksheet), Err(Overflow::Problem(0)));
+        let worksheet = Day06::parse("9223372036854775807 1\n+                   +").unwrap();
+        assert_eq!(checked_total::<i64>(&worksheet), Err(Overflow::Total));
     }
 
     #[test]
     fn test_solve() {
-        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
-        let result = solve(input);
-        assert_eq!(result, "4277556");
+        let result = solve(&Day06::parse(Day06::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day06::METADATA.example_answer(1)
+        );
     }
 }
@@ -1,25 +1,53 @@
+use super::{Overflow, Worksheet};
+
+use crate::integer::Integer;
+use crate::solver::Answer;
+
 /// Solves the puzzle by processing all columns and summing their results.
 ///
-/// The function splits the input into columns using `extract_columns`, then
+/// The function splits the rows into columns using `extract_columns`, then
 /// runs `perform_calculation` on each column. All results are summed and
-/// returned as a string.
+/// returned as an [`Answer`].
 ///
 /// # Arguments
-/// * `input` – The raw puzzle input.
+/// * `worksheet` – The worksheet parsed by [`Day06`](super::Day06).
+///
+/// # Returns
+/// An [`Answer`] holding the final sum of all column computations.
+///
+/// # Panics
+/// If a problem or the sum overflows `i64`, which [`validate`](super::validate) rejects.
+pub fn solve(worksheet: &Worksheet) -> Answer {
+    checked_total::<i64>(worksheet)
+        .unwrap_or_else(|overflow| panic!("{}", overflow))
+        .into()
+}
+
+/// Sums the results of all problems in any [`Integer`] type, like
+/// [`part1::total`](super::part1::total).
+///
+/// # Returns
+/// The sum, or `None` if a problem or the sum overflows `N`.
+pub fn total<N: Integer>(worksheet: &Worksheet) -> Option<N> {
+    checked_total(worksheet).ok()
+}
+
+/// Sums the results of all problems, reading the numbers down the columns.
 ///
 /// # Returns
-/// A string containing the final sum of all column computations.
-pub fn solve(input: &str) -> String {
-    let mut result: i64 = 0;
+/// The sum, or where the arithmetic overflows `N`.
+pub(super) fn checked_total<N: Integer>(worksheet: &Worksheet) -> Result<N, Overflow> {
+    let mut result: N = N::zero();
 
-    for column in extract_columns(input) {
-        result += perform_calculation(column);
+    for (index, column) in extract_columns(worksheet.rows()).into_iter().enumerate() {
+        let value: N = perform_calculation(column).ok_or(Overflow::Problem(index))?;
+        result = result.checked_add(&value).ok_or(Overflow::Total)?;
     }
 
-    result.to_string()
+    Ok(result)
 }
 
-/// Extracts vertical columns from the given multiline input.
+/// Extracts vertical columns from the given worksheet rows.
 /// 
 /// Columns are detected by scanning the last line for non-space
 /// characters, which mark the starting indices. Each column
@@ -27,20 +55,24 @@ pub fn solve(input: &str) -> String {
 /// detected column boundaries.
 ///
 /// # Arguments
-/// * `input` - The raw multiline input string.
+/// * `rows` - The worksheet rows, the last one holding the operators.
 ///
 /// # Returns
 /// A vector of columns, where each column is a vector of strings.
 /// Each inner string represents the slice of one line belonging
 /// to that column.
-fn extract_columns(input: &str) -> Vec<Vec<String>> {
+#[tracing::instrument(name = "parse", level = "debug", skip_all)]
+fn extract_columns(rows: &[String]) -> Vec<Vec<String>> {
     let mut columns: Vec<Vec<String>> = Vec::new();
 
-    let lines: Vec<&str> = input.lines().collect();
+    let lines: Vec<&str> = rows.iter().map(String::as_str).collect();
+    let Some(operators) = lines.last() else {
+        return columns;
+    };
 
     let mut collum_start_indicies: Vec<usize> = Vec::new();
-    for i in 0..lines.last().unwrap().len() {
-        if lines.last().unwrap()[i..=i] != " ".to_string() {
+    for (i, byte) in operators.bytes().enumerate() {
+        if byte != b' ' {
             collum_start_indicies.push(i);
         }
     }
@@ -49,12 +81,11 @@ fn extract_columns(input: &str) -> Vec<Vec<String>> {
         let mut column: Vec<String> = Vec::new();
 
         let start: usize = collum_start_indicies[i];
-        let end: usize;
-        if i == collum_start_indicies.len() - 1 {
-            end = lines.last().unwrap().len();
+        let end: usize = if i == collum_start_indicies.len() - 1 {
+            operators.len()
         } else {
-            end = collum_start_indicies[i + 1] - 1;
-        }
+            collum_start_indicies[i + 1] - 1
+        };
 
         for line in &lines {
             column.push(line[start..end].to_string());
@@ -70,48 +101,58 @@ fn extract_columns(input: &str) -> Vec<Vec<String>> {
 /// 
 /// The last row determines whether the operation is addition (`+`) or
 /// multiplication (`*`). All other rows contain digits aligned
-/// vertically, which are combined into full numbers per column
-/// before applying the operation.
+/// vertically, which are accumulated numerically per character column
+/// (`value * 10 + digit`) before applying the operation.
 ///
 /// # Arguments
 /// * `column` - A vector of strings representing one extracted column.
 ///
 /// # Returns
-/// The evaluated result as `i64`.
-fn perform_calculation(column: Vec<String>) -> i64 {
-    let multiply: bool = column.last().unwrap().trim() == "*";
+/// The evaluated result as `N`, or `None` if a row contains anything
+/// other than digits and spaces or a number or the result overflows `N`.
+fn perform_calculation<N: Integer>(column: Vec<String>) -> Option<N> {
+    let (operator, rows) = column.split_last()?;
+    let multiply: bool = operator.trim() == "*";
 
-    let mut numbers: Vec<String> = Vec::new();
-    for _ in 0..column.first().unwrap().len() {
-        numbers.push("".to_string());
-    }
-    for line in column[0..(column.len() - 1)].iter() {
-        for i in 0..line.len() {
-            numbers[i] = numbers[i].to_owned() + &line[i..=i];
+    let ten: N = N::from_digit(10);
+    let mut numbers: Vec<N> = vec![N::zero(); operator.len()];
+    for line in rows {
+        for (i, byte) in line.bytes().enumerate() {
+            if byte == b' ' {
+                continue;
+            }
+            if !byte.is_ascii_digit() {
+                return None;
+            }
+            let number: &mut N = numbers.get_mut(i)?;
+            *number = number
+                .checked_mul(&ten)?
+                .checked_add(&N::from_digit(byte - b'0'))?;
         }
     }
 
-    let mut result: i64 = numbers[0].trim().parse().unwrap();
-    for number in numbers[1..].iter() {
-        let parsed: i64 = number.trim().parse().unwrap();
-        if multiply {
-            result *= parsed;
+    let (first, rest) = numbers.split_first()?;
+    let mut result: N = first.clone();
+    for number in rest {
+        result = if multiply {
+            result.checked_mul(number)?
         } else {
-            result += parsed;
-        }
+            result.checked_add(number)?
+        };
     }
 
-    result
+    Some(result)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::day06::Day06;
+    use crate::solver::Solver;
 
     #[test]
     fn test_extract_columns() {
-        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
-        let result = extract_columns(input);
+        let result = extract_columns(Day06::parse(Day06::METADATA.example).unwrap().rows());
         assert_eq!(
             result,
             vec![
@@ -151,8 +192,8 @@ mod tests {
             "  6".to_string(),
             "*  ".to_string(),
         ];
-        let result = perform_calculation(input);
-        assert_eq!(result, 8544);
+        let result = perform_calculation::<i64>(input);
+        assert_eq!(result, Some(8544));
     }
 
     #[test]
@@ -163,8 +204,8 @@ mod tests {
             "98 ".to_string(),
             "+  ".to_string(),
         ];
-        let result = perform_calculation(input);
-        assert_eq!(result, 625);
+        let result = perform_calculation::<i64>(input);
+        assert_eq!(result, Some(625));
     }
 
     #[test]
@@ -175,8 +216,8 @@ mod tests {
             "215".to_string(),
             "*  ".to_string(),
         ];
-        let result = perform_calculation(input);
-        assert_eq!(result, 3253600);
+        let result = perform_calculation::<i64>(input);
+        assert_eq!(result, Some(3253600));
     }
 
     #[test]
@@ -187,14 +228,30 @@ mod tests {
             "314".to_string(),
             "+  ".to_string(),
         ];
-        let result = perform_calculation(input);
-        assert_eq!(result, 1058);
+        let result = perform_calculation::<i64>(input);
+        assert_eq!(result, Some(1058));
+    }
+
+    #[test]
+    fn test_perform_calculation_overflow() {
+        let input = vec![
+            "9999999999".to_string(),
+            "9999999999".to_string(),
+            "*         ".to_string(),
+        ];
+        assert_eq!(perform_calculation::<i64>(input.clone()), None);
+        assert_eq!(
+            perform_calculation::<i128>(input),
+            Some(90438207500880449001)
+        );
     }
 
     #[test]
     fn test_solve() {
-        let input = "123 328  51 64 \n 45 64  387 23 \n  6 98  215 314\n*   +   *   +  ";
-        let result = solve(input);
-        assert_eq!(result, "3263827");
+        let result = solve(&Day06::parse(Day06::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day06::METADATA.example_answer(2)
+        );
     }
 }
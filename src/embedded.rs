@@ -0,0 +1,48 @@
+/// The inputs compiled into the crate as `(file name, contents)`, sorted by
+/// name, generated by `build.rs` from `inputs/2025` and `inputs`. Empty
+/// without the `embed-inputs` feature.
+static INPUTS: &[(&str, &str)] = include!(concat!(env!("OUT_DIR"), "/embedded_inputs.rs"));
+
+/// Returns the embedded input file with the given name, e.g. `"day03.txt"`.
+pub fn file(name: &str) -> Option<&'static str> {
+    INPUTS
+        .iter()
+        .find(|(embedded, _)| *embedded == name)
+        .map(|(_, input)| *input)
+}
+
+/// Returns the embedded input of a puzzle together with its file name.
+///
+/// Like on disk, `"day{day:02}_part{part}.txt"` is preferred over `"day{day:02}.txt"`.
+pub fn input(day: i32, part: i32) -> Option<(&'static str, &'static str)> {
+    [
+        format!("day{:02}_part{}.txt", day, part),
+        format!("day{:02}.txt", day),
+    ]
+    .iter()
+    .find_map(|name| {
+        INPUTS
+            .iter()
+            .find(|(embedded, _)| embedded == name)
+            .copied()
+    })
+}
+
+/// Returns the names of all embedded input files, sorted.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    INPUTS.iter().map(|(name, _)| *name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup_matches_table() {
+        for name in names() {
+            assert!(file(name).is_some());
+        }
+        assert_eq!(file("day99.txt"), None);
+        assert_eq!(input(99, 1), None);
+    }
+}
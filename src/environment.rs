@@ -0,0 +1,131 @@
+use std::fmt;
+use std::fs;
+use std::process::Command;
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+/// The build and the machine puzzles are run on, see [`Environment::current`].
+///
+/// Timings are only comparable between runs of the same build settings on
+/// the same machine, so reports carry this along (see
+/// [`RunReport::environment`](crate::utils::RunReport::environment)).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Environment {
+    /// The compiler the crate was built with, e.g. `"rustc 1.91.0 (f8297e351 2025-10-28)"`.
+    pub rustc: String,
+    /// The Cargo profile of the build, `"debug"` or `"release"`.
+    pub profile: String,
+    /// The optimization level of the build, e.g. `"0"` or `"3"`.
+    pub opt_level: String,
+    /// Whether debug assertions, including overflow checks, are enabled.
+    pub debug_assertions: bool,
+    /// The abbreviated hash of the checked-out git commit, if known.
+    pub git_commit: Option<String>,
+    /// The model name of the CPU, if known, e.g. `"AMD Ryzen 7 7840U"`.
+    pub cpu: Option<String>,
+}
+
+impl Environment {
+    /// Returns the environment of this process, determined on first use.
+    ///
+    /// The compiler and build settings are recorded by the build script; the
+    /// git commit and the CPU model are looked up at run time.
+    pub fn current() -> &'static Environment {
+        static CURRENT: OnceLock<Environment> = OnceLock::new();
+        CURRENT.get_or_init(|| Environment {
+            rustc: env!("AOC_RUSTC_VERSION").to_string(),
+            profile: env!("AOC_PROFILE").to_string(),
+            opt_level: env!("AOC_OPT_LEVEL").to_string(),
+            debug_assertions: cfg!(debug_assertions),
+            git_commit: git_commit(),
+            cpu: cpu_model(),
+        })
+    }
+}
+
+impl fmt::Display for Environment {
+    /// Formats the environment as one line, e.g. `"release (opt-level 3),
+    /// rustc 1.91.0 (f8297e351 2025-10-28), commit 3f2a9c1"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (opt-level {}", self.profile, self.opt_level)?;
+        if self.debug_assertions {
+            write!(f, ", debug assertions")?;
+        }
+        write!(f, "), {}", self.rustc)?;
+        if let Some(commit) = &self.git_commit {
+            write!(f, ", commit {}", commit)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns the abbreviated hash of the commit checked out in the working
+/// directory, or `None` outside of a git repository or without git.
+pub fn git_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "--short", "HEAD"])
+        .output()
+        .ok()?;
+    let commit = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !commit.is_empty()).then_some(commit)
+}
+
+/// Returns the model name of the CPU from `/proc/cpuinfo` on Linux or
+/// `sysctl` on macOS, or `None` elsewhere.
+fn cpu_model() -> Option<String> {
+    if let Ok(cpuinfo) = fs::read_to_string("/proc/cpuinfo") {
+        return parse_cpuinfo(&cpuinfo);
+    }
+    let output = Command::new("sysctl")
+        .args(["-n", "machdep.cpu.brand_string"])
+        .output()
+        .ok()?;
+    let model = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (output.status.success() && !model.is_empty()).then_some(model)
+}
+
+/// Returns the first `model name` of a `/proc/cpuinfo` listing.
+fn parse_cpuinfo(cpuinfo: &str) -> Option<String> {
+    cpuinfo.lines().find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        (key.trim() == "model name").then(|| value.trim().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current() {
+        let environment = Environment::current();
+        assert!(environment.rustc.starts_with("rustc "));
+        assert!(["debug", "release"].contains(&environment.profile.as_str()));
+        assert_eq!(environment.debug_assertions, cfg!(debug_assertions));
+    }
+
+    #[test]
+    fn test_parse_cpuinfo() {
+        let cpuinfo = "processor\t: 0\nvendor_id\t: AuthenticAMD\nmodel name\t: AMD Ryzen 7 7840U\n\n\
+                       processor\t: 1\nmodel name\t: AMD Ryzen 7 7840U\n";
+        assert_eq!(parse_cpuinfo(cpuinfo).as_deref(), Some("AMD Ryzen 7 7840U"));
+        assert_eq!(parse_cpuinfo("processor\t: 0\n"), None);
+    }
+
+    #[test]
+    fn test_display() {
+        let environment = Environment {
+            rustc: "rustc 1.91.0".to_string(),
+            profile: "release".to_string(),
+            opt_level: "3".to_string(),
+            debug_assertions: false,
+            git_commit: Some("3f2a9c1".to_string()),
+            cpu: None,
+        };
+        assert_eq!(
+            environment.to_string(),
+            "release (opt-level 3), rustc 1.91.0, commit 3f2a9c1"
+        );
+    }
+}
@@ -0,0 +1,174 @@
+use std::fmt;
+use std::io;
+use std::time::Duration;
+
+use crate::check::Diagnostic;
+
+/// The maximum number of diagnostics listed by [`AocError::Parse`].
+const MAX_REPORTED: usize = 5;
+
+/// The error of running a puzzle, e.g. with [`run_puzzle`](crate::utils::run_puzzle).
+///
+/// It converts into an [`io::Error`] of a matching kind, so functions
+/// returning `io::Result` can still use `?` on it.
+#[derive(Debug)]
+pub enum AocError {
+    /// No input file exists for the puzzle.
+    InputNotFound {
+        /// Every path that was looked at, in the order of preference.
+        tried: Vec<String>,
+        /// Why downloading the missing input failed, if it was attempted.
+        download: Option<String>,
+    },
+    /// Reading the input or writing the report failed.
+    Io(io::Error),
+    /// The input of a day is malformed, found by its validator before solving.
+    Parse {
+        /// The day of the puzzle.
+        day: i32,
+        /// The path of the input.
+        path: String,
+        /// Every problem found, in input order, with its line and column.
+        diagnostics: Vec<Diagnostic>,
+    },
+    /// A solver exceeded its time budget, see [`RunReport::into_result`](crate::utils::RunReport::into_result).
+    Timeout {
+        /// The day of the puzzle.
+        day: i32,
+        /// The part of the puzzle.
+        part: i32,
+        /// The time budget the solver exceeded.
+        budget: Duration,
+    },
+    /// Downloading an input given as URL failed.
+    Network {
+        /// The URL of the input.
+        url: String,
+        /// What went wrong, e.g. `"HTTP 404"`.
+        message: String,
+    },
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::InputNotFound { tried, download } => {
+                let tried: Vec<String> = tried.iter().map(|path| format!("'{}'", path)).collect();
+                write!(f, "Input file not found: tried {}", tried.join(", "))?;
+                if let Some(download) = download {
+                    write!(f, "; downloading it failed: {}", download)?;
+                }
+                Ok(())
+            }
+            AocError::Io(err) => write!(f, "{}", err),
+            AocError::Parse {
+                day,
+                path,
+                diagnostics,
+            } => {
+                write!(f, "Input '{}' of day {} is malformed:", path, day)?;
+                for diagnostic in diagnostics.iter().take(MAX_REPORTED) {
+                    write!(
+                        f,
+                        "\n  {}{}: {}",
+                        path,
+                        diagnostic.location(),
+                        diagnostic.message
+                    )?;
+                }
+                if diagnostics.len() > MAX_REPORTED {
+                    write!(
+                        f,
+                        "\n  ... and {} more, see `aoc check-input --day {}`",
+                        diagnostics.len() - MAX_REPORTED,
+                        day
+                    )?;
+                }
+                write!(f, "\n(use --no-validate to run the solver anyway)")
+            }
+            AocError::Timeout { day, part, budget } => write!(
+                f,
+                "Day {} part {} did not finish within {:?}",
+                day, part, budget
+            ),
+            AocError::Network { url, message } => {
+                write!(f, "Could not download '{}': {}", url, message)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AocError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AocError::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(err: io::Error) -> AocError {
+        AocError::Io(err)
+    }
+}
+
+impl From<AocError> for io::Error {
+    fn from(err: AocError) -> io::Error {
+        let kind = match err {
+            AocError::Io(err) => return err,
+            AocError::InputNotFound { .. } => io::ErrorKind::NotFound,
+            AocError::Parse { .. } => io::ErrorKind::InvalidData,
+            AocError::Timeout { .. } => io::ErrorKind::TimedOut,
+            AocError::Network { .. } => io::ErrorKind::Other,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = AocError::InputNotFound {
+            tried: vec!["a.txt".to_string(), "a.txt.gz".to_string()],
+            download: Some("no session token".to_string()),
+        };
+        assert_eq!(
+            err.to_string(),
+            "Input file not found: tried 'a.txt', 'a.txt.gz'; downloading it failed: no session token"
+        );
+
+        let err = AocError::Parse {
+            day: 1,
+            path: "in.txt".to_string(),
+            diagnostics: (1..=7).map(|line| Diagnostic::at(line, "bad")).collect(),
+        };
+        let message = err.to_string();
+        assert!(message.starts_with("Input 'in.txt' of day 1 is malformed:\n  in.txt:1: bad\n"));
+        assert!(message.contains("... and 2 more, see `aoc check-input --day 1`"));
+
+        let err = AocError::Timeout {
+            day: 4,
+            part: 2,
+            budget: Duration::from_secs(3),
+        };
+        assert_eq!(err.to_string(), "Day 4 part 2 did not finish within 3s");
+    }
+
+    #[test]
+    fn test_into_io_error() {
+        let err = io::Error::from(AocError::InputNotFound {
+            tried: Vec::new(),
+            download: None,
+        });
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        let inner = io::Error::new(io::ErrorKind::PermissionDenied, "denied");
+        let err = io::Error::from(AocError::from(inner));
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+        assert_eq!(err.to_string(), "denied");
+    }
+}
@@ -0,0 +1,301 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::aoc_client::{BASE_URL, CachePolicy, Client};
+use crate::config::Config;
+use crate::history::format_timestamp;
+use crate::utils::hash_input;
+
+/// The file downloaded inputs are recorded in, see [`Download`].
+pub const DOWNLOADS_PATH: &str = ".aoc/downloads.json";
+
+/// Returns the URL of the personal puzzle input of a day.
+///
+/// # Examples
+/// ```
+/// use aoc2025::fetch::input_url;
+///
+/// assert_eq!(input_url(2025, 3), "https://adventofcode.com/2025/day/3/input");
+/// ```
+pub fn input_url(year: i32, day: i32) -> String {
+    format!("{}{}", BASE_URL, input_url_path(year, day))
+}
+
+/// Returns the path of the input of a day below [`BASE_URL`].
+fn input_url_path(year: i32, day: i32) -> String {
+    format!("/{}/day/{}/input", year, day)
+}
+
+/// Returns the file a downloaded input of a day is stored in, e.g. `inputs/2025/day03.txt`.
+pub fn input_path(config: &Config, day: i32) -> String {
+    format!("{}/day{:02}.txt", config.year_input_dir(), day)
+}
+
+/// How downloading an input changed the stored file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FetchOutcome {
+    /// There was no file before.
+    Created,
+    /// The file already had the downloaded contents.
+    Unchanged,
+    /// The file had different contents, which were replaced.
+    Replaced,
+}
+
+impl FetchOutcome {
+    /// Determines the outcome of storing `downloaded` over the `existing` contents of a file.
+    fn of(existing: Option<&str>, downloaded: &str) -> FetchOutcome {
+        match existing {
+            None => FetchOutcome::Created,
+            Some(existing) if existing == downloaded => FetchOutcome::Unchanged,
+            Some(_) => FetchOutcome::Replaced,
+        }
+    }
+}
+
+/// A downloaded input, recorded to detect stale or modified input files later.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Download {
+    /// The file the input was stored in.
+    pub path: String,
+    /// The event year the input belongs to.
+    pub year: i32,
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The hash of the input as hexadecimal string, see [`hash_input`].
+    pub input_hash: String,
+    /// When the input was downloaded, in seconds since the Unix epoch.
+    pub timestamp: u64,
+}
+
+impl Download {
+    /// Explains why an input file no longer matches this download, if it does not.
+    ///
+    /// # Parameters
+    /// - `year`: The configured event year.
+    /// - `input`: The current contents of the file.
+    fn stale_reason(&self, year: i32, input: &str) -> Option<String> {
+        if self.year != year {
+            Some(format!(
+                "Input '{}' was downloaded for {}, but the configured year is {}",
+                self.path, self.year, year
+            ))
+        } else if self.input_hash != format!("{:016x}", hash_input(input)) {
+            Some(format!(
+                "Input '{}' changed since it was downloaded at {} UTC",
+                self.path,
+                format_timestamp(self.timestamp)
+            ))
+        } else {
+            None
+        }
+    }
+}
+
+/// Reads the recorded downloads from `path`. A missing file means no downloads.
+///
+/// # Returns
+/// The downloads, or an I/O error if the file cannot be read or parsed.
+pub fn load_downloads(path: &str) -> io::Result<Vec<Download>> {
+    match fs::read_to_string(path) {
+        Ok(content) => serde_json::from_str(&content).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("Invalid downloads file '{}': {}", path, err),
+            )
+        }),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(err) => Err(err),
+    }
+}
+
+/// Records a download in the file at `path`, replacing an earlier download to the same file.
+fn record_download(path: &str, download: Download) -> io::Result<()> {
+    let mut downloads = load_downloads(path)?;
+    downloads.retain(|recorded| recorded.path != download.path);
+    downloads.push(download);
+    downloads.sort_by(|a, b| (a.year, a.day, &a.path).cmp(&(b.year, b.day, &b.path)));
+
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(&downloads).map_err(io::Error::other)?;
+    fs::write(path, json + "\n")
+}
+
+/// Downloads the input of a day and stores it in the input directory.
+///
+/// The input is written to [`input_path`], replacing an existing file, and
+/// recorded in [`DOWNLOADS_PATH`] with its hash. Inputs never change, so a
+/// response cached by the [`Client`] is reused unless `refresh` is set.
+///
+/// # Parameters
+/// - `refresh`: Requests the input from the server even if it is cached.
+///
+/// # Returns
+/// The path of the stored input and how the file changed, or an error if no
+/// session token is available, the download failed, or the file could not be written.
+pub fn fetch_input(config: &Config, day: i32, refresh: bool) -> io::Result<(String, FetchOutcome)> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Day {} is not part of the event", day),
+        ));
+    }
+
+    let client = Client::from_config(config)?;
+    let cache = if refresh {
+        CachePolicy::Refresh
+    } else {
+        CachePolicy::Forever
+    };
+    let input = client
+        .get(&input_url_path(config.year, day), cache)
+        .map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "The input of {} day {} is not available yet",
+                    config.year, day
+                ),
+            ),
+            _ => err,
+        })?;
+
+    let path = input_path(config, day);
+    let existing = fs::read_to_string(&path).ok();
+    let outcome = FetchOutcome::of(existing.as_deref(), &input);
+    if outcome == FetchOutcome::Replaced {
+        warn!(
+            path,
+            "the stored input differed from the downloaded one and was replaced"
+        );
+    }
+    fs::create_dir_all(config.year_input_dir())?;
+    fs::write(&path, &input)?;
+    info!(path, "input downloaded");
+
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0);
+    record_download(
+        DOWNLOADS_PATH,
+        Download {
+            path: path.clone(),
+            year: config.year,
+            day,
+            input_hash: format!("{:016x}", hash_input(&input)),
+            timestamp,
+        },
+    )?;
+    Ok((path, outcome))
+}
+
+/// Warns if an input file no longer matches its recorded download.
+///
+/// Catches solving an input downloaded for another year, or one edited since
+/// the download. Inputs that were not downloaded are not checked.
+pub fn warn_if_stale(config: &Config, day: i32, path: &str, input: &str) {
+    let downloads = match load_downloads(DOWNLOADS_PATH) {
+        Ok(downloads) => downloads,
+        Err(err) => {
+            warn!("{}", err);
+            return;
+        }
+    };
+    let reason = downloads
+        .iter()
+        .find(|download| download.path == path)
+        .and_then(|download| download.stale_reason(config.year, input));
+    if let Some(reason) = reason {
+        warn!(
+            "{}; run `aoc fetch --day {} --refresh` to compare it with the server",
+            reason, day
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_input_path() {
+        let config = Config {
+            input_dir: "data".to_string(),
+            ..Config::default()
+        };
+        assert_eq!(input_path(&config, 7), "data/2025/day07.txt");
+        let config = Config {
+            year: 2024,
+            ..config
+        };
+        assert_eq!(input_path(&config, 7), "data/2024/day07.txt");
+    }
+
+    #[test]
+    fn test_fetch_invalid_day() {
+        let err = fetch_input(&Config::default(), 26, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_fetch_outcome() {
+        assert_eq!(FetchOutcome::of(None, "1"), FetchOutcome::Created);
+        assert_eq!(FetchOutcome::of(Some("1"), "1"), FetchOutcome::Unchanged);
+        assert_eq!(FetchOutcome::of(Some("2"), "1"), FetchOutcome::Replaced);
+    }
+
+    #[test]
+    fn test_stale_reason() {
+        let download = Download {
+            path: "inputs/day01.txt".to_string(),
+            year: 2025,
+            day: 1,
+            input_hash: format!("{:016x}", hash_input("L68")),
+            timestamp: 1764547200,
+        };
+        assert_eq!(download.stale_reason(2025, "L68"), None);
+        assert!(
+            download
+                .stale_reason(2024, "L68")
+                .unwrap()
+                .contains("for 2025")
+        );
+        assert!(
+            download
+                .stale_reason(2025, "L69")
+                .unwrap()
+                .contains("changed since it was downloaded at 2025-12-01 00:00:00")
+        );
+    }
+
+    #[test]
+    fn test_record_download() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025-downloads-{}.json", std::process::id()));
+        let path = path.to_str().unwrap();
+        let download = |day: i32, hash: &str| Download {
+            path: format!("inputs/day{:02}.txt", day),
+            year: 2025,
+            day,
+            input_hash: hash.to_string(),
+            timestamp: 0,
+        };
+
+        assert!(load_downloads(path).unwrap().is_empty());
+        record_download(path, download(2, "a")).unwrap();
+        record_download(path, download(1, "b")).unwrap();
+        record_download(path, download(2, "c")).unwrap();
+        let downloads = load_downloads(path).unwrap();
+        fs::remove_file(path).unwrap();
+
+        assert_eq!(downloads, vec![download(1, "b"), download(2, "c")]);
+    }
+}
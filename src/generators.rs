@@ -0,0 +1,211 @@
+/// Generates a large random input of a day for stress-testing its solvers.
+///
+/// Every generated input passes the validator of its day. It only depends on
+/// the size and the seed, so benchmarks can be repeated on the same input
+/// without storing it. See [`day01`] for the meaning of the parameters.
+pub type Generator = fn(usize, u64) -> String;
+
+/// The seed used unless another one is given.
+pub const DEFAULT_SEED: u64 = 2025;
+
+/// A small xorshift pseudo-random number generator.
+///
+/// The quality is plenty for test inputs, and unlike a dependency it is
+/// guaranteed to produce the same inputs on every platform and version.
+struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    /// Creates a generator whose sequence is determined by `seed`.
+    fn new(seed: u64) -> Rng {
+        // Scrambled so similar seeds yield unrelated sequences; xorshift
+        // must never start at zero.
+        let state = seed.wrapping_mul(0x9e37_79b9_7f4a_7c15) ^ 0xd1b5_4a32_d192_ed03;
+        Rng {
+            state: state.max(1),
+        }
+    }
+
+    /// Returns the next number of the sequence.
+    fn next(&mut self) -> u64 {
+        self.state ^= self.state << 13;
+        self.state ^= self.state >> 7;
+        self.state ^= self.state << 17;
+        self.state
+    }
+
+    /// Returns a number in `0..bound`.
+    fn below(&mut self, bound: u64) -> u64 {
+        self.next() % bound
+    }
+
+    /// Returns a number with exactly `digits` decimal digits.
+    fn digits(&mut self, digits: u32) -> u64 {
+        let low = 10u64.pow(digits - 1);
+        low + self.below(9 * low)
+    }
+}
+
+/// Generates `size` dial rotations like `"R48"`.
+///
+/// # Parameters
+/// - `size`: The scale of the input, described by every generator.
+/// - `seed`: Selects one of the possible inputs; the same seed always
+///   produces the same input.
+///
+/// # Examples
+/// ```
+/// use aoc2025::generators::day01;
+///
+/// let input = day01(1000, 7);
+/// assert_eq!(input.lines().count(), 1000);
+/// assert_eq!(input, day01(1000, 7));
+/// ```
+pub fn day01(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let rotations: Vec<String> = (0..size)
+        .map(|_| {
+            let direction = if rng.below(2) == 0 { 'L' } else { 'R' };
+            format!("{}{}", direction, 1 + rng.below(999))
+        })
+        .collect();
+    rotations.join("\n")
+}
+
+/// Generates `size` comma-separated ID ranges of up to 100 000 IDs each.
+///
+/// The IDs have between 2 and 10 digits, spread evenly, so every ID length
+/// is scanned.
+pub fn day02(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let digits = 2 + rng.below(9) as u32;
+            let start = rng.digits(digits);
+            format!("{}-{}", start, start + rng.below(100_000))
+        })
+        .collect();
+    ranges.join(",")
+}
+
+/// Generates `size` battery banks of 100 digits each.
+pub fn day03(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let banks: Vec<String> = (0..size)
+        .map(|_| {
+            (0..100)
+                .map(|_| char::from(b'1' + rng.below(9) as u8))
+                .collect()
+        })
+        .collect();
+    banks.join("\n")
+}
+
+/// Generates a square grid of `size` × `size` cells, about 60 % of them rolls.
+///
+/// The density of real inputs keeps most rolls removable only after several
+/// rounds, which is what the removal loop of part 2 is stressed with.
+pub fn day04(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let rows: Vec<String> = (0..size)
+        .map(|_| {
+            (0..size)
+                .map(|_| if rng.below(10) < 6 { '@' } else { '.' })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
+
+/// Generates `size` fresh ID ranges and `size` available IDs of up to 15 digits.
+pub fn day05(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let ranges: Vec<String> = (0..size)
+        .map(|_| {
+            let start = rng.below(1_000_000_000_000_000);
+            format!("{}-{}", start, start + rng.below(1_000_000_000_000))
+        })
+        .collect();
+    let ids: Vec<String> = (0..size)
+        .map(|_| rng.below(1_000_000_000_000_000).to_string())
+        .collect();
+    format!("{}\n\n{}", ranges.join("\n"), ids.join("\n"))
+}
+
+/// Generates a worksheet of `size` problems with four numbers each.
+///
+/// Like in real inputs, the numbers of a problem have up to four digits and
+/// are aligned either left or right within its column.
+///
+/// # Examples
+/// ```
+/// use aoc2025::generators::day06;
+///
+/// assert_eq!(day06(3, 1).lines().count(), 5);
+/// ```
+pub fn day06(size: usize, seed: u64) -> String {
+    let mut rng = Rng::new(seed);
+    let mut rows: Vec<String> = vec![String::new(); 5];
+    for problem in 0..size {
+        let width = 1 + rng.below(4) as usize;
+        let left = rng.below(2) == 0;
+        for (index, row) in rows.iter_mut().take(4).enumerate() {
+            // One number spans the whole column, so no digit column is blank.
+            let digits = if index == 0 {
+                width
+            } else {
+                1 + rng.below(width as u64) as usize
+            };
+            let number = rng.digits(digits as u32);
+            if left {
+                row.push_str(&format!("{:<width$}", number));
+            } else {
+                row.push_str(&format!("{:>width$}", number));
+            }
+        }
+        let operator = if rng.below(2) == 0 { '*' } else { '+' };
+        rows[4].push_str(&format!("{:<width$}", operator));
+        if problem + 1 < size {
+            for row in rows.iter_mut() {
+                row.push(' ');
+            }
+        }
+    }
+    rows.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry;
+
+    #[test]
+    fn test_generated_inputs_valid() {
+        for (day, _) in registry::days() {
+            let generate = registry::generator(day).unwrap();
+            let validate = registry::validator(day).unwrap();
+            for seed in 0..3 {
+                let input = generate(10, seed);
+                assert_eq!(validate(&input), Ok(()), "day {} with seed {}", day, seed);
+                for (_, solve) in registry::parts(day) {
+                    solve(&input);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_seeds_differ() {
+        assert_ne!(day04(20, 1), day04(20, 2));
+        assert_eq!(day04(20, 1), day04(20, 1));
+    }
+
+    #[test]
+    fn test_rng_digits() {
+        let mut rng = Rng::new(0);
+        for digits in 1..=10 {
+            assert_eq!(rng.digits(digits).to_string().len(), digits as usize);
+        }
+    }
+}
@@ -0,0 +1,191 @@
+use std::ops::{Index, IndexMut};
+
+/// A two-dimensional grid stored in a single flat, row-major allocation.
+///
+/// The cell at column `x` and row `y` lives at index `y * width + x`.
+/// Compared to `Vec<Vec<T>>` this avoids one allocation and one pointer
+/// indirection per row and keeps neighboring rows close in memory.
+///
+/// Indexing a grid with a row number returns that row as a slice, so code
+/// written against `Vec<Vec<T>>` can keep using `grid[y][x]`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid of the given size with every cell set to `fill`.
+    ///
+    /// # Arguments
+    /// * `width` – The number of columns.
+    /// * `height` – The number of rows.
+    /// * `fill` – The initial value of every cell.
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses a multiline string into a grid, mapping every character with `map`.
+    ///
+    /// The width of the grid is taken from the longest line. Shorter lines
+    /// are filled up with `fill`.
+    ///
+    /// # Arguments
+    /// * `input` – The raw multiline string to parse.
+    /// * `fill` – The value used for cells missing from short lines.
+    /// * `map` – Converts a single character into a cell value.
+    pub fn from_lines<F>(input: &str, fill: T, map: F) -> Grid<T>
+    where
+        F: Fn(char) -> T,
+    {
+        let lines: Vec<&str> = input.lines().collect();
+        let width: usize = lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0);
+
+        let mut grid: Grid<T> = Grid::new(width, lines.len(), fill);
+        for (y, line) in lines.iter().enumerate() {
+            for (x, c) in line.chars().enumerate() {
+                grid[y][x] = map(c);
+            }
+        }
+        grid
+    }
+
+    /// Returns a copy of the grid surrounded by a border of `fill` cells.
+    ///
+    /// The new grid is built in a single pass instead of inserting rows and
+    /// columns one by one, so the cost is linear in the size of the grid.
+    ///
+    /// # Arguments
+    /// * `border` – The thickness of the border on every side.
+    /// * `fill` – The value of the border cells.
+    pub fn padded(&self, border: usize, fill: T) -> Grid<T> {
+        let mut grid: Grid<T> = Grid::new(self.width + 2 * border, self.height + 2 * border, fill);
+        for y in 0..self.height {
+            grid[y + border][border..(border + self.width)].clone_from_slice(&self[y]);
+        }
+        grid
+    }
+}
+
+impl<T> Grid<T> {
+    /// Returns the number of columns.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns the number of rows.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the cell at column `x` and row `y`, or `None` if out of bounds.
+    pub fn get(&self, x: usize, y: usize) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the cell at column `x` and row `y`,
+    /// or `None` if out of bounds.
+    pub fn get_mut(&mut self, x: usize, y: usize) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            Some(&mut self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over all rows as slices, from top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| &self[y])
+    }
+
+    /// Returns all cells in row-major order.
+    pub fn cells(&self) -> &[T] {
+        &self.cells
+    }
+}
+
+impl<T> Index<usize> for Grid<T> {
+    type Output = [T];
+
+    /// Returns row `y` as a slice.
+    fn index(&self, y: usize) -> &[T] {
+        &self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+}
+
+impl<T> IndexMut<usize> for Grid<T> {
+    /// Returns row `y` as a mutable slice.
+    fn index_mut(&mut self, y: usize) -> &mut [T] {
+        &mut self.cells[(y * self.width)..((y + 1) * self.width)]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let grid = Grid::new(3, 2, 0);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid.cells(), &[0; 6]);
+    }
+
+    #[test]
+    fn test_from_lines() {
+        let grid = Grid::from_lines(".@\n@..", false, |c| c == '@');
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(&grid[0], &[false, true, false]);
+        assert_eq!(&grid[1], &[true, false, false]);
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let grid = Grid::new(2, 2, 1);
+        assert_eq!(grid.get(1, 1), Some(&1));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 2), None);
+    }
+
+    #[test]
+    fn test_index_mut() {
+        let mut grid = Grid::new(2, 2, 0);
+        grid[1][0] = 5;
+        *grid.get_mut(1, 0).unwrap() = 7;
+        assert_eq!(grid.cells(), &[0, 7, 5, 0]);
+    }
+
+    #[test]
+    fn test_padded() {
+        let grid = Grid::from_lines("12\n34", 0, |c| c.to_digit(10).unwrap());
+        let padded = grid.padded(1, 0);
+        assert_eq!(padded.width(), 4);
+        assert_eq!(padded.height(), 4);
+        assert_eq!(
+            padded.cells(),
+            &[0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4, 0, 0, 0, 0, 0]
+        );
+    }
+
+    #[test]
+    fn test_rows() {
+        let grid = Grid::from_lines("ab\ncd", ' ', |c| c);
+        let rows: Vec<&[char]> = grid.rows().collect();
+        assert_eq!(rows, vec![&['a', 'b'][..], &['c', 'd'][..]]);
+    }
+}
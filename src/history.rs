@@ -0,0 +1,310 @@
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+use crate::config;
+use crate::environment::Environment;
+use crate::utils::RunReport;
+
+/// The file runs are recorded in unless another path is given.
+pub const DEFAULT_HISTORY_PATH: &str = ".aoc/history.jsonl";
+
+/// The first line of a timings file, see [`append_timings`].
+const TIMINGS_HEADER: &str = "timestamp,commit,day,part,solve_ms";
+
+/// A single recorded run, stored as one JSON object per line.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    /// When the run happened, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The part number of the puzzle.
+    pub part: i32,
+    /// The answer returned by the solver.
+    pub answer: String,
+    /// The hash of the input as hexadecimal string, see [`hash_input`](crate::utils::hash_input).
+    pub input_hash: String,
+    /// How long reading the input took, in milliseconds.
+    pub input_ms: f64,
+    /// How long the solver took, in milliseconds.
+    pub solve_ms: f64,
+}
+
+impl HistoryEntry {
+    /// Creates the history entry of a run that happened at `timestamp`.
+    pub fn from_report(report: &RunReport, timestamp: u64) -> HistoryEntry {
+        HistoryEntry {
+            timestamp,
+            day: report.day,
+            part: report.part,
+            answer: report.answer.clone(),
+            input_hash: format!("{:016x}", report.input_hash),
+            input_ms: report.input_duration.as_secs_f64() * 1000.0,
+            solve_ms: report.solve_duration.as_secs_f64() * 1000.0,
+        }
+    }
+}
+
+/// Appends the reports to the history file at `path`.
+///
+/// The file and its parent directories are created if necessary. Timed out
+/// runs have no answer and are not recorded.
+///
+/// # Returns
+/// An I/O error if the file could not be written.
+pub fn append(path: &str, reports: &[RunReport]) -> io::Result<()> {
+    let timestamp = now();
+    let mut lines = String::new();
+    for report in reports.iter().filter(|report| report.finished()) {
+        let entry = HistoryEntry::from_report(report, timestamp);
+        lines.push_str(&serde_json::to_string(&entry).map_err(io::Error::other)?);
+        lines.push('\n');
+    }
+    append_lines(path, &lines)
+}
+
+/// Appends one CSV row per report to the timings file at `path`.
+///
+/// The rows hold the time of the run as `YYYY-MM-DD HH:MM:SS` in UTC (see
+/// [`format_timestamp`]), the git commit the solvers were run at, the day,
+/// the part and the solve time in milliseconds, e.g.
+/// `2025-12-04 06:12:09,3f2a9c1,4,2,12.345`. A new file starts with a header
+/// line. Like in [`append`], runs without an answer are not recorded.
+///
+/// # Parameters
+/// - `timestamp`: When the runs happened, in seconds since the Unix epoch.
+/// - `commit`: The abbreviated git commit hash, left empty if unknown.
+///
+/// # Returns
+/// An I/O error if the file could not be written.
+pub fn append_timings(
+    path: &str,
+    reports: &[RunReport],
+    timestamp: u64,
+    commit: Option<&str>,
+) -> io::Result<()> {
+    let mut lines = String::new();
+    if fs::metadata(path).map_or(true, |metadata| metadata.len() == 0) {
+        lines.push_str(TIMINGS_HEADER);
+        lines.push('\n');
+    }
+    for report in reports.iter().filter(|report| report.finished()) {
+        lines.push_str(&format!(
+            "{},{},{},{},{:.3}\n",
+            format_timestamp(timestamp),
+            commit.unwrap_or_default(),
+            report.day,
+            report.part,
+            report.solve_duration.as_secs_f64() * 1000.0
+        ));
+    }
+    append_lines(path, &lines)
+}
+
+/// Appends `lines` to the file at `path`, creating it and its parent directories if necessary.
+fn append_lines(path: &str, lines: &str) -> io::Result<()> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    file.write_all(lines.as_bytes())
+}
+
+/// Returns the current time in seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|duration| duration.as_secs())
+        .unwrap_or(0)
+}
+
+/// Appends the reports to the default history file, and to the configured
+/// [`timings_file`](crate::config::Config::timings_file) if there is one,
+/// logging instead of failing.
+///
+/// Recording the history is a side effect of running puzzles, so a read-only
+/// working directory should not turn a successful run into an error.
+pub fn record(reports: &[RunReport]) {
+    if let Err(err) = append(DEFAULT_HISTORY_PATH, reports) {
+        tracing::warn!(
+            "Could not record run history in '{}': {}",
+            DEFAULT_HISTORY_PATH,
+            err
+        );
+    }
+    if let Some(path) = &config::current().timings_file
+        && let Err(err) = append_timings(
+            path,
+            reports,
+            now(),
+            Environment::current().git_commit.as_deref(),
+        )
+    {
+        tracing::warn!("Could not record timings in '{}': {}", path, err);
+    }
+}
+
+/// Reads all entries from the history file at `path`, oldest first.
+///
+/// A missing file is treated as an empty history.
+///
+/// # Returns
+/// The entries in file order, or an I/O error if a line is not a valid entry.
+pub fn load(path: &str) -> io::Result<Vec<HistoryEntry>> {
+    let file = match fs::File::open(path) {
+        Ok(file) => file,
+        Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(err) => return Err(err),
+    };
+
+    let mut entries = Vec::new();
+    for (index, line) in BufReader::new(file).lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry = serde_json::from_str(&line).map_err(|err| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "Invalid history entry in '{}' line {}: {}",
+                    path,
+                    index + 1,
+                    err
+                ),
+            )
+        })?;
+        entries.push(entry);
+    }
+    Ok(entries)
+}
+
+/// Formats a Unix timestamp as `YYYY-MM-DD HH:MM:SS` in UTC.
+///
+/// # Examples
+/// ```
+/// use aoc2025::history::format_timestamp;
+///
+/// assert_eq!(format_timestamp(0), "1970-01-01 00:00:00");
+/// assert_eq!(format_timestamp(1764547200), "2025-12-01 00:00:00");
+/// ```
+pub fn format_timestamp(timestamp: u64) -> String {
+    let days = (timestamp / 86_400) as i64;
+    let seconds = timestamp % 86_400;
+
+    // Converts days since the epoch into a civil date (proleptic Gregorian calendar).
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}:{:02}",
+        year,
+        month,
+        day,
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn report(answer: &str, timed_out: bool) -> RunReport {
+        RunReport {
+            day: 4,
+            part: 2,
+            input_path: "inputs/day04.txt".to_string(),
+            answer: answer.to_string(),
+            input_bytes: 0,
+            input_lines: 0,
+            input_hash: 0xabc,
+            input_duration: Duration::from_micros(500),
+            parse_duration: Duration::ZERO,
+            solve_duration: Duration::from_millis(3),
+            samples: Vec::new(),
+            timed_out,
+            cancelled: false,
+            panic: None,
+            allocations: None,
+            peak_rss: None,
+            environment: None,
+        }
+    }
+
+    #[test]
+    fn test_from_report() {
+        let entry = HistoryEntry::from_report(&report("43", false), 42);
+        assert_eq!(entry.timestamp, 42);
+        assert_eq!((entry.day, entry.part), (4, 2));
+        assert_eq!(entry.answer, "43");
+        assert_eq!(entry.input_hash, "0000000000000abc");
+        assert_eq!(entry.input_ms, 0.5);
+        assert_eq!(entry.solve_ms, 3.0);
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-history-{}", std::process::id()));
+        let path = dir.join("nested/history.jsonl");
+        let path = path.to_str().unwrap();
+
+        assert_eq!(load(path).unwrap(), Vec::new());
+        append(path, &[report("43", false), report("", true)]).unwrap();
+        append(path, &[report("44", false)]).unwrap();
+
+        let entries = load(path).unwrap();
+        let answers: Vec<&str> = entries.iter().map(|entry| entry.answer.as_str()).collect();
+        assert_eq!(answers, vec!["43", "44"]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_append_timings() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-timings-{}", std::process::id()));
+        let path = dir.join("timings.csv");
+        let path = path.to_str().unwrap();
+
+        append_timings(
+            path,
+            &[report("43", false), report("", true)],
+            0,
+            Some("3f2a9c1"),
+        )
+        .unwrap();
+        append_timings(path, &[report("44", false)], 1_765_000_000, None).unwrap();
+        assert_eq!(
+            fs::read_to_string(path).unwrap(),
+            "timestamp,commit,day,part,solve_ms\n\
+             1970-01-01 00:00:00,3f2a9c1,4,2,3.000\n\
+             2025-12-06 05:46:40,,4,2,3.000\n"
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(951_782_400), "2000-02-29 00:00:00");
+        assert_eq!(format_timestamp(1_765_000_000), "2025-12-06 05:46:40");
+    }
+}
@@ -0,0 +1,269 @@
+use std::fmt;
+
+use crate::solver::Answer;
+
+/// An integer type the solvers of days 2, 3 and 6 can compute their sums in.
+///
+/// The solvers default to a type wide enough for every valid puzzle input,
+/// but their generic variants (e.g. [`day06::part1::total`](crate::day06::part1::total))
+/// accept any implementation, so a large custom input can opt into `i128` or
+/// `u128`, or with the `bigint` feature into an arbitrary precision `BigInt`
+/// or `BigUint`, instead of overflowing. The arithmetic is checked, so an overflow is reported as
+/// `None` instead of wrapping silently.
+///
+/// # Examples
+/// ```
+/// use aoc2025::integer::Integer;
+///
+/// assert_eq!(Integer::checked_mul(&i64::MAX, &2), None);
+/// assert_eq!(Integer::checked_mul(&i128::from(i64::MAX), &2), Some(i128::from(i64::MAX) * 2));
+/// assert_eq!(<i64 as Integer>::from_digit(7), 7);
+/// ```
+pub trait Integer: Clone + fmt::Debug + fmt::Display + PartialEq + Send + Into<Answer> {
+    /// Returns `0`.
+    fn zero() -> Self;
+
+    /// Converts a small number, e.g. a decimal digit.
+    fn from_digit(digit: u8) -> Self;
+
+    /// Converts an `i64`, e.g. a parsed joltage, which every implementation can
+    /// represent. Unsigned implementations panic on negative values, which no
+    /// solver passes.
+    fn from_i64(value: i64) -> Self;
+
+    /// Converts a `u128`, e.g. an ID of day 2, returning `None` if it does not fit.
+    fn from_u128(value: u128) -> Option<Self>;
+
+    /// Adds `other`, returning `None` on overflow.
+    fn checked_add(&self, other: &Self) -> Option<Self>;
+
+    /// Multiplies by `other`, returning `None` on overflow.
+    fn checked_mul(&self, other: &Self) -> Option<Self>;
+}
+
+impl Integer for i64 {
+    fn zero() -> i64 {
+        0
+    }
+
+    fn from_digit(digit: u8) -> i64 {
+        i64::from(digit)
+    }
+
+    fn from_i64(value: i64) -> i64 {
+        value
+    }
+
+    fn from_u128(value: u128) -> Option<i64> {
+        i64::try_from(value).ok()
+    }
+
+    fn checked_add(&self, other: &i64) -> Option<i64> {
+        i64::checked_add(*self, *other)
+    }
+
+    fn checked_mul(&self, other: &i64) -> Option<i64> {
+        i64::checked_mul(*self, *other)
+    }
+}
+
+impl Integer for i128 {
+    fn zero() -> i128 {
+        0
+    }
+
+    fn from_digit(digit: u8) -> i128 {
+        i128::from(digit)
+    }
+
+    fn from_i64(value: i64) -> i128 {
+        i128::from(value)
+    }
+
+    fn from_u128(value: u128) -> Option<i128> {
+        i128::try_from(value).ok()
+    }
+
+    fn checked_add(&self, other: &i128) -> Option<i128> {
+        i128::checked_add(*self, *other)
+    }
+
+    fn checked_mul(&self, other: &i128) -> Option<i128> {
+        i128::checked_mul(*self, *other)
+    }
+}
+
+impl Integer for u128 {
+    fn zero() -> u128 {
+        0
+    }
+
+    fn from_digit(digit: u8) -> u128 {
+        u128::from(digit)
+    }
+
+    fn from_i64(value: i64) -> u128 {
+        u128::try_from(value).expect("a negative value for an unsigned integer")
+    }
+
+    fn from_u128(value: u128) -> Option<u128> {
+        Some(value)
+    }
+
+    fn checked_add(&self, other: &u128) -> Option<u128> {
+        u128::checked_add(*self, *other)
+    }
+
+    fn checked_mul(&self, other: &u128) -> Option<u128> {
+        u128::checked_mul(*self, *other)
+    }
+}
+
+/// Parses a non-empty string of decimal digits into any [`Integer`].
+///
+/// # Returns
+/// The number, or `None` if `digits` is empty, contains anything but the
+/// digits `'0'` to `'9'`, or overflows `N`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::integer::parse_decimal;
+///
+/// assert_eq!(parse_decimal::<i64>("0042"), Some(42));
+/// assert_eq!(parse_decimal::<i64>("99999999999999999999"), None);
+/// assert_eq!(parse_decimal::<i128>("99999999999999999999"), Some(99999999999999999999));
+/// assert_eq!(parse_decimal::<i64>("-1"), None);
+/// ```
+pub fn parse_decimal<N: Integer>(digits: &str) -> Option<N> {
+    if digits.is_empty() {
+        return None;
+    }
+    let ten = N::from_digit(10);
+    digits.bytes().try_fold(N::zero(), |value, byte| {
+        if !byte.is_ascii_digit() {
+            return None;
+        }
+        value
+            .checked_mul(&ten)?
+            .checked_add(&N::from_digit(byte - b'0'))
+    })
+}
+
+#[cfg(feature = "bigint")]
+impl Integer for num_bigint::BigInt {
+    fn zero() -> num_bigint::BigInt {
+        num_bigint::BigInt::ZERO
+    }
+
+    fn from_digit(digit: u8) -> num_bigint::BigInt {
+        num_bigint::BigInt::from(digit)
+    }
+
+    fn from_i64(value: i64) -> num_bigint::BigInt {
+        num_bigint::BigInt::from(value)
+    }
+
+    fn from_u128(value: u128) -> Option<num_bigint::BigInt> {
+        Some(num_bigint::BigInt::from(value))
+    }
+
+    fn checked_add(&self, other: &num_bigint::BigInt) -> Option<num_bigint::BigInt> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &num_bigint::BigInt) -> Option<num_bigint::BigInt> {
+        Some(self * other)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<num_bigint::BigInt> for Answer {
+    fn from(number: num_bigint::BigInt) -> Answer {
+        i128::try_from(&number).map_or_else(|_| Answer::BigInt(number.to_string()), Answer::from)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl Integer for num_bigint::BigUint {
+    fn zero() -> num_bigint::BigUint {
+        num_bigint::BigUint::ZERO
+    }
+
+    fn from_digit(digit: u8) -> num_bigint::BigUint {
+        num_bigint::BigUint::from(digit)
+    }
+
+    fn from_i64(value: i64) -> num_bigint::BigUint {
+        u64::try_from(value)
+            .map(num_bigint::BigUint::from)
+            .expect("a negative value for an unsigned integer")
+    }
+
+    fn from_u128(value: u128) -> Option<num_bigint::BigUint> {
+        Some(num_bigint::BigUint::from(value))
+    }
+
+    fn checked_add(&self, other: &num_bigint::BigUint) -> Option<num_bigint::BigUint> {
+        Some(self + other)
+    }
+
+    fn checked_mul(&self, other: &num_bigint::BigUint) -> Option<num_bigint::BigUint> {
+        Some(self * other)
+    }
+}
+
+#[cfg(feature = "bigint")]
+impl From<num_bigint::BigUint> for Answer {
+    fn from(number: num_bigint::BigUint) -> Answer {
+        u128::try_from(&number).map_or_else(|_| Answer::BigInt(number.to_string()), Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Computes `10^exponent` through the trait, like the solvers build numbers.
+    fn power_of_ten<N: Integer>(exponent: u32) -> Option<N> {
+        (0..exponent).try_fold(N::from_digit(1), |value, _| {
+            value.checked_mul(&N::from_digit(10))
+        })
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        assert_eq!(power_of_ten::<i64>(18), Some(10_i64.pow(18)));
+        assert_eq!(power_of_ten::<i64>(19), None);
+        assert_eq!(power_of_ten::<i128>(19), Some(10_i128.pow(19)));
+        assert_eq!(
+            Integer::checked_add(&i64::MAX, &i64::zero()),
+            Some(i64::MAX)
+        );
+        assert_eq!(Integer::checked_add(&i64::MAX, &1), None);
+        assert_eq!(i128::from_i64(i64::MIN), i128::from(i64::MIN));
+        assert_eq!(power_of_ten::<u128>(38), Some(10_u128.pow(38)));
+        assert_eq!(power_of_ten::<u128>(39), None);
+        assert_eq!(i64::from_u128(u128::from(u64::MAX)), None);
+        assert_eq!(i128::from_u128(u128::MAX), None);
+    }
+
+    #[cfg(feature = "bigint")]
+    #[test]
+    fn test_bigint_never_overflows() {
+        let big: num_bigint::BigInt = power_of_ten(40).unwrap();
+        assert_eq!(
+            Answer::from(big),
+            Answer::parse(&format!("1{}", "0".repeat(40)))
+        );
+        assert_eq!(Answer::from(num_bigint::BigInt::from(42)), Answer::I64(42));
+        let big: num_bigint::BigUint = power_of_ten(40).unwrap();
+        assert_eq!(
+            Answer::from(big),
+            Answer::parse(&format!("1{}", "0".repeat(40)))
+        );
+        assert_eq!(
+            Answer::from(num_bigint::BigUint::from(42_u8)),
+            Answer::I64(42)
+        );
+    }
+}
@@ -1,7 +1,73 @@
+#[cfg(feature = "cli")]
+pub mod allocations;
+#[cfg(feature = "cli")]
+pub mod answers;
+#[cfg(feature = "cli")]
+pub mod aoc_client;
+pub mod api;
+#[cfg(feature = "cli")]
+pub mod batch;
+#[cfg(feature = "cli")]
+pub mod bench;
+pub mod cancel;
+pub mod check;
+#[cfg(feature = "clipboard")]
+pub mod clipboard;
+#[cfg(feature = "cli")]
+pub mod config;
+#[cfg(feature = "cli")]
+pub mod dashboard;
+#[cfg(feature = "day01")]
 pub mod day01;
+#[cfg(feature = "day02")]
 pub mod day02;
+#[cfg(feature = "day03")]
 pub mod day03;
+#[cfg(feature = "day04")]
 pub mod day04;
+#[cfg(feature = "day05")]
 pub mod day05;
+#[cfg(feature = "day06")]
 pub mod day06;
+pub mod embedded;
+#[cfg(feature = "cli")]
+pub mod environment;
+pub mod error;
+#[cfg(feature = "cli")]
+pub mod fetch;
+pub mod generators;
+pub mod grid;
+#[cfg(feature = "cli")]
+pub mod history;
+pub mod integer;
+#[cfg(feature = "cli")]
+pub mod memory;
+#[cfg(feature = "notify")]
+pub mod notify;
+pub mod panics;
+pub mod parsing;
+pub mod progress;
+#[cfg(feature = "cli")]
+pub mod puzzle;
+pub mod registry;
+#[cfg(feature = "cli")]
+pub mod report;
+#[cfg(feature = "cli")]
+pub mod scaffold;
+#[cfg(feature = "cli")]
+pub mod serve;
+pub mod solver;
+pub mod stats;
+pub mod table;
+#[cfg(feature = "cli")]
 pub mod utils;
+#[cfg(feature = "wasm")]
+pub mod wasm;
+#[cfg(feature = "cli")]
+pub mod watch;
+
+pub use api::{Error, YEAR, puzzles, solve};
+pub use solver::{Answer, SolveError};
+
+#[doc(hidden)]
+pub use inventory;
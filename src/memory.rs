@@ -0,0 +1,87 @@
+use std::fs;
+
+/// Executes `run` and measures the peak resident set size of the process meanwhile.
+///
+/// On Linux, the peak ("high water mark") tracked by the kernel is reset to
+/// the current resident size through `/proc/self/clear_refs` before `run`
+/// starts, and read from `/proc/self/status` afterwards. The result is the
+/// peak of the whole process, so with several solvers running in parallel it
+/// covers all of them.
+///
+/// # Returns
+/// The result of `run`, together with the peak in bytes, or `None` if the
+/// platform does not support measuring it.
+pub fn measure_peak_rss<R>(run: impl FnOnce() -> R) -> (R, Option<u64>) {
+    let reset = fs::write("/proc/self/clear_refs", "5").is_ok();
+    let result = run();
+    if !reset {
+        return (result, None);
+    }
+
+    let peak = fs::read_to_string("/proc/self/status")
+        .ok()
+        .and_then(|status| parse_peak_rss(&status));
+    (result, peak)
+}
+
+/// Extracts the peak resident set size (`VmHWM`) in bytes from `/proc/self/status`.
+fn parse_peak_rss(status: &str) -> Option<u64> {
+    let line = status.lines().find(|line| line.starts_with("VmHWM:"))?;
+    let kilobytes: u64 = line
+        .trim_start_matches("VmHWM:")
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()?;
+    Some(kilobytes * 1024)
+}
+
+/// Formats a number of bytes with a binary unit, e.g. `"12.5 MiB"`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::memory::format_bytes;
+///
+/// assert_eq!(format_bytes(512), "512 B");
+/// assert_eq!(format_bytes(1536), "1.5 KiB");
+/// assert_eq!(format_bytes(3 * 1024 * 1024), "3.0 MiB");
+/// ```
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_peak_rss() {
+        let status = "Name:\taoc\nVmPeak:\t    2640 kB\nVmHWM:\t    1412 kB\nVmRSS:\t    1400 kB\n";
+        assert_eq!(parse_peak_rss(status), Some(1412 * 1024));
+        assert_eq!(parse_peak_rss("Name:\taoc\n"), None);
+    }
+
+    #[test]
+    fn test_measure_peak_rss() {
+        let (value, peak) = measure_peak_rss(|| 7);
+        assert_eq!(value, 7);
+        if cfg!(target_os = "linux") {
+            // Sandboxes may forbid writing to clear_refs, but a measured peak is never zero.
+            assert_ne!(peak, Some(0));
+        } else {
+            assert_eq!(peak, None);
+        }
+    }
+}
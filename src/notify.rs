@@ -0,0 +1,97 @@
+use std::time::Duration;
+
+use notify_rust::Notification;
+
+use crate::config;
+use crate::utils::{RunReport, format_duration};
+
+/// Returns the reports of solves that took longer than `threshold`.
+///
+/// Cancelled runs are left out, as whoever pressed Ctrl-C is already looking.
+pub fn slow_reports(reports: &[RunReport], threshold: Duration) -> Vec<&RunReport> {
+    reports
+        .iter()
+        .filter(|report| !report.cancelled && report.total_duration() > threshold)
+        .collect()
+}
+
+/// Returns the summary and the body of the notification for a report, e.g.
+/// `("Day 2 part 1 finished", "Answer: 1227775554\nTook 133.402 s")`.
+pub fn message(report: &RunReport) -> (String, String) {
+    let summary = format!("Day {} part {} finished", report.day, report.part);
+    let body = format!(
+        "Answer: {}\nTook {}",
+        report.display_answer(),
+        format_duration(report.total_duration())
+    );
+    (summary, body)
+}
+
+/// Shows a desktop notification for every solve that took longer than the
+/// configured [`notify_after`](crate::config::Config::notify_after).
+///
+/// Notifications are a convenience, so a missing notification service is
+/// logged instead of failing the run.
+pub fn notify_slow(reports: &[RunReport]) {
+    for report in slow_reports(reports, config::current().notify_after) {
+        let (summary, body) = message(report);
+        if let Err(err) = Notification::new()
+            .appname("aoc")
+            .summary(&summary)
+            .body(&body)
+            .show()
+        {
+            tracing::warn!("Could not show a desktop notification: {}", err);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(part: i32, solve_secs: u64) -> RunReport {
+        RunReport {
+            day: 2,
+            part,
+            input_path: "inputs/day02.txt".to_string(),
+            answer: "1227775554".to_string(),
+            input_bytes: 0,
+            input_lines: 0,
+            input_hash: 0,
+            input_duration: Duration::ZERO,
+            parse_duration: Duration::ZERO,
+            solve_duration: Duration::from_secs(solve_secs),
+            samples: Vec::new(),
+            timed_out: false,
+            cancelled: false,
+            panic: None,
+            allocations: None,
+            peak_rss: None,
+            environment: None,
+        }
+    }
+
+    #[test]
+    fn test_slow_reports() {
+        let mut cancelled = report(2, 60);
+        cancelled.cancelled = true;
+        let reports = [report(1, 5), report(2, 60), cancelled];
+
+        let slow = slow_reports(&reports, Duration::from_secs(10));
+        assert_eq!(slow.len(), 1);
+        assert_eq!(slow[0].part, 2);
+        assert!(slow_reports(&reports, Duration::from_secs(60)).is_empty());
+    }
+
+    #[test]
+    fn test_message() {
+        let (summary, body) = message(&report(1, 12));
+        assert_eq!(summary, "Day 2 part 1 finished");
+        assert_eq!(body, "Answer: 1227775554\nTook 12.000 s");
+
+        let mut timed_out = report(2, 30);
+        timed_out.timed_out = true;
+        assert!(message(&timed_out).1.starts_with("Answer: timed out\n"));
+    }
+}
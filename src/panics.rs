@@ -0,0 +1,139 @@
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::Once;
+
+thread_local! {
+    /// Whether a panic on this thread happens inside [`catch`].
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    /// The panic recorded by the hook for the innermost [`catch`] on this thread.
+    static CAUGHT: RefCell<Option<SolverPanic>> = const { RefCell::new(None) };
+}
+
+/// Installs the panic hook recording panics inside [`catch`] exactly once.
+static INSTALL_HOOK: Once = Once::new();
+
+/// Where and why a solver panicked, see [`catch`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SolverPanic {
+    /// The panic message, e.g. `"attempt to subtract with overflow"`.
+    pub message: String,
+    /// The source location of the panic, e.g. `"src/day04/part2.rs:31:9"`, if known.
+    pub location: Option<String>,
+}
+
+impl fmt::Display for SolverPanic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "solver panicked at {}: {}", location, self.message),
+            None => write!(f, "solver panicked: {}", self.message),
+        }
+    }
+}
+
+/// Runs `function`, catching a panic instead of unwinding into the caller.
+///
+/// Panics caught here are not printed by the default panic hook, so a
+/// panicking solver does not interleave a raw message and backtrace note
+/// with the report. Panics outside of `catch`, also on threads spawned by
+/// `function`, are printed as usual.
+///
+/// # Returns
+/// The result of `function`, or where and why it panicked.
+///
+/// # Examples
+/// ```
+/// use aoc2025::panics::catch;
+///
+/// assert_eq!(catch(|| 6 * 7), Ok(42));
+///
+/// let panic = catch(|| -> i32 { panic!("no solution") }).unwrap_err();
+/// assert_eq!(panic.message, "no solution");
+/// assert!(panic.location.is_some());
+/// ```
+pub fn catch<T>(function: impl FnOnce() -> T) -> Result<T, SolverPanic> {
+    INSTALL_HOOK.call_once(|| {
+        let previous = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(Cell::get) {
+                return previous(info);
+            }
+            let caught = SolverPanic {
+                message: payload_message(info.payload()),
+                location: info.location().map(ToString::to_string),
+            };
+            CAUGHT.with(|slot| *slot.borrow_mut() = Some(caught));
+        }));
+    });
+
+    let was_catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(function));
+    CATCHING.with(|catching| catching.set(was_catching));
+
+    result.map_err(|payload| {
+        CAUGHT
+            .with(|slot| slot.borrow_mut().take())
+            .unwrap_or_else(|| SolverPanic {
+                message: payload_message(payload.as_ref()),
+                location: None,
+            })
+    })
+}
+
+/// Returns the message of a panic payload, which is a `&str` or `String` for
+/// `panic!` with a message.
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_catch() {
+        assert_eq!(catch(|| "fine"), Ok("fine"));
+
+        let values: Vec<i32> = Vec::new();
+        let panic = catch(|| values[3]).unwrap_err();
+        assert!(panic.message.contains("index out of bounds"));
+        assert!(panic.location.unwrap().starts_with("src/panics.rs:"));
+
+        let panic = catch(|| std::panic::panic_any(7)).unwrap_err();
+        assert_eq!(panic.message, "Box<dyn Any>");
+    }
+
+    #[test]
+    fn test_catch_nested() {
+        let outer = catch(|| {
+            let inner = catch(|| panic!("inner {}", 1));
+            assert_eq!(inner.unwrap_err().message, "inner 1");
+            panic!("outer");
+        });
+        assert_eq!(outer.unwrap_err().message, "outer");
+    }
+
+    #[test]
+    fn test_display() {
+        let panic = SolverPanic {
+            message: "boom".to_string(),
+            location: Some("src/day01/part1.rs:3:5".to_string()),
+        };
+        assert_eq!(
+            panic.to_string(),
+            "solver panicked at src/day01/part1.rs:3:5: boom"
+        );
+        let panic = SolverPanic {
+            location: None,
+            ..panic
+        };
+        assert_eq!(panic.to_string(), "solver panicked: boom");
+    }
+}
@@ -0,0 +1,302 @@
+use std::fmt::Display;
+use std::io::{self, BufRead};
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use crate::grid::Grid;
+
+/// Returns the non-blank lines of an input with surrounding whitespace removed.
+///
+/// Unlike splitting at `'\n'`, a trailing newline, Windows line endings and
+/// blank lines never produce empty items.
+///
+/// # Examples
+/// ```
+/// use aoc2025::parsing::lines;
+///
+/// let lines: Vec<&str> = lines("L68\r\nR48\n\n").collect();
+/// assert_eq!(lines, vec!["L68", "R48"]);
+/// ```
+pub fn lines(input: &str) -> impl Iterator<Item = &str> {
+    input.lines().map(str::trim).filter(|line| !line.is_empty())
+}
+
+/// Splits an input into the sections separated by blank lines.
+///
+/// Lines containing only whitespace count as blank, and several blank lines
+/// in a row separate only two sections. Each section keeps its inner line
+/// breaks but no leading or trailing ones.
+///
+/// # Examples
+/// ```
+/// use aoc2025::parsing::blank_line_sections;
+///
+/// let sections = blank_line_sections("3-5\n10-14\n\n1\n5\n");
+/// assert_eq!(sections, vec!["3-5\n10-14", "1\n5"]);
+/// ```
+pub fn blank_line_sections(input: &str) -> Vec<&str> {
+    let mut sections: Vec<&str> = Vec::new();
+    let mut start: Option<usize> = None;
+    let mut end: usize = 0;
+    let mut offset: usize = 0;
+
+    for line in input.split_inclusive('\n') {
+        let content = line.trim_end_matches(['\n', '\r']);
+        if content.trim().is_empty() {
+            if let Some(section_start) = start.take() {
+                sections.push(&input[section_start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + content.len();
+        }
+        offset += line.len();
+    }
+    if let Some(section_start) = start {
+        sections.push(&input[section_start..end]);
+    }
+    sections
+}
+
+/// Extracts every integer from a text, ignoring the characters between them.
+///
+/// An integer is a run of ASCII digits. A `'-'` directly before it is parsed
+/// as sign, unless it follows a digit itself, so ranges like `"3-5"` yield
+/// both bounds.
+///
+/// # Returns
+/// The integers in order of appearance, or an error naming the first one
+/// that does not fit into `T`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::parsing::numbers;
+///
+/// assert_eq!(numbers::<i64>("move -3 from 7-9").unwrap(), vec![-3, 7, 9]);
+/// assert!(numbers::<u8>("300").is_err());
+/// ```
+pub fn numbers<T>(text: &str) -> io::Result<Vec<T>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let bytes = text.as_bytes();
+    let mut numbers: Vec<T> = Vec::new();
+    let mut index: usize = 0;
+
+    while index < bytes.len() {
+        if !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+        let negative = index > 0
+            && bytes[index - 1] == b'-'
+            && (index < 2 || !bytes[index - 2].is_ascii_digit());
+        let start = if negative { index - 1 } else { index };
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        numbers.push(parse(&text[start..index])?);
+    }
+    Ok(numbers)
+}
+
+/// Parses the characters of an input into a grid, one row per line.
+///
+/// Lines shorter than the longest one are filled up with spaces. A trailing
+/// newline does not add an empty row.
+///
+/// # Examples
+/// ```
+/// use aoc2025::parsing::char_grid;
+///
+/// let grid = char_grid("..@\n@@\n");
+/// assert_eq!((grid.width(), grid.height()), (3, 2));
+/// assert_eq!(grid[1], ['@', '@', ' ']);
+/// ```
+pub fn char_grid(input: &str) -> Grid<char> {
+    Grid::from_lines(input, ' ', |c| c)
+}
+
+/// Parses comma-separated inclusive ranges like `"11-22,95-115"`.
+///
+/// Whitespace around the ranges, including line breaks, is ignored.
+///
+/// # Returns
+/// The ranges in input order, or an error naming the first range that is not
+/// two values of `T` separated by `'-'`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::parsing::comma_ranges;
+///
+/// assert_eq!(comma_ranges::<i64>("11-22,\n95-115\n").unwrap(), vec![11..=22, 95..=115]);
+/// assert!(comma_ranges::<i64>("11-22,95").is_err());
+/// ```
+pub fn comma_ranges<T>(input: &str) -> io::Result<Vec<RangeInclusive<T>>>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    input
+        .split(',')
+        .map(str::trim)
+        .filter(|range| !range.is_empty())
+        .map(|range| {
+            let (start, end) = range.split_once('-').ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Expected a range like '3-5', found '{}'", range),
+                )
+            })?;
+            Ok(parse(start.trim())?..=parse(end.trim())?)
+        })
+        .collect()
+}
+
+/// Reads an input line by line and parses every line with `parse`, without
+/// holding the whole input in memory.
+///
+/// Line endings (`"\n"` or `"\r\n"`) are removed before parsing. Use
+/// [`try_consume`] to run a solver written for plain items on the result.
+///
+/// # Returns
+/// An iterator over the parsed lines. An item is an I/O error if reading
+/// failed, or an `InvalidData` error naming the line if `parse` rejected it.
+///
+/// # Examples
+/// ```
+/// use aoc2025::parsing::read_lines;
+///
+/// let parsed: Vec<_> = read_lines("1\n2\nx".as_bytes(), |line| line.parse::<i32>().map_err(|err| err.to_string())).collect();
+/// assert_eq!(parsed[1].as_ref().unwrap(), &2);
+/// assert!(parsed[2].as_ref().unwrap_err().to_string().starts_with("line 3: "));
+/// ```
+pub fn read_lines<R, T, F>(reader: R, mut parse: F) -> impl Iterator<Item = io::Result<T>>
+where
+    R: BufRead,
+    F: FnMut(&str) -> Result<T, String>,
+{
+    reader
+        .lines()
+        .enumerate()
+        .map(move |(index, line)| parse(&line?).map_err(|message| invalid_line(index + 1, message)))
+}
+
+/// Passes the items of a fallible iterator to `consume` until the first error.
+///
+/// This lets a solver working on plain items, e.g. an `impl Iterator<Item = i32>`,
+/// run on [`read_lines`] without collecting the items first.
+///
+/// # Returns
+/// The result of `consume`, or the first error of `items`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::parsing::{read_lines, try_consume};
+///
+/// let parse = |line: &str| line.parse::<i32>().map_err(|err| err.to_string());
+/// let sum = try_consume(read_lines("1\n2".as_bytes(), parse), |numbers| numbers.sum::<i32>());
+/// assert_eq!(sum.unwrap(), 3);
+/// assert!(try_consume(read_lines("1\nx".as_bytes(), parse), |numbers| numbers.sum::<i32>()).is_err());
+/// ```
+pub fn try_consume<T, R, F>(items: impl Iterator<Item = io::Result<T>>, consume: F) -> io::Result<R>
+where
+    F: FnOnce(&mut dyn Iterator<Item = T>) -> R,
+{
+    let mut error: Option<io::Error> = None;
+    let mut valid = items.map_while(|item| item.map_err(|err| error = Some(err)).ok());
+    let result = consume(&mut valid);
+    drop(valid);
+    match error {
+        Some(err) => Err(err),
+        None => Ok(result),
+    }
+}
+
+/// Builds the `InvalidData` error of a malformed line, e.g. `"line 3: expected ..."`.
+pub fn invalid_line(line_number: usize, message: impl Display) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("line {}: {}", line_number, message),
+    )
+}
+
+/// Parses a single value, describing the text in the error if it is invalid.
+fn parse<T>(text: &str) -> io::Result<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text.parse().map_err(|err| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("Invalid number '{}': {}", text, err),
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        assert_eq!(lines("").count(), 0);
+        assert_eq!(lines(" a \n\n  \nb").collect::<Vec<_>>(), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn test_blank_line_sections() {
+        assert!(blank_line_sections("\n \n").is_empty());
+        assert_eq!(
+            blank_line_sections("\r\na\r\nb\r\n \r\n\r\nc"),
+            vec!["a\r\nb", "c"]
+        );
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(numbers::<i32>("").unwrap(), Vec::<i32>::new());
+        assert_eq!(numbers::<i32>("-1,-2--3").unwrap(), vec![-1, -2, -3]);
+        assert_eq!(numbers::<u64>("x=10, y=20").unwrap(), vec![10, 20]);
+        let err = numbers::<u32>("-7").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("'-7'"));
+    }
+
+    #[test]
+    fn test_char_grid() {
+        let grid = char_grid("ab\ncd");
+        assert_eq!(grid[0], ['a', 'b']);
+        assert_eq!(grid[1][0], 'c');
+    }
+
+    #[test]
+    fn test_read_lines() {
+        let parse = |line: &str| line.parse::<u8>().map_err(|err| err.to_string());
+        let parsed: Vec<u8> = read_lines("1\r\n2\n".as_bytes(), parse)
+            .collect::<io::Result<_>>()
+            .unwrap();
+        assert_eq!(parsed, vec![1, 2]);
+
+        let err = try_consume(read_lines("1\n2\n300\n4".as_bytes(), parse), |numbers| {
+            numbers.count()
+        })
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().starts_with("line 3: "), "{}", err);
+    }
+
+    #[test]
+    fn test_comma_ranges() {
+        assert!(comma_ranges::<i64>("").unwrap().is_empty());
+        assert_eq!(comma_ranges::<u8>(" 1 - 2 ").unwrap(), vec![1..=2]);
+        assert!(
+            comma_ranges::<i64>("1-x")
+                .unwrap_err()
+                .to_string()
+                .contains("'x'")
+        );
+    }
+}
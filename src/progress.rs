@@ -0,0 +1,159 @@
+use std::cell::RefCell;
+use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+/// Whether progress bars are drawn at all, see [`set_enabled`].
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// The minimum time between two redraws of a progress bar.
+const REDRAW_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The number of characters of the bar itself, without label and counters.
+const BAR_WIDTH: usize = 30;
+
+thread_local! {
+    /// The progress bar of the solver running on this thread, if any.
+    static CURRENT: RefCell<Option<ProgressBar>> = const { RefCell::new(None) };
+}
+
+/// The state of the progress bar of one running solver.
+struct ProgressBar {
+    /// The text shown in front of the bar.
+    label: String,
+    /// When the bar was last drawn, or `None` if it was not drawn yet.
+    last_draw: Option<Instant>,
+    /// When the solver started, so short solvers never draw a bar.
+    start: Instant,
+}
+
+/// Turns drawing progress bars on stderr on or off for the whole process.
+///
+/// Progress bars are disabled by default, so library users and tests never
+/// see them. The binaries enable them when stderr is a terminal.
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Reports that `done` out of `total` units of work are finished.
+///
+/// Solvers call this from their main loop, e.g. after every scanned range.
+/// Outside of a run started by the runner, or with progress bars disabled,
+/// this does nothing and is cheap enough to call in hot loops. Redraws are
+/// throttled, and nothing is drawn during the first 100 ms, so
+/// fast solvers produce no output.
+///
+/// # Examples
+/// ```
+/// use aoc2025::progress;
+///
+/// let ranges = ["11-22", "95-115"];
+/// for (index, _range) in ranges.iter().enumerate() {
+///     progress::report(index as u64 + 1, ranges.len() as u64);
+/// }
+/// ```
+pub fn report(done: u64, total: u64) {
+    CURRENT.with(|current| {
+        let mut current = current.borrow_mut();
+        let Some(bar) = current.as_mut() else {
+            return;
+        };
+
+        let now = Instant::now();
+        let due = match bar.last_draw {
+            Some(last_draw) => now.duration_since(last_draw) >= REDRAW_INTERVAL,
+            None => now.duration_since(bar.start) >= REDRAW_INTERVAL,
+        };
+        if due {
+            bar.last_draw = Some(now);
+            let mut stderr = io::stderr().lock();
+            let _ = write!(stderr, "\r{}", render(&bar.label, done, total));
+            let _ = stderr.flush();
+        }
+    });
+}
+
+/// Runs `run` with a progress bar labeled with the day and part.
+///
+/// Calls to [`report`] on the current thread draw to that bar while `run`
+/// executes. The bar is erased afterwards, so the regular report follows
+/// on a clean line.
+#[cfg(feature = "cli")]
+pub(crate) fn track<R>(day: i32, part: i32, run: impl FnOnce() -> R) -> R {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return run();
+    }
+
+    CURRENT.with(|current| {
+        *current.borrow_mut() = Some(ProgressBar {
+            label: format!("Day {} part {}", day, part),
+            last_draw: None,
+            start: Instant::now(),
+        });
+    });
+    let result = run();
+    let bar = CURRENT.with(|current| current.borrow_mut().take());
+
+    if bar.is_some_and(|bar| bar.last_draw.is_some()) {
+        let mut stderr = io::stderr().lock();
+        let _ = write!(stderr, "\r\x1b[2K");
+        let _ = stderr.flush();
+    }
+    result
+}
+
+/// Renders one line of a progress bar, e.g. `Day 2 part 1 [#####-----]  50% (1/2)`.
+fn render(label: &str, done: u64, total: u64) -> String {
+    let done = done.min(total);
+    let filled = match total {
+        0 => BAR_WIDTH,
+        _ => (done as u128 * BAR_WIDTH as u128 / total as u128) as usize,
+    };
+    let percent = match total {
+        0 => 100,
+        _ => done as u128 * 100 / total as u128,
+    };
+
+    format!(
+        "{} [{}{}] {:>3}% ({}/{})",
+        label,
+        "#".repeat(filled),
+        "-".repeat(BAR_WIDTH - filled),
+        percent,
+        done,
+        total
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render("Day 2 part 1", 1, 2),
+            format!(
+                "Day 2 part 1 [{}{}]  50% (1/2)",
+                "#".repeat(15),
+                "-".repeat(15)
+            )
+        );
+        assert_eq!(
+            render("x", 5, 3),
+            format!("x [{}] 100% (3/3)", "#".repeat(BAR_WIDTH))
+        );
+        assert_eq!(
+            render("x", 0, 0),
+            format!("x [{}] 100% (0/0)", "#".repeat(BAR_WIDTH))
+        );
+    }
+
+    #[test]
+    fn test_report_without_bar() {
+        // Must not panic or draw anything outside of a tracked run.
+        report(1, 2);
+        #[cfg(feature = "cli")]
+        assert_eq!(track(1, 1, || 42), 42);
+    }
+}
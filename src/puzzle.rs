@@ -0,0 +1,223 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use tracing::info;
+
+use crate::aoc_client::{CachePolicy, Client};
+use crate::config::Config;
+
+/// The directory puzzle descriptions are stored in.
+pub const PUZZLE_DIR: &str = "puzzles";
+
+/// How long a downloaded puzzle page is reused before it is requested again.
+///
+/// The page gains the description of part 2 once part 1 is solved, so it is
+/// not cached for good like the inputs.
+const PAGE_MAX_AGE: Duration = Duration::from_secs(15 * 60);
+
+/// The title and descriptions of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Puzzle {
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The title without the day, e.g. `"Lobby"`.
+    pub title: String,
+    /// The description of every unlocked part as Markdown, in part order.
+    pub parts: Vec<String>,
+}
+
+impl Puzzle {
+    /// Extracts the puzzle from the HTML of its page.
+    ///
+    /// Every part is described in an `<article class="day-desc">` element,
+    /// the first of which starts with the heading `--- Day N: Title ---`.
+    ///
+    /// # Returns
+    /// The puzzle, or `None` if the page contains no description.
+    pub fn from_html(day: i32, html: &str) -> Option<Puzzle> {
+        let parts: Vec<String> = html
+            .split("<article class=\"day-desc\">")
+            .skip(1)
+            .filter_map(|article| article.split("</article>").next())
+            .map(html_to_markdown)
+            .collect();
+
+        let heading = parts.first()?.lines().next()?;
+        let title = heading
+            .trim_start_matches("## ")
+            .trim_matches(|c| c == '-' || c == ' ');
+        let title = title
+            .split_once(": ")
+            .map_or(title, |(_, title)| title)
+            .to_string();
+        Some(Puzzle { day, title, parts })
+    }
+
+    /// Renders the puzzle as Markdown document with the title as top-level heading.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = format!("# Day {}: {}\n", self.day, self.title);
+        for (index, part) in self.parts.iter().enumerate() {
+            // The heading of the first part repeats the title, so both are replaced.
+            let body = part.split_once('\n').map_or("", |(_, body)| body);
+            let heading = if index == 0 { "Part One" } else { "Part Two" };
+            markdown.push_str(&format!("\n## {}\n{}", heading, body));
+        }
+        markdown
+    }
+}
+
+/// Returns the file the description of a day is stored in, e.g. `puzzles/2025/day03.md`.
+///
+/// # Examples
+/// ```
+/// use aoc2025::puzzle::puzzle_path;
+///
+/// assert_eq!(puzzle_path(2024, 3), "puzzles/2024/day03.md");
+/// ```
+pub fn puzzle_path(year: i32, day: i32) -> String {
+    format!("{}/{}/day{:02}.md", PUZZLE_DIR, year, day)
+}
+
+/// Downloads the description of a day and stores it as Markdown at [`puzzle_path`].
+///
+/// # Returns
+/// The puzzle and the path it was stored at, or an error if no session token
+/// is available, the download failed, or the page contains no description.
+pub fn fetch_puzzle(config: &Config, day: i32) -> io::Result<(Puzzle, String)> {
+    let client = Client::from_config(config)?;
+    let html = client.get(
+        &format!("/{}/day/{}", config.year, day),
+        CachePolicy::MaxAge(PAGE_MAX_AGE),
+    )?;
+    let puzzle = Puzzle::from_html(day, &html).ok_or_else(|| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "The page of {} day {} contains no puzzle description",
+                config.year, day
+            ),
+        )
+    })?;
+
+    let path = puzzle_path(config.year, day);
+    if let Some(parent) = Path::new(&path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, puzzle.to_markdown())?;
+    info!(path, "puzzle stored");
+    Ok((puzzle, path))
+}
+
+/// Returns the title of a day from its stored description, if it was downloaded.
+///
+/// # Examples
+/// ```
+/// use aoc2025::puzzle::title;
+///
+/// assert_eq!(title(2025, 99), None);
+/// ```
+pub fn title(year: i32, day: i32) -> Option<String> {
+    let content = fs::read_to_string(puzzle_path(year, day)).ok()?;
+    let heading = content.lines().next()?.strip_prefix("# ")?;
+    let title = heading.split_once(": ").map_or(heading, |(_, title)| title);
+    Some(title.to_string())
+}
+
+/// Converts the HTML of a puzzle description to Markdown.
+///
+/// Only the few elements used by the descriptions are translated: headings,
+/// paragraphs, lists, code blocks, inline code and emphasis. Other tags are
+/// dropped and their text kept.
+fn html_to_markdown(html: &str) -> String {
+    let mut markdown = String::new();
+    let mut in_pre = false;
+    let mut rest = html;
+
+    while let Some(start) = rest.find('<') {
+        markdown.push_str(&decode_entities(&rest[..start]));
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = &rest[start + 1..start + end];
+        let name = tag
+            .split(|c: char| c.is_whitespace())
+            .next()
+            .unwrap_or_default();
+
+        match name {
+            "h2" => markdown.push_str("## "),
+            "/h2" | "/p" | "/ul" => markdown.push_str("\n\n"),
+            "pre" => {
+                in_pre = true;
+                markdown.push_str("```\n");
+            }
+            "/pre" => {
+                in_pre = false;
+                if !markdown.ends_with('\n') {
+                    markdown.push('\n');
+                }
+                markdown.push_str("```\n\n");
+            }
+            "code" | "/code" if !in_pre => markdown.push('`'),
+            "em" | "/em" => markdown.push('*'),
+            "li" => markdown.push_str("- "),
+            "/li" => markdown.push('\n'),
+            _ => {}
+        }
+        rest = &rest[start + end + 1..];
+    }
+    markdown.push_str(&decode_entities(rest));
+
+    while markdown.contains("\n\n\n") {
+        markdown = markdown.replace("\n\n\n", "\n\n");
+    }
+    markdown.trim().to_string() + "\n"
+}
+
+/// Replaces the HTML entities used in puzzle descriptions by their characters.
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = "<main>\n<article class=\"day-desc\"><h2>--- Day 3: Lobby ---</h2>\
+        <p>You descend a <em>short</em> staircase.</p>\
+        <pre><code>987654321111111\n811111111111119\n</code></pre>\
+        <ul><li>In <code>987654321111111</code>, pick &lt;two&gt;.</li></ul>\
+        <p>What is the <em>total output joltage</em>?</p></article>\
+        <p>Your puzzle answer was <code>357</code>.</p>\
+        <article class=\"day-desc\"><h2 id=\"part2\">--- Part Two ---</h2><p>Now twelve.</p></article>\n</main>";
+
+    #[test]
+    fn test_from_html() {
+        let puzzle = Puzzle::from_html(3, PAGE).unwrap();
+        assert_eq!(puzzle.title, "Lobby");
+        assert_eq!(puzzle.parts.len(), 2);
+        assert_eq!(
+            puzzle.parts[0],
+            "## --- Day 3: Lobby ---\n\nYou descend a *short* staircase.\n\n\
+             ```\n987654321111111\n811111111111119\n```\n\n\
+             - In `987654321111111`, pick <two>.\n\n\
+             What is the *total output joltage*?\n"
+        );
+        assert_eq!(Puzzle::from_html(3, "<main></main>"), None);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        let markdown = Puzzle::from_html(3, PAGE).unwrap().to_markdown();
+        assert!(markdown.starts_with("# Day 3: Lobby\n\n## Part One\n\nYou descend"));
+        assert!(markdown.ends_with("## Part Two\n\nNow twelve.\n"));
+    }
+}
@@ -0,0 +1,408 @@
+use std::sync::OnceLock;
+
+use crate::check::Validator;
+#[cfg(feature = "cli")]
+use crate::config;
+use crate::generators::Generator;
+use crate::solver::{Answer, BothSolver, Metadata, SolveError};
+
+/// A solver function taking the puzzle input and returning the answer.
+pub type Solver = fn(&str) -> Answer;
+
+/// A solver function like [`Solver`] that reports a malformed input instead
+/// of panicking, see [`try_solve_part1`](crate::solver::try_solve_part1).
+pub type TrySolver = fn(&str) -> Result<Answer, SolveError>;
+
+/// Everything the registry knows about one implemented day.
+///
+/// Every day module submits one entry with [`register_day!`](crate::register_day),
+/// so a day is wired up in the module that implements it; there is no central
+/// table to forget. The `aoc` command line interface and the `all` binary
+/// pick up registered days automatically through [`events`].
+#[derive(Debug)]
+pub struct DayEntry {
+    /// The year of the event the day belongs to.
+    pub year: i32,
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The implemented parts with their solvers, ordered by part.
+    pub parts: &'static [(i32, Solver)],
+    /// The fallible solvers of the implemented parts, ordered by part.
+    pub try_parts: &'static [(i32, TrySolver)],
+    /// The solver of both parts on a single parse, if both parts are
+    /// implemented, see [`solve_both_timed`](crate::solver::solve_both_timed).
+    pub both_parts: Option<BothSolver>,
+    /// The input validator of the day, see [`Validator`].
+    pub validator: Validator,
+    /// The puzzle title, example and complexity notes declared by the solver.
+    pub metadata: Metadata,
+    /// The stress-input generator of the day.
+    pub generator: Generator,
+}
+
+inventory::collect!(DayEntry);
+
+/// Registers a day with the registry.
+///
+/// Invoke it once in the module of the day, which must provide the
+/// `validate` function every day module has. The title and example are taken
+/// from the [`Solver::METADATA`](crate::solver::Solver::METADATA) of the solver. The parts are given as `[1, 2]` for solvers
+/// implementing [`Part2`](crate::solver::Part2), or `[1]` while part 2 is
+/// not solved yet.
+///
+/// # Examples
+/// ```ignore
+/// pub struct Day07;
+///
+/// impl Solver for Day07 { /* ... */ }
+///
+/// impl Part2 for Day07 { /* ... */ }
+///
+/// register_day!(year: 2025, day: 7, solver: Day07, parts: [1, 2], generator: generators::day07);
+/// ```
+#[macro_export]
+macro_rules! register_day {
+    (year: $year:literal, day: $day:literal, solver: $solver:ty, parts: [1, 2], generator: $generator:path $(,)?) => {
+        $crate::register_day!(@submit $year, $day, $solver, $generator,
+            &[
+                (1, $crate::solver::solve_part1::<$solver>),
+                (2, $crate::solver::solve_part2::<$solver>),
+            ],
+            &[
+                (1, $crate::solver::try_solve_part1::<$solver>),
+                (2, $crate::solver::try_solve_part2::<$solver>),
+            ],
+            Some($crate::solver::solve_both_timed::<$solver>)
+        );
+    };
+    (year: $year:literal, day: $day:literal, solver: $solver:ty, parts: [1], generator: $generator:path $(,)?) => {
+        $crate::register_day!(@submit $year, $day, $solver, $generator,
+            &[(1, $crate::solver::solve_part1::<$solver>)],
+            &[(1, $crate::solver::try_solve_part1::<$solver>)],
+            None
+        );
+    };
+    (@submit $year:literal, $day:literal, $solver:ty, $generator:path, $parts:expr, $try_parts:expr, $both_parts:expr) => {
+        $crate::inventory::submit! {
+            $crate::registry::DayEntry {
+                year: $year,
+                day: $day,
+                parts: $parts,
+                try_parts: $try_parts,
+                both_parts: $both_parts,
+                validator: validate,
+                metadata: <$solver as $crate::solver::Solver>::METADATA,
+                generator: $generator,
+            }
+        }
+    };
+}
+
+/// The puzzles of one Advent of Code event.
+///
+/// Solutions of several years can live in the same workspace; the event of
+/// the configured year (see [`Config::year`](crate::config::Config::year))
+/// is the one the lookup functions of this module search.
+#[derive(Debug)]
+pub struct Event {
+    /// The year of the event.
+    pub year: i32,
+    /// Every registered day of the event, ordered by day.
+    pub days: Vec<&'static DayEntry>,
+    /// Every implemented puzzle as `(day, part, solver)`, ordered by day and part.
+    pub solvers: Vec<(i32, i32, Solver)>,
+}
+
+/// Returns every event with registered days, ordered by year.
+///
+/// The events are collected from the [`register_day!`](crate::register_day)
+/// entries on first use.
+///
+/// # Panics
+/// Panics if a day of an event is registered twice.
+pub fn events() -> &'static [Event] {
+    static EVENTS: OnceLock<Vec<Event>> = OnceLock::new();
+    EVENTS.get_or_init(|| {
+        let mut entries: Vec<&'static DayEntry> = inventory::iter::<DayEntry>().collect();
+        entries.sort_by_key(|entry| (entry.year, entry.day));
+
+        let mut events: Vec<Event> = Vec::new();
+        for entry in entries {
+            match events.last_mut() {
+                Some(event) if event.year == entry.year => {
+                    assert!(
+                        event.days.last().is_none_or(|last| last.day != entry.day),
+                        "day {} of {} is registered twice",
+                        entry.day,
+                        entry.year
+                    );
+                    event.days.push(entry);
+                }
+                _ => events.push(Event {
+                    year: entry.year,
+                    days: vec![entry],
+                    solvers: Vec::new(),
+                }),
+            }
+        }
+        for event in &mut events {
+            event.solvers = event
+                .days
+                .iter()
+                .flat_map(|entry| {
+                    entry
+                        .parts
+                        .iter()
+                        .map(|(part, solve)| (entry.day, *part, *solve))
+                })
+                .collect();
+        }
+        events
+    })
+}
+
+/// Returns the event of the given year, if any of its puzzles are implemented.
+///
+/// # Examples
+/// ```
+/// use aoc2025::registry::event;
+///
+/// assert!(event(2025).is_some());
+/// assert!(event(2015).is_none());
+/// ```
+pub fn event(year: i32) -> Option<&'static Event> {
+    events().iter().find(|event| event.year == year)
+}
+
+/// Returns every implemented puzzle of the configured year as `(day, part, solver)`.
+///
+/// The result is empty if no puzzle of the year is implemented.
+pub fn solvers() -> &'static [(i32, i32, Solver)] {
+    current_event().map_or(&[], |event| &event.solvers)
+}
+
+/// Returns the event of the configured year.
+fn current_event() -> Option<&'static Event> {
+    event(current_year())
+}
+
+/// Returns the configured year, or [`YEAR`](crate::api::YEAR) without the
+/// `cli` feature, which reads no configuration.
+fn current_year() -> i32 {
+    #[cfg(feature = "cli")]
+    return config::current().year;
+    #[cfg(not(feature = "cli"))]
+    return crate::api::YEAR;
+}
+
+/// Returns the registered entry of the given day of the configured year.
+fn day_entry(day: i32) -> Option<&'static DayEntry> {
+    current_event()?
+        .days
+        .iter()
+        .find(|entry| entry.day == day)
+        .copied()
+}
+
+/// Returns the solver function for the given day and part, if implemented.
+///
+/// # Examples
+/// ```
+/// use aoc2025::registry::solver;
+///
+/// assert!(solver(1, 1).is_some());
+/// assert!(solver(25, 2).is_none());
+/// ```
+pub fn solver(day: i32, part: i32) -> Option<Solver> {
+    solvers()
+        .iter()
+        .find(|(solver_day, solver_part, _)| *solver_day == day && *solver_part == part)
+        .map(|(_, _, solve)| *solve)
+}
+
+/// Returns the solver of both parts of the given day, if both are implemented.
+pub fn both_parts(day: i32) -> Option<BothSolver> {
+    day_entry(day)?.both_parts
+}
+
+/// Returns the input validator of the given day, if implemented.
+pub fn validator(day: i32) -> Option<Validator> {
+    day_entry(day).map(|entry| entry.validator)
+}
+
+/// Returns the stress-input generator of the given day, if implemented.
+pub fn generator(day: i32) -> Option<Generator> {
+    day_entry(day).map(|entry| entry.generator)
+}
+
+/// Returns the example input from the puzzle description of the given day, if implemented.
+///
+/// # Examples
+/// ```
+/// use aoc2025::registry::{example, example_answer, solver};
+///
+/// let solve = solver(3, 1).unwrap();
+/// assert_eq!(solve(example(3).unwrap()).to_string(), example_answer(3, 1).unwrap());
+/// ```
+pub fn example(day: i32) -> Option<&'static str> {
+    metadata(day).map(|metadata| metadata.example)
+}
+
+/// Returns the answer of the given day and part for the example input, if known.
+pub fn example_answer(day: i32, part: i32) -> Option<&'static str> {
+    metadata(day)?.example_answer(part)
+}
+
+/// Returns the puzzle title, example and complexity notes of the given day, if implemented.
+///
+/// # Examples
+/// ```
+/// use aoc2025::registry::metadata;
+///
+/// assert_eq!(metadata(3).unwrap().title, "Lobby");
+/// assert!(metadata(25).is_none());
+/// ```
+pub fn metadata(day: i32) -> Option<&'static Metadata> {
+    day_entry(day).map(|entry| &entry.metadata)
+}
+
+/// Returns the implemented parts of one day with their solvers, ordered by part.
+pub fn parts(day: i32) -> Vec<(i32, Solver)> {
+    solvers()
+        .iter()
+        .filter(|(solver_day, _, _)| *solver_day == day)
+        .map(|(_, part, solve)| (*part, *solve))
+        .collect()
+}
+
+/// Returns every day with at least one implemented part, grouped by day.
+///
+/// The result can be passed directly to [`run_days`](crate::utils::run_days).
+pub fn days() -> Vec<(i32, Vec<(i32, Solver)>)> {
+    let mut days: Vec<i32> = solvers().iter().map(|(day, _, _)| *day).collect();
+    days.dedup();
+    days.into_iter().map(|day| (day, parts(day))).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::solver::Answer;
+
+    #[test]
+    fn test_solvers_sorted_and_unique() {
+        for pair in solvers().windows(2) {
+            let (day_a, part_a, _) = pair[0];
+            let (day_b, part_b, _) = pair[1];
+            assert!((day_a, part_a) < (day_b, part_b));
+        }
+        for (day, part, _) in solvers() {
+            assert!((1..=25).contains(day));
+            assert!((1..=2).contains(part));
+        }
+    }
+
+    #[test]
+    fn test_events_sorted_and_unique() {
+        for pair in events().windows(2) {
+            assert!(pair[0].year < pair[1].year);
+        }
+        #[cfg(feature = "all-days")]
+        assert_eq!(event(2025).unwrap().days.len(), 6);
+    }
+
+    #[test]
+    fn test_every_day_has_validator() {
+        for (day, _) in days() {
+            assert!(
+                validator(day).is_some(),
+                "day {} has no input validator",
+                day
+            );
+        }
+    }
+
+    #[test]
+    fn test_every_day_has_generator() {
+        for (day, _) in days() {
+            assert!(generator(day).is_some(), "day {} has no generator", day);
+        }
+    }
+
+    #[test]
+    fn test_every_day_has_metadata() {
+        for (day, parts) in days() {
+            let metadata = metadata(day).unwrap();
+            assert!(!metadata.title.is_empty(), "day {} has no title", day);
+            assert!(
+                !metadata.complexity.is_empty(),
+                "day {} has no complexity notes",
+                day
+            );
+            for (part, _) in parts {
+                assert!(
+                    metadata.example_answer(part).is_some(),
+                    "day {} part {} has no example answer",
+                    day,
+                    part
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_examples_solved() {
+        for (day, part, solve) in solvers() {
+            let input = example(*day).unwrap();
+            assert_eq!(
+                Some(solve(input)),
+                example_answer(*day, *part).map(Answer::parse),
+                "example of day {} part {}",
+                day,
+                part
+            );
+        }
+    }
+
+    #[test]
+    fn test_both_parts_solved() {
+        for (day, parts) in days() {
+            let Some(solve) = both_parts(day) else {
+                assert!(parts.len() < 2, "day {} has no solver of both parts", day);
+                continue;
+            };
+            let answers = solve(example(day).unwrap());
+            assert_eq!(
+                Some(answers.part1.0),
+                example_answer(day, 1).map(Answer::parse)
+            );
+            assert_eq!(
+                Some(answers.part2.0),
+                example_answer(day, 2).map(Answer::parse)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "all-days")]
+    fn test_days() {
+        let days = days();
+        assert_eq!(days.len(), 6);
+        assert_eq!(days[4].0, 5);
+        assert_eq!(days[4].1.len(), 1);
+        assert_eq!(
+            days.iter().map(|(_, parts)| parts.len()).sum::<usize>(),
+            solvers().len()
+        );
+    }
+
+    #[test]
+    #[cfg(not(feature = "day01"))]
+    fn test_disabled_day_missing() {
+        assert!(days().iter().all(|(day, _)| *day != 1));
+        assert!(solver(1, 1).is_none());
+        assert!(validator(1).is_none());
+        assert!(example(1).is_none());
+        assert!(parts(1).is_empty());
+    }
+}
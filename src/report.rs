@@ -0,0 +1,282 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use crate::environment::Environment;
+use crate::utils::RunReport;
+
+/// The file formats run reports can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum ExportFormat {
+    /// A GitHub-flavored Markdown table.
+    Markdown,
+    /// Comma-separated values with a header row.
+    Csv,
+    /// A JSON array with one object per run, including the build environment.
+    Json,
+}
+
+impl ExportFormat {
+    /// Guesses the export format from the extension of `path`.
+    ///
+    /// `.csv` files are exported as CSV, `.json` files as JSON, everything
+    /// else as Markdown.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::report::ExportFormat;
+    ///
+    /// assert_eq!(ExportFormat::from_path("times.csv"), ExportFormat::Csv);
+    /// assert_eq!(ExportFormat::from_path("times.json"), ExportFormat::Json);
+    /// assert_eq!(ExportFormat::from_path("times.md"), ExportFormat::Markdown);
+    /// ```
+    pub fn from_path(path: &str) -> ExportFormat {
+        match Path::new(path).extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("csv") => ExportFormat::Csv,
+            Some(ext) if ext.eq_ignore_ascii_case("json") => ExportFormat::Json,
+            _ => ExportFormat::Markdown,
+        }
+    }
+
+    /// Renders the reports in this format.
+    pub fn render(self, reports: &[RunReport]) -> String {
+        match self {
+            ExportFormat::Markdown => to_markdown(reports),
+            ExportFormat::Csv => to_csv(reports),
+            ExportFormat::Json => to_json(reports),
+        }
+    }
+}
+
+/// Renders run reports as a Markdown table.
+///
+/// The table has one row per report with the day, part, answer, and all
+/// timings in milliseconds, followed by a bold row with the summed timings.
+///
+/// # Parameters
+/// - `reports`: The reports to render, in the given order.
+///
+/// # Returns
+/// The Markdown table, terminated by a newline.
+pub fn to_markdown(reports: &[RunReport]) -> String {
+    let mut output = String::new();
+    output.push_str("| Day | Part | Answer | Input read (ms) | Solve (ms) | Total (ms) |\n");
+    output.push_str("|----:|-----:|:-------|----------------:|-----------:|-----------:|\n");
+
+    for report in reports {
+        output.push_str(&format!(
+            "| {} | {} | `{}` | {} | {} | {} |\n",
+            report.day,
+            report.part,
+            report.display_answer().replace('|', "\\|"),
+            ms(report.input_duration),
+            ms(report.solve_duration),
+            ms(report.total_duration()),
+        ));
+    }
+
+    let (input_total, solve_total, total) = totals(reports);
+    output.push_str(&format!(
+        "| | | **Total** | {} | {} | {} |\n",
+        ms(input_total),
+        ms(solve_total),
+        ms(total),
+    ));
+
+    output
+}
+
+/// Renders run reports as CSV.
+///
+/// The first line is a header, followed by one line per report. Answers
+/// containing commas, quotes, or line breaks are quoted.
+///
+/// # Parameters
+/// - `reports`: The reports to render, in the given order.
+///
+/// # Returns
+/// The CSV document, terminated by a newline.
+pub fn to_csv(reports: &[RunReport]) -> String {
+    let mut output = String::new();
+    output.push_str("day,part,answer,input_ms,solve_ms,total_ms\n");
+
+    for report in reports {
+        output.push_str(&format!(
+            "{},{},{},{},{},{}\n",
+            report.day,
+            report.part,
+            csv_field(report.display_answer()),
+            ms(report.input_duration),
+            ms(report.solve_duration),
+            ms(report.total_duration()),
+        ));
+    }
+
+    output
+}
+
+/// One run as written to a JSON export.
+#[derive(Serialize)]
+struct RunRecord<'a> {
+    day: i32,
+    part: i32,
+    answer: &'a str,
+    input_ms: f64,
+    parse_ms: f64,
+    solve_ms: f64,
+    total_ms: f64,
+    environment: Option<&'a Environment>,
+}
+
+/// Renders run reports as JSON.
+///
+/// The document is an array with one object per report holding the day,
+/// part, answer, all timings in milliseconds, and the [`Environment`] the
+/// puzzle was run on, or `null` if unknown.
+///
+/// # Returns
+/// The JSON document, terminated by a newline.
+pub fn to_json(reports: &[RunReport]) -> String {
+    let millis = |duration: Duration| duration.as_secs_f64() * 1000.0;
+    let records: Vec<RunRecord> = reports
+        .iter()
+        .map(|report| RunRecord {
+            day: report.day,
+            part: report.part,
+            answer: report.display_answer(),
+            input_ms: millis(report.input_duration),
+            parse_ms: millis(report.parse_duration),
+            solve_ms: millis(report.solve_duration),
+            total_ms: millis(report.total_duration()),
+            environment: report.environment,
+        })
+        .collect();
+    serde_json::to_string_pretty(&records).expect("reports are always serializable") + "\n"
+}
+
+/// Writes the reports to `path` in the given format.
+///
+/// # Returns
+/// An I/O error if the file could not be written.
+pub fn export(path: &str, format: ExportFormat, reports: &[RunReport]) -> io::Result<()> {
+    fs::write(path, format.render(reports))
+}
+
+/// Quotes a CSV field if it contains characters with special meaning.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Formats a duration as milliseconds with three decimals.
+fn ms(duration: Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1000.0)
+}
+
+/// Sums the input read, solve, and total durations of all reports.
+fn totals(reports: &[RunReport]) -> (Duration, Duration, Duration) {
+    (
+        reports.iter().map(|report| report.input_duration).sum(),
+        reports.iter().map(|report| report.solve_duration).sum(),
+        reports.iter().map(RunReport::total_duration).sum(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<RunReport> {
+        vec![
+            RunReport {
+                day: 1,
+                part: 1,
+                input_path: "inputs/day01.txt".to_string(),
+                answer: "3".to_string(),
+                input_bytes: 0,
+                input_lines: 0,
+                input_hash: 0,
+                input_duration: Duration::from_micros(1500),
+                parse_duration: Duration::ZERO,
+                solve_duration: Duration::from_millis(2),
+                samples: Vec::new(),
+                timed_out: false,
+                cancelled: false,
+                panic: None,
+                allocations: None,
+                peak_rss: None,
+                environment: None,
+            },
+            RunReport {
+                day: 1,
+                part: 2,
+                input_path: "inputs/day01.txt".to_string(),
+                answer: "a,\"b\"".to_string(),
+                input_bytes: 0,
+                input_lines: 0,
+                input_hash: 0,
+                input_duration: Duration::ZERO,
+                parse_duration: Duration::ZERO,
+                solve_duration: Duration::from_millis(4),
+                samples: Vec::new(),
+                timed_out: false,
+                cancelled: false,
+                panic: None,
+                allocations: None,
+                peak_rss: None,
+                environment: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_from_path() {
+        assert_eq!(ExportFormat::from_path("out/report.CSV"), ExportFormat::Csv);
+        assert_eq!(ExportFormat::from_path("report.md"), ExportFormat::Markdown);
+        assert_eq!(ExportFormat::from_path("report"), ExportFormat::Markdown);
+        assert_eq!(ExportFormat::from_path("report.Json"), ExportFormat::Json);
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(&reports()),
+            "| Day | Part | Answer | Input read (ms) | Solve (ms) | Total (ms) |\n\
+             |----:|-----:|:-------|----------------:|-----------:|-----------:|\n\
+             | 1 | 1 | `3` | 1.500 | 2.000 | 3.500 |\n\
+             | 1 | 2 | `a,\"b\"` | 0.000 | 4.000 | 4.000 |\n\
+             | | | **Total** | 1.500 | 6.000 | 7.500 |\n"
+        );
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&reports()),
+            "day,part,answer,input_ms,solve_ms,total_ms\n\
+             1,1,3,1.500,2.000,3.500\n\
+             1,2,\"a,\"\"b\"\"\",0.000,4.000,4.000\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let mut reports = reports();
+        reports[0].environment = Some(Environment::current());
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&reports)).unwrap();
+        assert_eq!(json[0]["answer"], "3");
+        assert_eq!(json[0]["total_ms"], 3.5);
+        assert_eq!(
+            json[0]["environment"]["rustc"],
+            Environment::current().rustc.as_str()
+        );
+        assert_eq!(json[1]["environment"], serde_json::Value::Null);
+    }
+}
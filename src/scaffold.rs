@@ -0,0 +1,345 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use tracing::info;
+
+use crate::config::Config;
+use crate::fetch;
+
+/// The template of `src/dayXX/mod.rs`.
+const MOD_TEMPLATE: &str = r#"use crate::check::{Diagnostic, check_lines};
+use crate::solver::{Answer, Metadata, Part2, Solver};
+
+pub mod part1;
+pub mod part2;
+
+/// The solution of day {day}.
+pub struct Day{day02};
+
+impl Solver for Day{day02} {
+    /// The lines of the input.
+    type Parsed = Vec<String>;
+
+    const METADATA: Metadata = Metadata {
+        title: "TODO",
+        example: "TODO: paste the example input",
+        example_answers: [None, None],
+        complexity: "TODO",
+    };
+
+    /// Parses the input into its lines.
+    fn parse(input: &str) -> Result<Vec<String>, Diagnostic> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+        validate(input)
+    }
+
+    fn part1(lines: &Vec<String>) -> Answer {
+        part1::solve(lines)
+    }
+}
+
+impl Part2 for Day{day02} {
+    fn part2(lines: &Vec<String>) -> Answer {
+        part2::solve(lines)
+    }
+}
+
+crate::register_day!(
+    year: {year},
+    day: {day},
+    solver: Day{day02},
+    parts: [1, 2],
+    generator: crate::generators::day{day02},
+);
+
+/// Validates that the input can be parsed by [`Day{day02}::parse`].
+pub fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+    check_lines(input, |_line| None)
+}
+"#;
+
+/// The template of `src/dayXX/part1.rs` and `src/dayXX/part2.rs`.
+const PART_TEMPLATE: &str = r#"use crate::solver::Answer;
+
+/// Solves Day {day02} Part {part} puzzle.
+///
+/// # Parameters
+/// - `_lines`: The lines parsed by [`Day{day02}`](super::Day{day02}).
+///
+/// # Returns
+/// An [`Answer`] holding the solution.
+pub fn solve(_lines: &[String]) -> Answer {
+    todo!("day {day} part {part} is not solved yet")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::day{day02}::Day{day02};
+    use crate::solver::Solver;
+
+    #[test]
+    fn test_solve() {
+        let result = solve(&Day{day02}::parse(Day{day02}::METADATA.example).unwrap());
+        assert_eq!(
+            Some(result.to_string().as_str()),
+            Day{day02}::METADATA.example_answer({part})
+        );
+    }
+}
+"#;
+
+/// The template of the stress-input generator appended to `src/generators.rs`.
+const GENERATOR_TEMPLATE: &str = r#"/// Generates `size` copies of the example input of day {day}.
+///
+/// A placeholder until the input format of the day is known.
+#[cfg(feature = "day{day02}")]
+pub fn day{day02}(size: usize, _seed: u64) -> String {
+    let example = <crate::day{day02}::Day{day02} as crate::solver::Solver>::METADATA.example;
+    vec![example; size.max(1)].join("\n")
+}
+"#;
+
+/// Creates the skeleton of a new day below the crate root `root`.
+///
+/// Writes `src/dayXX/{mod.rs,part1.rs,part2.rs}` from templates, declares the
+/// module in `src/lib.rs` behind a new `dayXX` feature in `Cargo.toml`, which
+/// `all-days` enables, adds a placeholder generator to `src/generators.rs`
+/// and creates an empty input file at [`fetch::input_path`], unless it
+/// already exists. The day is registered for the configured year with both
+/// parts; their solvers panic with `todo!` and their example tests fail
+/// until the example and its answers are filled in.
+///
+/// # Returns
+/// The created or changed files, or an error if the day is not part of the
+/// event, its module already exists, or a file could not be written.
+pub fn new_day(root: &Path, config: &Config, day: i32) -> io::Result<Vec<PathBuf>> {
+    if !(1..=25).contains(&day) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("Day {} is not part of the event", day),
+        ));
+    }
+    let module_dir = root.join(format!("src/day{:02}", day));
+    if module_dir.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("'{}' already exists", module_dir.display()),
+        ));
+    }
+    let manifest_path = root.join("Cargo.toml");
+    let manifest = add_feature(&fs::read_to_string(&manifest_path)?, day);
+    let lib_path = root.join("src/lib.rs");
+    let lib = declare_module(&fs::read_to_string(&lib_path)?, day);
+    let generators_path = root.join("src/generators.rs");
+    let generators = add_generator(&fs::read_to_string(&generators_path)?, day);
+
+    fs::create_dir_all(&module_dir)?;
+    let mut written: Vec<PathBuf> = Vec::new();
+    let mut write = |path: PathBuf, contents: &str| -> io::Result<()> {
+        fs::write(&path, contents)?;
+        info!(path = %path.display(), "written");
+        written.push(path);
+        Ok(())
+    };
+    write(
+        module_dir.join("mod.rs"),
+        &render(MOD_TEMPLATE, config.year, day, 0),
+    )?;
+    for part in 1..=2 {
+        write(
+            module_dir.join(format!("part{}.rs", part)),
+            &render(PART_TEMPLATE, config.year, day, part),
+        )?;
+    }
+    write(manifest_path, &manifest)?;
+    write(lib_path, &lib)?;
+    write(generators_path, &generators)?;
+
+    let input_path = root.join(fetch::input_path(config, day));
+    if !input_path.exists() {
+        if let Some(parent) = input_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write(input_path, "")?;
+    }
+    Ok(written)
+}
+
+/// Fills the placeholders `{year}`, `{day}`, `{day02}` and `{part}` of a template.
+fn render(template: &str, year: i32, day: i32, part: i32) -> String {
+    template
+        .replace("{year}", &year.to_string())
+        .replace("{day02}", &format!("{:02}", day))
+        .replace("{day}", &day.to_string())
+        .replace("{part}", &part.to_string())
+}
+
+/// Inserts `pub mod dayXX;` behind its feature into the source of `lib.rs`,
+/// keeping the modules sorted.
+///
+/// The declaration goes before the first module sorting after it, together
+/// with the attributes above that module, or after the last module.
+fn declare_module(lib: &str, day: i32) -> String {
+    let name = format!("day{:02}", day);
+    let mut lines: Vec<&str> = lib.lines().collect();
+    let module = |line: &str| {
+        line.strip_prefix("pub mod ")
+            .and_then(|rest| rest.strip_suffix(';'))
+            .map(str::to_string)
+    };
+
+    let next = lines
+        .iter()
+        .position(|line| module(line).is_some_and(|module| module > name));
+    let index = match next {
+        Some(mut index) => {
+            while index > 0 && lines[index - 1].starts_with("#[") {
+                index -= 1;
+            }
+            index
+        }
+        None => lines
+            .iter()
+            .rposition(|line| module(line).is_some())
+            .map_or(lines.len(), |index| index + 1),
+    };
+    let declaration = format!("#[cfg(feature = \"{}\")]\npub mod {};", name, name);
+    lines.insert(index, &declaration);
+    lines.join("\n") + "\n"
+}
+
+/// Adds the feature `dayXX = []` to the source of `Cargo.toml`, after the
+/// features of the other days, and enables it in `all-days`.
+fn add_feature(manifest: &str, day: i32) -> String {
+    let name = format!("day{:02}", day);
+    let mut lines: Vec<String> = manifest.lines().map(String::from).collect();
+    let all_days = lines
+        .iter()
+        .position(|line| line.starts_with("all-days = ["));
+    if let Some(index) = all_days {
+        let list = &lines[index];
+        lines[index] = match list.strip_suffix("[]") {
+            Some(start) => format!("{}[\"{}\"]", start, name),
+            None => list.replacen(']', &format!(", \"{}\"]", name), 1),
+        };
+    }
+
+    let index = lines
+        .iter()
+        .rposition(|line| line.starts_with("day") && line.ends_with(" = []"))
+        .or(all_days)
+        .map_or(lines.len(), |index| index + 1);
+    lines.insert(index, format!("{} = []", name));
+    lines.join("\n") + "\n"
+}
+
+/// Inserts the placeholder generator of a day into the source of
+/// `generators.rs`, before its tests.
+fn add_generator(generators: &str, day: i32) -> String {
+    let generator = render(GENERATOR_TEMPLATE, 0, day, 0);
+    match generators.find("\n#[cfg(test)]") {
+        Some(index) => format!(
+            "{}\n{}{}",
+            &generators[..index],
+            generator,
+            &generators[index..]
+        ),
+        None => format!("{}\n{}", generators, generator),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render() {
+        let rendered = render("Day{day02} day {day} part {part} of {year}", 2025, 7, 2);
+        assert_eq!(rendered, "Day07 day 7 part 2 of 2025");
+    }
+
+    #[test]
+    fn test_declare_module() {
+        let lib = "pub mod cancel;\npub mod day06;\n#[cfg(feature = \"cli\")]\npub mod fetch;\n";
+        assert_eq!(
+            declare_module(lib, 7),
+            "pub mod cancel;\npub mod day06;\n#[cfg(feature = \"day07\")]\npub mod day07;\n\
+             #[cfg(feature = \"cli\")]\npub mod fetch;\n"
+        );
+        assert_eq!(
+            declare_module("pub mod cancel;\n\npub use x;\n", 7),
+            "pub mod cancel;\n#[cfg(feature = \"day07\")]\npub mod day07;\n\npub use x;\n"
+        );
+    }
+
+    #[test]
+    fn test_add_feature() {
+        let manifest =
+            "[features]\nall-days = [\"day01\", \"day06\"]\nday01 = []\nday06 = []\nwasm = []\n";
+        assert_eq!(
+            add_feature(manifest, 7),
+            "[features]\nall-days = [\"day01\", \"day06\", \"day07\"]\nday01 = []\nday06 = []\n\
+             day07 = []\nwasm = []\n"
+        );
+        assert_eq!(
+            add_feature("[features]\nall-days = []\n", 1),
+            "[features]\nall-days = [\"day01\"]\nday01 = []\n"
+        );
+    }
+
+    #[test]
+    fn test_add_generator() {
+        let generators = "pub fn day06() {}\n\n#[cfg(test)]\nmod tests {}\n";
+        let result = add_generator(generators, 7);
+        assert!(result.starts_with("pub fn day06() {}\n\n/// Generates `size` copies"));
+        assert!(result.contains("pub fn day07(size: usize, _seed: u64) -> String {"));
+        assert!(result.ends_with("}\n\n#[cfg(test)]\nmod tests {}\n"));
+    }
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(root.join("Cargo.toml"), "[features]\nall-days = []\n").unwrap();
+        fs::write(root.join("src/lib.rs"), "pub mod day06;\n").unwrap();
+        fs::write(root.join("src/generators.rs"), "pub fn day06() {}\n").unwrap();
+        let config = Config {
+            input_dir: root.join("inputs").display().to_string(),
+            ..Config::default()
+        };
+
+        let written = new_day(&root, &config, 7).unwrap();
+        assert_eq!(written.len(), 7);
+        let module = fs::read_to_string(root.join("src/day07/mod.rs")).unwrap();
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("    year: 2025,\n    day: 7,\n"));
+        let part2 = fs::read_to_string(root.join("src/day07/part2.rs")).unwrap();
+        assert!(part2.contains("Day07::METADATA.example_answer(2)"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            "pub mod day06;\n#[cfg(feature = \"day07\")]\npub mod day07;\n"
+        );
+        assert!(
+            fs::read_to_string(root.join("Cargo.toml"))
+                .unwrap()
+                .ends_with("all-days = [\"day07\"]\nday07 = []\n")
+        );
+        assert_eq!(
+            fs::read_to_string(fetch::input_path(&config, 7)).unwrap(),
+            ""
+        );
+
+        let err = new_day(&root, &config, 7).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(
+            new_day(&root, &config, 26).unwrap_err().kind(),
+            io::ErrorKind::InvalidInput
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
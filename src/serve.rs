@@ -0,0 +1,225 @@
+use std::io::{self, Read};
+use std::panic::{self, AssertUnwindSafe};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tracing::{debug, info, warn};
+
+use crate::registry;
+
+/// The largest request body accepted as puzzle input, 64 MiB.
+pub const MAX_INPUT_BYTES: u64 = 64 * 1024 * 1024;
+
+/// The HTTP response to one request, before it is sent.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+    /// The HTTP status code, e.g. `200`.
+    pub status: u16,
+    /// The JSON document sent as body.
+    pub body: String,
+}
+
+/// The body of a successful `POST /solve/{day}/{part}`.
+#[derive(Debug, Serialize)]
+struct Solved {
+    day: i32,
+    part: i32,
+    answer: String,
+    timings: Timings,
+}
+
+/// How long validating and solving the input took, in milliseconds.
+#[derive(Debug, Serialize)]
+struct Timings {
+    validate_ms: f64,
+    solve_ms: f64,
+    total_ms: f64,
+}
+
+/// The body of every failed request.
+#[derive(Debug, Serialize)]
+struct Failure {
+    error: String,
+}
+
+impl Response {
+    /// Creates a response with the given status and JSON body.
+    fn json(status: u16, body: &impl Serialize) -> Response {
+        let body = serde_json::to_string(body).expect("responses are always serializable");
+        Response { status, body }
+    }
+
+    /// Creates an error response whose body is `{"error": message}`.
+    fn error(status: u16, message: impl Into<String>) -> Response {
+        Response::json(
+            status,
+            &Failure {
+                error: message.into(),
+            },
+        )
+    }
+}
+
+/// Answers one request, independent of the HTTP transport.
+///
+/// `POST /solve/{day}/{part}` validates the body as puzzle input of the
+/// configured year and solves it. Unknown paths yield `404`, other methods
+/// on a solve path `405`, unknown puzzles `404`, malformed input `400` and a
+/// panicking solver `500`.
+///
+/// # Returns
+/// The response with its status and JSON body.
+///
+/// # Examples
+/// ```
+/// use aoc2025::serve::handle;
+///
+/// let response = handle("POST", "/solve/1/1", "R5\nL55");
+/// assert_eq!(response.status, 200);
+/// assert!(response.body.contains(r#""answer":"1""#));
+/// ```
+pub fn handle(method: &str, path: &str, body: &str) -> Response {
+    let Some((day, part)) = parse_solve_path(path) else {
+        return Response::error(404, format!("unknown path '{}'", path));
+    };
+    if method != "POST" {
+        return Response::error(405, "use POST with the puzzle input as body");
+    }
+    let (Some(validate), Some(solve)) = (registry::validator(day), registry::solver(day, part))
+    else {
+        return Response::error(404, format!("day {} part {} is not implemented", day, part));
+    };
+
+    let start = Instant::now();
+    if let Err(diagnostics) = validate(body) {
+        let problems: Vec<String> = diagnostics.iter().map(ToString::to_string).collect();
+        return Response::error(400, format!("malformed input: {}", problems.join("; ")));
+    }
+    let validate_duration = start.elapsed();
+
+    let solve_start = Instant::now();
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| solve(body))) {
+        Ok(answer) => answer,
+        Err(_) => return Response::error(500, "the solver panicked"),
+    };
+    let solve_duration = solve_start.elapsed();
+
+    Response::json(
+        200,
+        &Solved {
+            day,
+            part,
+            answer: answer.to_string(),
+            timings: Timings {
+                validate_ms: ms(validate_duration),
+                solve_ms: ms(solve_duration),
+                total_ms: ms(validate_duration + solve_duration),
+            },
+        },
+    )
+}
+
+/// Serves the solve endpoint on `host:port` until the process is stopped.
+///
+/// Every request is answered on its own thread with [`handle`], so a slow
+/// solver does not block other requests. Bodies larger than
+/// [`MAX_INPUT_BYTES`] or not in UTF-8 are rejected with `413` and `400`.
+///
+/// # Returns
+/// An I/O error if the address cannot be bound.
+pub fn serve(host: &str, port: u16) -> io::Result<()> {
+    let server = tiny_http::Server::http((host, port)).map_err(io::Error::other)?;
+    info!(host, port, "serving");
+    println!("Listening on http://{}:{}", host, port);
+
+    for request in server.incoming_requests() {
+        thread::spawn(move || respond(request));
+    }
+    Ok(())
+}
+
+/// Reads the body of a request, answers it with [`handle`] and sends the response.
+fn respond(mut request: tiny_http::Request) {
+    let method = request.method().as_str().to_string();
+    let path = request.url().to_string();
+
+    let mut body = String::new();
+    let read = request
+        .as_reader()
+        .take(MAX_INPUT_BYTES + 1)
+        .read_to_string(&mut body);
+    let response = match read {
+        Err(err) => Response::error(400, format!("the body is not valid UTF-8: {}", err)),
+        Ok(length) if length as u64 > MAX_INPUT_BYTES => {
+            Response::error(413, format!("the input exceeds {} bytes", MAX_INPUT_BYTES))
+        }
+        Ok(_) => handle(&method, &path, &body),
+    };
+    debug!(method, path, status = response.status, "answered");
+
+    let content_type = tiny_http::Header::from_bytes("Content-Type", "application/json")
+        .expect("the header is valid");
+    let result = request.respond(
+        tiny_http::Response::from_string(response.body)
+            .with_status_code(response.status)
+            .with_header(content_type),
+    );
+    if let Err(err) = result {
+        warn!(method, path, %err, "could not send the response");
+    }
+}
+
+/// Parses a path like `"/solve/3/2"` into `(day, part)`.
+fn parse_solve_path(path: &str) -> Option<(i32, i32)> {
+    let (day, part) = path.strip_prefix("/solve/")?.split_once('/')?;
+    Some((day.parse().ok()?, part.parse().ok()?))
+}
+
+/// Converts a duration to fractional milliseconds.
+fn ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_solve_path() {
+        assert_eq!(parse_solve_path("/solve/3/2"), Some((3, 2)));
+        assert_eq!(parse_solve_path("/solve/3"), None);
+        assert_eq!(parse_solve_path("/solve/x/1"), None);
+        assert_eq!(parse_solve_path("/puzzles"), None);
+    }
+
+    #[test]
+    #[cfg(feature = "day06")]
+    fn test_handle_solves_example() {
+        let input = registry::example(6).unwrap();
+        let response = handle("POST", "/solve/6/2", input);
+        assert_eq!(response.status, 200);
+
+        let json: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert_eq!(json["day"], 6);
+        assert_eq!(json["part"], 2);
+        assert_eq!(json["answer"], registry::example_answer(6, 2).unwrap());
+        assert!(json["timings"]["solve_ms"].as_f64().unwrap() >= 0.0);
+        assert!(json["timings"]["total_ms"].as_f64().is_some());
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn test_handle_errors() {
+        let status = |method, path, body| handle(method, path, body).status;
+        assert_eq!(status("POST", "/solve/1/1", "forward 5"), 400);
+        assert_eq!(status("POST", "/solve/25/1", ""), 404);
+        assert_eq!(status("POST", "/solve/5/2", ""), 404);
+        assert_eq!(status("GET", "/solve/1/1", ""), 405);
+        assert_eq!(status("POST", "/", ""), 404);
+
+        let response = handle("POST", "/solve/1/1", "X7");
+        let json: serde_json::Value = serde_json::from_str(&response.body).unwrap();
+        assert!(json["error"].as_str().unwrap().contains("line 1"));
+    }
+}
@@ -0,0 +1,673 @@
+use std::cell::Cell;
+use std::fmt;
+use std::io;
+use std::time::{Duration, Instant};
+
+use crate::cancel::{self, Cancelled};
+use crate::check::Diagnostic;
+
+thread_local! {
+    /// The time spent in [`Solver::parse`] by [`solve_part1`] and
+    /// [`solve_part2`] inside the innermost [`measure_parse`] on this thread.
+    static PARSE_DURATION: Cell<Option<Duration>> = const { Cell::new(None) };
+}
+
+/// The answer of a puzzle part, as printed by the runner and compared with
+/// recorded answers.
+///
+/// Numeric answers are compared by value regardless of how they were
+/// produced, so `Answer::I64(42)`, `Answer::U64(42)` and the text `"042"`
+/// are all equal. Other text answers are compared without surrounding
+/// whitespace.
+///
+/// # Examples
+/// ```
+/// use aoc2025::solver::Answer;
+///
+/// assert_eq!(Answer::from(42_u64), Answer::parse(" 042\n"));
+/// assert_eq!(Answer::from(-7_i64).to_string(), "-7");
+/// assert_eq!(Answer::from(u128::MAX), Answer::parse("340282366920938463463374607431768211455"));
+/// assert_ne!(Answer::from("ABC"), Answer::from("abc"));
+/// ```
+#[derive(Debug, Clone)]
+pub enum Answer {
+    /// A signed integer.
+    I64(i64),
+    /// An unsigned integer too large for [`Answer::I64`].
+    U64(u64),
+    /// An integer too large for 64 bits, as its decimal digits with an
+    /// optional leading `'-'` and without leading zeros.
+    BigInt(String),
+    /// Any answer that is not a number, e.g. letters read from a display.
+    Text(String),
+}
+
+impl Answer {
+    /// Parses an answer as printed or recorded, e.g. in `answers.toml`.
+    ///
+    /// Integers of any size become numeric answers, everything else is kept
+    /// as [`Answer::Text`]. Surrounding whitespace is ignored.
+    pub fn parse(text: &str) -> Answer {
+        let text = text.trim();
+        if let Ok(number) = text.parse::<i64>() {
+            return Answer::I64(number);
+        }
+        if let Ok(number) = text.parse::<u64>() {
+            return Answer::U64(number);
+        }
+
+        let (sign, digits) = match text.strip_prefix('-') {
+            Some(digits) => ("-", digits),
+            None => ("", text.strip_prefix('+').unwrap_or(text)),
+        };
+        if digits.is_empty() || !digits.bytes().all(|byte| byte.is_ascii_digit()) {
+            return Answer::Text(text.to_string());
+        }
+        // Smaller numbers with leading zeros were parsed above, so this is never zero.
+        Answer::BigInt(format!("{}{}", sign, digits.trim_start_matches('0')))
+    }
+
+    /// Returns the form answers are compared in: the numeric value for
+    /// numbers (also when given as text) and the trimmed text otherwise.
+    fn canonical(&self) -> Answer {
+        let answer = match self {
+            Answer::Text(text) => Answer::parse(text),
+            other => other.clone(),
+        };
+        match answer {
+            Answer::I64(number) if number >= 0 => Answer::U64(number as u64),
+            other => other,
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::I64(number) => write!(f, "{}", number),
+            Answer::U64(number) => write!(f, "{}", number),
+            Answer::BigInt(digits) => f.write_str(digits),
+            Answer::Text(text) => f.write_str(text),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Answer) -> bool {
+        match (self.canonical(), other.canonical()) {
+            (Answer::I64(a), Answer::I64(b)) => a == b,
+            (Answer::U64(a), Answer::U64(b)) => a == b,
+            (Answer::BigInt(a), Answer::BigInt(b)) => a == b,
+            (Answer::Text(a), Answer::Text(b)) => a == b,
+            _ => false,
+        }
+    }
+}
+
+impl Eq for Answer {}
+
+impl From<i64> for Answer {
+    fn from(number: i64) -> Answer {
+        Answer::I64(number)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(number: i32) -> Answer {
+        Answer::I64(number.into())
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(number: u64) -> Answer {
+        i64::try_from(number).map_or(Answer::U64(number), Answer::I64)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(number: u32) -> Answer {
+        Answer::I64(number.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(number: usize) -> Answer {
+        Answer::from(number as u64)
+    }
+}
+
+impl From<i128> for Answer {
+    fn from(number: i128) -> Answer {
+        if let Ok(number) = i64::try_from(number) {
+            Answer::I64(number)
+        } else if let Ok(number) = u64::try_from(number) {
+            Answer::U64(number)
+        } else {
+            Answer::BigInt(number.to_string())
+        }
+    }
+}
+
+impl From<u128> for Answer {
+    fn from(number: u128) -> Answer {
+        u64::try_from(number).map_or(Answer::BigInt(number.to_string()), Answer::from)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Answer {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Answer {
+        Answer::Text(text.to_string())
+    }
+}
+
+/// The solution of one day, split into parsing the input and solving each part.
+///
+/// Both parts of a day share the input format, so it is parsed once by
+/// [`Solver::parse`] and the parts only work on the parsed form. Part 2 is
+/// solved by the separate [`Part2`] trait, which a day implements once it is
+/// solved. Days are registered with [`register_day!`](crate::register_day),
+/// which wires up the parts as plain [`utils::Solver`](crate::utils::Solver)
+/// functions through [`solve_part1`] and [`solve_part2`].
+///
+/// # Examples
+/// ```
+/// use aoc2025::check::Diagnostic;
+/// use aoc2025::solver::{Answer, Metadata, Part2, Solver, solve_part2};
+///
+/// struct Sum;
+///
+/// impl Solver for Sum {
+///     type Parsed = Vec<i64>;
+///
+///     const METADATA: Metadata = Metadata {
+///         title: "Sum and Product",
+///         example: "2\n3\n7",
+///         example_answers: [Some("12"), Some("42")],
+///         complexity: "One pass over the numbers: O(n).",
+///     };
+///
+///     fn parse(input: &str) -> Result<Vec<i64>, Diagnostic> {
+///         input
+///             .lines()
+///             .enumerate()
+///             .map(|(index, line)| {
+///                 line.parse()
+///                     .map_err(|_| Diagnostic::at(index + 1, format!("expected a number, found '{}'", line)))
+///             })
+///             .collect()
+///     }
+///
+///     fn part1(numbers: &Vec<i64>) -> Answer {
+///         numbers.iter().sum::<i64>().into()
+///     }
+/// }
+///
+/// impl Part2 for Sum {
+///     fn part2(numbers: &Vec<i64>) -> Answer {
+///         numbers.iter().product::<i64>().into()
+///     }
+/// }
+///
+/// assert_eq!(solve_part2::<Sum>("2\n3\n7"), Answer::I64(42));
+/// ```
+pub trait Solver {
+    /// The input in the form both parts work on.
+    type Parsed;
+
+    /// The puzzle title, example and complexity notes of the day.
+    const METADATA: Metadata;
+
+    /// Parses the puzzle input.
+    ///
+    /// # Returns
+    /// The parsed input, or a [`Diagnostic`] locating the first part of the
+    /// input that cannot be parsed. Parsing never panics, even on input that
+    /// was not checked by [`Solver::validate`].
+    fn parse(input: &str) -> Result<Self::Parsed, Diagnostic>;
+
+    /// Checks that [`Solver::parse`] can parse the input, returning every
+    /// problem found in input order.
+    ///
+    /// An input passing this check must not make [`Solver::parse`] fail or
+    /// either part panic, so the fallible API is safe to use on untrusted input.
+    ///
+    /// Used by the fallible [`try_solve_part1`] and friends. Accepts any
+    /// input by default.
+    fn validate(input: &str) -> Result<(), Vec<Diagnostic>> {
+        let _ = input;
+        Ok(())
+    }
+
+    /// Solves part 1 on the parsed input.
+    fn part1(parsed: &Self::Parsed) -> Answer;
+}
+
+/// Part 2 of a day whose part 2 is solved.
+///
+/// Keeping it apart from [`Solver`] makes an unsolved part 2 a compile error
+/// instead of a panic: [`solve_part2`], [`solve_both`] and the other helpers
+/// solving part 2 require it, and [`register_day!`](crate::register_day)
+/// only accepts `parts: [1, 2]` for days implementing it.
+pub trait Part2: Solver {
+    /// Solves part 2 on the parsed input.
+    fn part2(parsed: &Self::Parsed) -> Answer;
+}
+
+/// What a day declares about its puzzle, see [`Solver::METADATA`].
+///
+/// The registry hands it out through [`registry::metadata`](crate::registry::metadata),
+/// so the command line interface and the tests need no copies of titles or
+/// examples.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::Day01;
+/// use aoc2025::solver::{Solver, solve_part1};
+///
+/// let metadata = Day01::METADATA;
+/// assert_eq!(metadata.title, "Secret Entrance");
+/// assert_eq!(solve_part1::<Day01>(metadata.example).to_string(), metadata.example_answer(1).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Metadata {
+    /// The puzzle title without the day, e.g. `"Lobby"`.
+    pub title: &'static str,
+    /// The example input from the puzzle description.
+    pub example: &'static str,
+    /// The answers of part 1 and part 2 for [`Metadata::example`], `None` for unsolved parts.
+    pub example_answers: [Option<&'static str>; 2],
+    /// A rough note on the running time of the solvers, e.g. `"O(n) in the input size"`.
+    pub complexity: &'static str,
+}
+
+impl Metadata {
+    /// Returns the answer of a part for [`Metadata::example`], or `None` for unsolved or unknown parts.
+    pub const fn example_answer(&self, part: i32) -> Option<&'static str> {
+        match part {
+            1 => self.example_answers[0],
+            2 => self.example_answers[1],
+            _ => None,
+        }
+    }
+}
+
+/// The error of solving a puzzle with the fallible API, e.g. [`try_solve_part1`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    /// The input cannot be parsed; carries every problem found, in input order.
+    InvalidInput(Vec<Diagnostic>),
+    /// The solver stopped early because the [global cancel token](cancel::global)
+    /// was cancelled, so it has no answer.
+    Cancelled,
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SolveError::InvalidInput(diagnostics) => {
+                write!(f, "malformed input")?;
+                if let Some(first) = diagnostics.first() {
+                    write!(f, ": {}", first)?;
+                }
+                if diagnostics.len() > 1 {
+                    write!(f, " (and {} more problems)", diagnostics.len() - 1)?;
+                }
+                Ok(())
+            }
+            SolveError::Cancelled => write!(f, "{}", Cancelled),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<Cancelled> for SolveError {
+    fn from(_: Cancelled) -> SolveError {
+        SolveError::Cancelled
+    }
+}
+
+impl From<SolveError> for io::Error {
+    fn from(err: SolveError) -> io::Error {
+        let kind = match err {
+            SolveError::InvalidInput(_) => io::ErrorKind::InvalidData,
+            SolveError::Cancelled => io::ErrorKind::Interrupted,
+        };
+        io::Error::new(kind, err)
+    }
+}
+
+/// Validates and parses the input of the day `S`.
+fn try_parse<S: Solver>(input: &str) -> Result<S::Parsed, SolveError> {
+    S::validate(input).map_err(SolveError::InvalidInput)?;
+    S::parse(input).map_err(|diagnostic| SolveError::InvalidInput(vec![diagnostic]))
+}
+
+/// Passes on the answers of a solver unless the [global cancel
+/// token](cancel::global) was cancelled, which stops the solvers checking it
+/// before they have an answer.
+///
+/// # Returns
+/// The answers, or [`SolveError::Cancelled`].
+fn uncancelled<T>(answers: T) -> Result<T, SolveError> {
+    cancel::global().check()?;
+    Ok(answers)
+}
+
+/// Parses the input of the day `S` for the panicking API, e.g. [`solve_part1`].
+///
+/// # Panics
+/// Panics with the [`Diagnostic`] if the input cannot be parsed.
+fn parse<S: Solver>(input: &str) -> S::Parsed {
+    S::parse(input).unwrap_or_else(|diagnostic| panic!("malformed input: {}", diagnostic))
+}
+
+/// Parses the input of the day `S` like [`parse`], adding the time it took
+/// to the innermost [`measure_parse`].
+fn timed_parse<S: Solver>(input: &str) -> S::Parsed {
+    let start = Instant::now();
+    let parsed = parse::<S>(input);
+    let elapsed = start.elapsed();
+    PARSE_DURATION.with(|duration| {
+        duration.set(Some(duration.get().unwrap_or_default() + elapsed));
+    });
+    parsed
+}
+
+/// Runs `function` and measures how much of its time was spent parsing.
+///
+/// Solvers registered with [`register_day!`](crate::register_day), i.e.
+/// [`solve_part1`] and [`solve_part2`], record how long [`Solver::parse`]
+/// took, so the runners can report parsing and solving separately.
+///
+/// # Returns
+/// The result of `function` and the time spent parsing, or `None` if
+/// `function` did not call a solver that records it.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::Day01;
+/// use aoc2025::solver::{Answer, measure_parse, solve_part1};
+///
+/// let (answer, parse_duration) = measure_parse(|| solve_part1::<Day01>("R5\nL55"));
+/// assert_eq!(answer, Answer::I64(1));
+/// assert!(parse_duration.is_some());
+///
+/// let (_, parse_duration) = measure_parse(|| Answer::from(42));
+/// assert_eq!(parse_duration, None);
+/// ```
+pub fn measure_parse<T>(function: impl FnOnce() -> T) -> (T, Option<Duration>) {
+    let outer = PARSE_DURATION.with(|duration| duration.replace(None));
+    let result = function();
+    let parse_duration = PARSE_DURATION.with(|duration| duration.replace(outer));
+    (result, parse_duration)
+}
+
+/// Parses the input and solves part 1 of the day `S`.
+///
+/// # Panics
+/// Panics if the input is malformed, see [`try_solve_part1`] for the fallible version.
+pub fn solve_part1<S: Solver>(input: &str) -> Answer {
+    S::part1(&timed_parse::<S>(input))
+}
+
+/// Parses the input and solves part 2 of the day `S`.
+///
+/// # Panics
+/// Panics if the input is malformed, see [`try_solve_part2`] for the fallible version.
+pub fn solve_part2<S: Part2>(input: &str) -> Answer {
+    S::part2(&timed_parse::<S>(input))
+}
+
+/// Validates the input and solves part 1 of the day `S`.
+///
+/// # Returns
+/// The answer, [`SolveError::InvalidInput`] listing the problems of a
+/// malformed input instead of panicking on it, or [`SolveError::Cancelled`]
+/// if the [global cancel token](cancel::global) stopped the solver.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::Day01;
+/// use aoc2025::solver::{Answer, SolveError, try_solve_part1};
+///
+/// assert_eq!(try_solve_part1::<Day01>("R5\nL55"), Ok(Answer::I64(1)));
+///
+/// let err = try_solve_part1::<Day01>("R5\nX7").unwrap_err();
+/// assert!(matches!(err, SolveError::InvalidInput(_)));
+/// assert_eq!(
+///     err.to_string(),
+///     "malformed input: line 2, column 1: expected a rotation like 'R5' or 'L12', found 'X7'"
+/// );
+/// ```
+pub fn try_solve_part1<S: Solver>(input: &str) -> Result<Answer, SolveError> {
+    try_parse::<S>(input).and_then(|parsed| uncancelled(S::part1(&parsed)))
+}
+
+/// Validates the input and solves part 2 of the day `S`, like [`try_solve_part1`].
+pub fn try_solve_part2<S: Part2>(input: &str) -> Result<Answer, SolveError> {
+    try_parse::<S>(input).and_then(|parsed| uncancelled(S::part2(&parsed)))
+}
+
+/// Parses the input once and solves both parts of the day `S`.
+///
+/// # Returns
+/// The answers of part 1 and part 2.
+///
+/// # Panics
+/// Panics if the input is malformed, see [`try_solve_both`] for the fallible version.
+///
+/// # Examples
+/// ```
+/// use aoc2025::day01::Day01;
+/// use aoc2025::solver::{Answer, Solver, solve_both};
+///
+/// assert_eq!(solve_both::<Day01>(Day01::METADATA.example), (Answer::I64(3), Answer::I64(6)));
+/// ```
+pub fn solve_both<S: Part2>(input: &str) -> (Answer, Answer) {
+    let parsed = parse::<S>(input);
+    (S::part1(&parsed), S::part2(&parsed))
+}
+
+/// Validates the input once and solves both parts of the day `S`, like [`try_solve_part1`].
+pub fn try_solve_both<S: Part2>(input: &str) -> Result<(Answer, Answer), SolveError> {
+    try_parse::<S>(input).and_then(|parsed| uncancelled((S::part1(&parsed), S::part2(&parsed))))
+}
+
+/// The answers of both parts of a day with the time every step took, see [`solve_both_timed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimedAnswers {
+    /// How long parsing the input took.
+    pub parse_duration: Duration,
+    /// The answer of part 1 and how long solving it on the parsed input took.
+    pub part1: (Answer, Duration),
+    /// The answer of part 2 and how long solving it on the parsed input took.
+    pub part2: (Answer, Duration),
+}
+
+/// Solves both parts of a day on a single parse of the input, see [`solve_both_timed`].
+pub type BothSolver = fn(&str) -> TimedAnswers;
+
+/// Like [`solve_both`], but measures parsing and each part separately.
+pub fn solve_both_timed<S: Part2>(input: &str) -> TimedAnswers {
+    let start = Instant::now();
+    let parsed = parse::<S>(input);
+    let parse_duration = start.elapsed();
+
+    let start = Instant::now();
+    let part1 = S::part1(&parsed);
+    let part1_duration = start.elapsed();
+
+    let start = Instant::now();
+    let part2 = S::part2(&parsed);
+    let part2_duration = start.elapsed();
+
+    TimedAnswers {
+        parse_duration,
+        part1: (part1, part1_duration),
+        part2: (part2, part2_duration),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_answer() {
+        assert!(matches!(Answer::parse("-12"), Answer::I64(-12)));
+        assert!(matches!(
+            Answer::parse("18446744073709551615"),
+            Answer::U64(u64::MAX)
+        ));
+        assert!(
+            matches!(Answer::parse("-0099999999999999999999"), Answer::BigInt(ref digits) if digits == "-99999999999999999999")
+        );
+        assert!(matches!(Answer::parse(" ABC\n"), Answer::Text(ref text) if text == "ABC"));
+        assert!(matches!(Answer::parse("1-2"), Answer::Text(_)));
+    }
+
+    #[test]
+    fn test_answers_compare_numerically() {
+        assert_eq!(Answer::I64(7), Answer::U64(7));
+        assert_eq!(Answer::from(7_usize), Answer::from("007"));
+        assert_eq!(
+            Answer::from(i128::MIN),
+            Answer::parse(&i128::MIN.to_string())
+        );
+        assert_ne!(Answer::I64(-7), Answer::U64(7));
+        assert_ne!(Answer::from("7a"), Answer::I64(7));
+    }
+
+    #[test]
+    fn test_measure_parse() {
+        struct SlowParse;
+
+        impl Solver for SlowParse {
+            type Parsed = usize;
+            const METADATA: Metadata = Metadata {
+                title: "Slow Parse",
+                example: "",
+                example_answers: [None, None],
+                complexity: "",
+            };
+
+            fn parse(input: &str) -> Result<usize, Diagnostic> {
+                std::thread::sleep(Duration::from_millis(5));
+                Ok(input.len())
+            }
+
+            fn part1(parsed: &usize) -> Answer {
+                (*parsed).into()
+            }
+        }
+
+        impl Part2 for SlowParse {
+            fn part2(parsed: &usize) -> Answer {
+                (*parsed * 2).into()
+            }
+        }
+
+        let ((inner, inner_duration), outer_duration) = measure_parse(|| {
+            solve_part1::<SlowParse>("abc");
+            measure_parse(|| solve_part2::<SlowParse>("abc"))
+        });
+        assert_eq!(inner, Answer::I64(6));
+        assert!(inner_duration.unwrap() >= Duration::from_millis(5));
+        assert!(outer_duration.unwrap() >= Duration::from_millis(5));
+        assert_eq!(measure_parse(|| SlowParse::part1(&3)).1, None);
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn test_try_solve_reports_problems() {
+        use crate::day01::Day01;
+
+        let err = try_solve_part1::<Day01>("X7\nR5\nL").unwrap_err();
+        let SolveError::InvalidInput(diagnostics) = &err else {
+            panic!("expected an invalid input, got {:?}", err);
+        };
+        assert_eq!(diagnostics.len(), 2);
+        assert!(
+            err.to_string().ends_with("(and 1 more problems)"),
+            "{}",
+            err
+        );
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_cancelled_error() {
+        let err = SolveError::from(Cancelled);
+        assert_eq!(err, SolveError::Cancelled);
+        assert_eq!(err.to_string(), "cancelled");
+        assert_eq!(io::Error::from(err).kind(), io::ErrorKind::Interrupted);
+    }
+
+    #[test]
+    #[cfg(all(feature = "day02", feature = "day05"))]
+    fn test_try_solve_valid_input() {
+        use crate::day02::Day02;
+        use crate::day05::Day05;
+
+        assert_eq!(
+            try_solve_part1::<Day05>("3-5\n10-14\n\n1\n5\n11"),
+            Ok(Answer::I64(2))
+        );
+        assert!(try_solve_both::<Day02>("11-22").is_ok());
+    }
+    #[test]
+    fn test_try_solve_reports_parse_error() {
+        struct Numbers;
+
+        impl Solver for Numbers {
+            type Parsed = Vec<i64>;
+            const METADATA: Metadata = Metadata {
+                title: "Numbers",
+                example: "",
+                example_answers: [None, None],
+                complexity: "",
+            };
+
+            fn parse(input: &str) -> Result<Vec<i64>, Diagnostic> {
+                input
+                    .lines()
+                    .enumerate()
+                    .map(|(index, line)| {
+                        line.parse()
+                            .map_err(|_| Diagnostic::at(index + 1, "expected a number"))
+                    })
+                    .collect()
+            }
+
+            fn part1(numbers: &Vec<i64>) -> Answer {
+                numbers.len().into()
+            }
+        }
+
+        impl Part2 for Numbers {
+            fn part2(numbers: &Vec<i64>) -> Answer {
+                numbers.iter().sum::<i64>().into()
+            }
+        }
+
+        // The default validation accepts anything, so only parsing finds the problem.
+        assert_eq!(
+            try_solve_part2::<Numbers>("1\nx"),
+            Err(SolveError::InvalidInput(vec![Diagnostic::at(
+                2,
+                "expected a number"
+            )]))
+        );
+        assert_eq!(
+            try_solve_both::<Numbers>("1\n2"),
+            Ok((Answer::I64(2), Answer::I64(3)))
+        );
+    }
+}
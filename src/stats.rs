@@ -0,0 +1,140 @@
+use std::time::Duration;
+
+/// Summary statistics of repeatedly timing the same solver.
+///
+/// Percentiles interpolate linearly between the two nearest samples, so the
+/// median of an even number of samples is the mean of the middle two.
+/// Outliers are detected with Tukey's fences: samples more than 1.5
+/// interquartile ranges below the first or above the third quartile. They are
+/// usually caused by the operating system, e.g. a context switch, and a
+/// change in the median is only meaningful if it is larger than the spread.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    /// The number of samples.
+    pub count: usize,
+    /// The fastest sample.
+    pub min: Duration,
+    /// The slowest sample.
+    pub max: Duration,
+    /// The arithmetic mean of all samples.
+    pub mean: Duration,
+    /// The median of all samples.
+    pub median: Duration,
+    /// The sample standard deviation, zero for a single sample.
+    pub std_dev: Duration,
+    /// The 95th percentile.
+    pub p95: Duration,
+    /// The samples outside Tukey's fences, sorted from fastest to slowest.
+    pub outliers: Vec<Duration>,
+}
+
+impl Stats {
+    /// Computes the statistics of the given samples, in any order.
+    ///
+    /// # Returns
+    /// The statistics, or `None` if there are no samples.
+    ///
+    /// # Examples
+    /// ```
+    /// use std::time::Duration;
+    /// use aoc2025::stats::Stats;
+    ///
+    /// let ms = Duration::from_millis;
+    /// let stats = Stats::from_samples(&[ms(10), ms(11), ms(9), ms(10), ms(40)]).unwrap();
+    /// assert_eq!(stats.median, ms(10));
+    /// assert_eq!(stats.outliers, vec![ms(40)]);
+    /// ```
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let (&min, &max) = (sorted.first()?, sorted.last()?);
+
+        let count = sorted.len();
+        let mean = sorted.iter().sum::<Duration>() / count as u32;
+        let variance = if count > 1 {
+            sorted
+                .iter()
+                .map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+                .sum::<f64>()
+                / (count - 1) as f64
+        } else {
+            0.0
+        };
+
+        let first_quartile = percentile(&sorted, 0.25);
+        let third_quartile = percentile(&sorted, 0.75);
+        let fence = (third_quartile - first_quartile).mul_f64(1.5);
+        let outliers = sorted
+            .iter()
+            .filter(|&&sample| sample + fence < first_quartile || sample > third_quartile + fence)
+            .copied()
+            .collect();
+
+        Some(Stats {
+            count,
+            min,
+            max,
+            mean,
+            median: percentile(&sorted, 0.5),
+            std_dev: Duration::from_secs_f64(variance.sqrt()),
+            p95: percentile(&sorted, 0.95),
+            outliers,
+        })
+    }
+}
+
+/// Returns the `fraction` percentile of non-empty, sorted samples,
+/// interpolating linearly between the two nearest samples.
+fn percentile(sorted: &[Duration], fraction: f64) -> Duration {
+    let rank = fraction * (sorted.len() - 1) as f64;
+    let lower = sorted[rank.floor() as usize];
+    let upper = sorted[rank.ceil() as usize];
+    lower + (upper - lower).mul_f64(rank.fract())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn test_from_samples() {
+        let stats = Stats::from_samples(&[ms(4), ms(1), ms(9), ms(2)]).unwrap();
+        assert_eq!(stats.count, 4);
+        assert_eq!((stats.min, stats.max), (ms(1), ms(9)));
+        assert_eq!(stats.mean, ms(4));
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.p95, ms(9) - ms(5).mul_f64(0.15));
+        // The sample variance is (9 + 4 + 0 + 25) / 3 ms².
+        let expected = (38.0_f64 / 3.0).sqrt() / 1000.0;
+        assert!((stats.std_dev.as_secs_f64() - expected).abs() < 1e-9);
+        assert!(stats.outliers.is_empty());
+    }
+
+    #[test]
+    fn test_from_samples_edge_cases() {
+        assert_eq!(Stats::from_samples(&[]), None);
+
+        let stats = Stats::from_samples(&[ms(7)]).unwrap();
+        assert_eq!(
+            (stats.min, stats.median, stats.p95, stats.max),
+            (ms(7), ms(7), ms(7), ms(7))
+        );
+        assert_eq!(stats.std_dev, Duration::ZERO);
+        assert!(stats.outliers.is_empty());
+    }
+
+    #[test]
+    fn test_outliers() {
+        let mut samples = vec![ms(10); 18];
+        samples.extend([ms(11), ms(9), ms(1), ms(30)]);
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.outliers, vec![ms(1), ms(9), ms(11), ms(30)]);
+
+        let stats = Stats::from_samples(&[ms(10), ms(12), ms(11), ms(13), ms(14)]).unwrap();
+        assert!(stats.outliers.is_empty());
+    }
+}
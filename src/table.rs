@@ -0,0 +1,210 @@
+/// The spaces between two columns of a [`Table`].
+const GAP: &str = "  ";
+
+/// How the cells of a [`Table`] column are aligned.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Align {
+    /// Pad on the right, for text.
+    Left,
+    /// Pad on the left, for numbers.
+    Right,
+}
+
+/// A plain-text table whose columns are as wide as their widest cell.
+///
+/// The header is followed by a dashed line, rows are rendered in the order
+/// they were added and [`separator`](Table::separator) adds another dashed
+/// line. If the table is wider than the maximum width passed to
+/// [`render`](Table::render), the [`flexible`](Table::flexible) column is
+/// narrowed down to its header and longer cells are cut off with `…`.
+/// Widths are counted in characters, so answers are assumed to be ASCII or
+/// other single-width characters.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Table {
+    columns: Vec<(String, Align)>,
+    rows: Vec<Option<Vec<String>>>,
+    flexible: Option<usize>,
+}
+
+impl Table {
+    /// Creates an empty table with the given column headers and alignments.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::table::{Align, Table};
+    ///
+    /// let mut table = Table::new(&[("Day", Align::Right), ("Answer", Align::Left)]);
+    /// table.row(["1", "1234"]);
+    /// table.separator();
+    /// table.row(["", "Total"]);
+    /// assert_eq!(
+    ///     table.render(None),
+    ///     ["Day  Answer", "-----------", "  1  1234", "-----------", "     Total"]
+    /// );
+    /// ```
+    pub fn new(columns: &[(&str, Align)]) -> Table {
+        Table {
+            columns: columns
+                .iter()
+                .map(|&(header, align)| (header.to_string(), align))
+                .collect(),
+            rows: Vec::new(),
+            flexible: None,
+        }
+    }
+
+    /// Marks the column at `index` as the one to narrow if the table is too wide.
+    pub fn flexible(mut self, index: usize) -> Table {
+        self.flexible = Some(index);
+        self
+    }
+
+    /// Appends a row with one cell per column.
+    ///
+    /// # Panics
+    /// Panics if the number of cells differs from the number of columns.
+    pub fn row<S: Into<String>>(&mut self, cells: impl IntoIterator<Item = S>) {
+        let cells: Vec<String> = cells.into_iter().map(Into::into).collect();
+        assert_eq!(
+            cells.len(),
+            self.columns.len(),
+            "a row needs one cell per column"
+        );
+        self.rows.push(Some(cells));
+    }
+
+    /// Appends a dashed line across the whole table.
+    pub fn separator(&mut self) {
+        self.rows.push(None);
+    }
+
+    /// Renders the table into lines without trailing whitespace.
+    ///
+    /// # Parameters
+    /// - `max_width`: The number of characters a line may take, e.g. the
+    ///   terminal width, or `None` for no limit.
+    pub fn render(&self, max_width: Option<usize>) -> Vec<String> {
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .map(|(header, _)| width(header))
+            .collect();
+        for cells in self.rows.iter().flatten() {
+            for (column, cell) in cells.iter().enumerate() {
+                widths[column] = widths[column].max(width(cell));
+            }
+        }
+
+        let total = |widths: &[usize]| {
+            widths.iter().sum::<usize>() + GAP.len() * widths.len().saturating_sub(1)
+        };
+        if let (Some(max_width), Some(column)) = (max_width, self.flexible) {
+            let excess = total(&widths).saturating_sub(max_width);
+            let narrowest = width(&self.columns[column].0);
+            widths[column] = widths[column].saturating_sub(excess).max(narrowest);
+        }
+
+        let line = |cells: &[&str]| {
+            let padded: Vec<String> = cells
+                .iter()
+                .zip(&self.columns)
+                .zip(&widths)
+                .map(|((cell, (_, align)), &width)| {
+                    let cell = truncate(cell, width);
+                    match align {
+                        Align::Left => format!("{:<width$}", cell),
+                        Align::Right => format!("{:>width$}", cell),
+                    }
+                })
+                .collect();
+            padded.join(GAP).trim_end().to_string()
+        };
+        let dashes = "-".repeat(total(&widths));
+
+        let headers: Vec<&str> = self
+            .columns
+            .iter()
+            .map(|(header, _)| header.as_str())
+            .collect();
+        let mut lines = vec![line(&headers), dashes.clone()];
+        for row in &self.rows {
+            lines.push(match row {
+                Some(cells) => line(&cells.iter().map(String::as_str).collect::<Vec<_>>()),
+                None => dashes.clone(),
+            });
+        }
+        lines
+    }
+}
+
+/// Returns the number of characters of a cell.
+fn width(cell: &str) -> usize {
+    cell.chars().count()
+}
+
+/// Cuts a cell down to `width` characters, ending it with `…` if it was longer.
+fn truncate(cell: &str, width: usize) -> String {
+    if self::width(cell) <= width {
+        return cell.to_string();
+    }
+    let mut truncated: String = cell.chars().take(width.saturating_sub(1)).collect();
+    truncated.push('…');
+    truncated
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table() -> Table {
+        let mut table = Table::new(&[
+            ("Part", Align::Right),
+            ("Answer", Align::Left),
+            ("Solve", Align::Right),
+        ])
+        .flexible(1);
+        table.row(["1", "42", "1.000 ms"]);
+        table.row(["2", "123456789012345678", "12.000 ms"]);
+        table
+    }
+
+    #[test]
+    fn test_render_aligns_columns() {
+        assert_eq!(
+            table().render(None),
+            vec![
+                "Part  Answer                  Solve",
+                "-----------------------------------",
+                "   1  42                   1.000 ms",
+                "   2  123456789012345678  12.000 ms",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_render_narrows_flexible_column() {
+        assert_eq!(
+            table().render(Some(30)),
+            vec![
+                "Part  Answer             Solve",
+                "------------------------------",
+                "   1  42              1.000 ms",
+                "   2  123456789012…  12.000 ms",
+            ]
+        );
+        // The flexible column keeps the width of its header.
+        assert_eq!(table().render(Some(10))[2], "   1  42       1.000 ms");
+    }
+
+    #[test]
+    fn test_truncate() {
+        assert_eq!(truncate("12345", 5), "12345");
+        assert_eq!(truncate("123456", 5), "1234…");
+    }
+
+    #[test]
+    #[should_panic(expected = "one cell per column")]
+    fn test_row_checks_cells() {
+        Table::new(&[("Day", Align::Right)]).row(["1", "2"]);
+    }
+}
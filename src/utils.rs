@@ -1,143 +1,2353 @@
 use std::fs;
-use std::io;
+use std::hint::black_box;
+use std::io::{self, Read, Write};
+use std::num::NonZeroUsize;
 use std::path::Path;
-use std::time::Instant;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
 
-/// Determines whether the current stdout supports colored output.
+use flate2::read::MultiGzDecoder;
+use tracing::{debug, debug_span, error, warn};
+use tracing_subscriber::EnvFilter;
+use tracing_subscriber::fmt::format::FmtSpan;
+
+use crate::allocations::{self, AllocStats};
+use crate::aoc_client;
+use crate::cancel;
+use crate::check;
+use crate::config;
+use crate::embedded;
+use crate::environment::Environment;
+use crate::error::AocError;
+use crate::fetch;
+use crate::memory;
+use crate::panics::{self, SolverPanic};
+use crate::progress;
+use crate::puzzle;
+use crate::registry;
+use crate::solver::{self, Answer, BothSolver, TimedAnswers};
+use crate::stats::Stats;
+use crate::table::{Align, Table};
+
+/// Installs a `tracing` subscriber printing log messages to stderr.
 ///
-/// # Returns
-/// `true` if stdout is a terminal and supports ANSI colors, `false` otherwise.
-fn supports_color() -> bool {
-    atty::is(atty::Stream::Stdout)
+/// The level is read from the `RUST_LOG` environment variable (e.g.
+/// `RUST_LOG=debug` or `RUST_LOG=aoc2025::day02=trace`) and defaults to
+/// `warn`. Closing spans are logged with their duration, so `RUST_LOG=debug`
+/// shows how long reading, parsing, and solving took. Colors follow the same
+/// rules as [`ColorChoice::Auto`], but for stderr. Calling this more than once
+/// has no effect.
+pub fn init_logging() {
+    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
+    let ansi = color_enabled(
+        ColorChoice::Auto,
+        std::env::var("NO_COLOR").ok().as_deref(),
+        std::env::var("CLICOLOR_FORCE").ok().as_deref(),
+        atty::is(atty::Stream::Stderr),
+    );
+
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(ansi)
+        .with_timer(tracing_subscriber::fmt::time::uptime())
+        .with_target(false)
+        .with_span_events(FmtSpan::CLOSE)
+        .try_init();
 }
 
-/// Reads an input file, executes a solver function, logs metadata, timing, and the result,
-/// and returns the solver result.
+/// Enables progress bars for long-running solvers if stderr is a terminal.
 ///
-/// This function will automatically select an input file if `input_path` is `None`:
-/// - First it tries `"inputs/day{day:02}_part{part}.txt"`.
-/// - If that does not exist, it falls back to `"inputs/day{day:02}.txt"`.
+/// Nothing is drawn with [`Verbosity::Quiet`], so quiet runs stay silent
+/// apart from errors. See [`progress::report`] for how solvers report progress.
+pub fn enable_progress(options: &OutputOptions) {
+    progress::set_enabled(options.verbosity > Verbosity::Quiet && atty::is(atty::Stream::Stderr));
+}
+
+/// When to use ANSI colors in the output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ColorChoice {
+    /// Decide based on the environment and whether stdout is a terminal.
+    #[default]
+    Auto,
+    /// Always emit colors, e.g. when piping to `less -R`.
+    Always,
+    /// Never emit colors.
+    Never,
+}
+
+impl ColorChoice {
+    /// Returns the choice given on the command line, or the configured one, or [`ColorChoice::Auto`].
+    pub fn resolve(choice: Option<ColorChoice>) -> ColorChoice {
+        choice.or(config::current().color).unwrap_or_default()
+    }
+
+    /// Returns whether colors should be used for stdout.
+    ///
+    /// For [`ColorChoice::Auto`] the following rules apply, in order:
+    /// 1. A non-empty `NO_COLOR` variable disables colors.
+    /// 2. A `CLICOLOR_FORCE` variable other than `0` enables colors.
+    /// 3. Otherwise colors are used if stdout is a terminal.
+    pub fn enabled(self) -> bool {
+        color_enabled(
+            self,
+            std::env::var("NO_COLOR").ok().as_deref(),
+            std::env::var("CLICOLOR_FORCE").ok().as_deref(),
+            atty::is(atty::Stream::Stdout),
+        )
+    }
+}
+
+/// Decides whether to use colors from the choice, the environment, and the terminal state.
+fn color_enabled(
+    choice: ColorChoice,
+    no_color: Option<&str>,
+    clicolor_force: Option<&str>,
+    is_terminal: bool,
+) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            if no_color.is_some_and(|value| !value.is_empty()) {
+                false
+            } else if clicolor_force.is_some_and(|value| value != "0") {
+                true
+            } else {
+                is_terminal
+            }
+        }
+    }
+}
+
+/// The input path that makes the runner read the puzzle input from stdin.
+pub const STDIN_PATH: &str = "-";
+
+/// The input path that makes the runner read the bundled example input.
+///
+/// The example is selected like a regular input, but from the `examples`
+/// subdirectory of the input directory (see [`Config::examples_dirs`](config::Config::examples_dirs)):
+/// `"inputs/2025/examples/day{day:02}_part{part}.txt"` is preferred over
+/// `"inputs/2025/examples/day{day:02}.txt"`. Without either file, the example
+/// embedded in the day module is used (see [`registry::example`]).
+pub const EXAMPLE_PATH: &str = "@example";
+
+/// The prefix of the pseudo paths under which inputs compiled in with the
+/// `embed-inputs` feature are read, e.g. `"@embedded:day03.txt"`.
+///
+/// An embedded input is only used if no input file of the puzzle exists, see
+/// [`embedded`](crate::embedded).
+pub const EMBEDDED_PATH: &str = "@embedded";
+
+/// The extension of gzip-compressed input files, which are decompressed transparently.
+pub const GZIP_EXTENSION: &str = ".gz";
+
+/// The time budget for downloading an input given as URL.
+const URL_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How much the runner prints about a run.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Only the answer, suitable for scripting.
+    Quiet,
+    /// Metadata, timings, and the answer.
+    #[default]
+    Normal,
+    /// Additionally the input size and line count.
+    Verbose,
+    /// Additionally the exact, unrounded durations.
+    Debug,
+}
+
+impl Verbosity {
+    /// Maps a quiet flag and the number of `-v` flags to a verbosity level.
+    ///
+    /// # Examples
+    /// ```
+    /// use aoc2025::utils::Verbosity;
+    ///
+    /// assert_eq!(Verbosity::from_flags(true, 0), Verbosity::Quiet);
+    /// assert_eq!(Verbosity::from_flags(false, 0), Verbosity::Normal);
+    /// assert_eq!(Verbosity::from_flags(false, 2), Verbosity::Debug);
+    /// ```
+    pub fn from_flags(quiet: bool, verbose: u8) -> Verbosity {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, 0) => Verbosity::Normal,
+            (false, 1) => Verbosity::Verbose,
+            (false, _) => Verbosity::Debug,
+        }
+    }
+}
+
+/// Options controlling how run reports are printed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputOptions {
+    /// How much is printed.
+    pub verbosity: Verbosity,
+    /// When to use ANSI colors.
+    pub color: ColorChoice,
+    /// The width tables may take, usually the [terminal width](terminal_width),
+    /// or `None` for no limit.
+    pub max_width: Option<usize>,
+}
+
+/// Returns the width of the terminal in characters, or `None` if stdout is
+/// not a terminal, e.g. when it is piped into a file.
+pub fn terminal_width() -> Option<usize> {
+    if !atty::is(atty::Stream::Stdout) {
+        return None;
+    }
+    ratatui::crossterm::terminal::size()
+        .ok()
+        .map(|(columns, _)| usize::from(columns))
+}
+
+/// Options controlling how [`run_day_with`] and [`run_days`] execute solvers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RunOptions {
+    /// The time budget of every single solver, or `None` for no limit.
+    ///
+    /// With [`iterations`](RunOptions::iterations), the budget covers the
+    /// warm-up run and all timed iterations together.
+    pub timeout: Option<Duration>,
+    /// How often every solver is timed after one warm-up run, or `None` for
+    /// a single cold run.
+    ///
+    /// Single cold runs can vary by a factor of 2 or more; with iterations,
+    /// the report's `solve_duration` is the median of the timed iterations
+    /// and every sample is kept in [`RunReport::samples`].
+    pub iterations: Option<NonZeroUsize>,
+}
+
+pub use crate::registry::Solver;
+
+/// The outcome of a single solver run, including the answer and timings.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunReport {
+    /// The day number of the puzzle.
+    pub day: i32,
+    /// The part number of the puzzle.
+    pub part: i32,
+    /// The path of the input file that was used.
+    pub input_path: String,
+    /// The answer returned by the solver, as printed (see [`Answer`]).
+    pub answer: String,
+    /// The size of the input in bytes.
+    pub input_bytes: usize,
+    /// The number of lines in the input.
+    pub input_lines: usize,
+    /// A stable hash of the input, see [`hash_input`].
+    pub input_hash: u64,
+    /// How long reading the input file took.
+    pub input_duration: Duration,
+    /// How long parsing the input took, if the solver records it (see
+    /// [`measure_parse`](solver::measure_parse)) and zero otherwise, or the
+    /// median over the timed iterations.
+    ///
+    /// If the input was parsed once for both parts (see [`run_both`]), only
+    /// the report of part 1 carries it.
+    pub parse_duration: Duration,
+    /// How long the solver took without parsing, or the median of
+    /// [`samples`](RunReport::samples) if the solver was timed repeatedly.
+    pub solve_duration: Duration,
+    /// The solve durations of the timed iterations after the warm-up run,
+    /// without parsing, in execution order, or empty for a single cold run
+    /// (see [`RunOptions::iterations`]).
+    pub samples: Vec<Duration>,
+    /// Whether the solver was abandoned because it exceeded its time budget.
+    ///
+    /// In that case `answer` is empty and `solve_duration` is the budget.
+    pub timed_out: bool,
+    /// Whether the run was cancelled while solving, e.g. by Ctrl-C (see [`cancel`]).
+    ///
+    /// In that case `answer` is empty and `solve_duration` is the time until
+    /// the solver stopped.
+    pub cancelled: bool,
+    /// Where and why the solver panicked, if it did (see [`panics::catch`]).
+    ///
+    /// In that case `answer` is empty and `solve_duration` is the time until
+    /// the panic.
+    pub panic: Option<SolverPanic>,
+    /// The heap allocations of the solver, if counted (see [`allocations::enabled`]).
+    pub allocations: Option<AllocStats>,
+    /// The peak resident memory of the process while solving, in bytes, if
+    /// supported by the platform (see [`memory::measure_peak_rss`]).
+    pub peak_rss: Option<u64>,
+    /// The build and machine the puzzle was run on, `None` for reports not
+    /// created by the runners of this module.
+    pub environment: Option<&'static Environment>,
+}
+
+impl RunReport {
+    /// Returns the answer for display, or `"timed out"`, `"cancelled"` or
+    /// `"panicked"` if the solver did not finish.
+    pub fn display_answer(&self) -> &str {
+        if self.timed_out {
+            "timed out"
+        } else if self.cancelled {
+            "cancelled"
+        } else if self.panic.is_some() {
+            "panicked"
+        } else {
+            &self.answer
+        }
+    }
+
+    /// Returns whether the solver finished, i.e. neither timed out, was
+    /// cancelled, nor panicked.
+    pub fn finished(&self) -> bool {
+        !self.timed_out && !self.cancelled && self.panic.is_none()
+    }
+
+    /// Returns the combined duration of reading the input, parsing, and solving.
+    pub fn total_duration(&self) -> Duration {
+        self.input_duration + self.parse_duration + self.solve_duration
+    }
+
+    /// Returns the fastest of the timed iterations, if the solver was timed repeatedly.
+    pub fn best_duration(&self) -> Option<Duration> {
+        self.samples.iter().min().copied()
+    }
+
+    /// Returns the statistics of the timed iterations, if the solver was timed repeatedly.
+    pub fn stats(&self) -> Option<Stats> {
+        Stats::from_samples(&self.samples)
+    }
+
+    /// Returns how fast the solver processed its input, in bytes and lines
+    /// per second of parsing and solving.
+    ///
+    /// # Returns
+    /// `None` if the solver did not finish, the input is empty, or the time
+    /// was too short to measure.
+    pub fn throughput(&self) -> Option<(f64, f64)> {
+        let seconds = (self.parse_duration + self.solve_duration).as_secs_f64();
+        if !self.finished() || self.input_bytes == 0 || seconds == 0.0 {
+            return None;
+        }
+        Some((
+            self.input_bytes as f64 / seconds,
+            self.input_lines as f64 / seconds,
+        ))
+    }
+
+    /// Turns a timed-out run into an error, for callers that treat the time
+    /// budget as a hard limit.
+    ///
+    /// # Returns
+    /// The report itself, or [`AocError::Timeout`] with the budget if the
+    /// solver [timed out](RunReport::timed_out).
+    pub fn into_result(self) -> Result<RunReport, AocError> {
+        if self.timed_out {
+            return Err(AocError::Timeout {
+                day: self.day,
+                part: self.part,
+                budget: self.solve_duration,
+            });
+        }
+        Ok(self)
+    }
+}
+
+/// Reads an input file, executes a solver function, writes metadata, timing,
+/// and the result to `out`, and returns the report of the run.
+///
+/// This function will automatically select an input file of the configured
+/// year if `input_path` is `None`:
+/// - First it tries `"inputs/2025/day{day:02}_part{part}.txt"`.
+/// - If that does not exist, it falls back to `"inputs/2025/day{day:02}.txt"`.
 ///
 /// # Parameters
 /// - `day`: The day number of the puzzle (used for input path selection and logging).
 /// - `part`: The part number of the puzzle (used for input path selection and logging).
 /// - `input_path`: Optional path to a specific input file. If `None`, automatic selection is used.
-/// - `solve`: A function or closure that takes the file contents as `&str` and returns a `String` result.
+///   Passing [`STDIN_PATH`] (`"-"`) reads the input from stdin instead.
+/// - `solve`: A function or closure that takes the file contents as `&str` and returns an [`Answer`].
+/// - `out`: Where the report is written, e.g. `io::stdout()` or a `Vec<u8>`.
 ///
 /// # Returns
-/// A `Result<String, io::Error>` containing the result of the `solve` function, or an I/O error
-/// if the input file could not be found or read.
+/// The [`RunReport`] with the answer and timings, or an [`AocError`] if the
+/// input could not be found, read or validated, or writing to `out` failed.
 ///
 /// # Examples
 /// ```no_run
+/// use std::io;
+///
+/// use aoc2025::day01::Day01;
+/// use aoc2025::solver::solve_part1;
 /// use aoc2025::utils::run_puzzle;
-/// use aoc2025::day01::part1::solve;
+///
+/// let solve = solve_part1::<Day01>;
 ///
 /// // Automatically select input file for day 1, part 1
-/// let result = run_puzzle(1, 1, None, solve).unwrap();
+/// let report = run_puzzle(1, 1, None, solve, &mut io::stdout()).unwrap();
+/// println!("{} in {:?}", report.answer, report.solve_duration);
+///
+/// // Use a specific input file, collecting the output
+/// let mut out: Vec<u8> = Vec::new();
+/// let report = run_puzzle(1, 1, Some("inputs/day01_example.txt"), solve, &mut out).unwrap();
+///
+/// // Read the input from stdin
+/// let report = run_puzzle(1, 1, Some("-"), solve, &mut io::stdout()).unwrap();
+/// ```
+pub fn run_puzzle<F>(
+    day: i32,
+    part: i32,
+    input_path: Option<&str>,
+    solve: F,
+    out: &mut impl Write,
+) -> Result<RunReport, AocError>
+where
+    F: Fn(&str) -> Answer,
+{
+    run_puzzle_with(day, part, input_path, solve, &OutputOptions::default(), out)
+}
+
+/// Like [`run_puzzle`], but writes the report according to the given output options.
+///
+/// # Examples
+/// ```no_run
+/// use std::io;
+///
+/// use aoc2025::day01::Day01;
+/// use aoc2025::solver::solve_part1;
+/// use aoc2025::utils::{ColorChoice, OutputOptions, Verbosity, run_puzzle_with};
+///
+/// let solve = solve_part1::<Day01>;
+///
+/// // Print only the answer
+/// let options = OutputOptions {
+///     verbosity: Verbosity::Quiet,
+///     color: ColorChoice::Never,
+///     max_width: None,
+/// };
+/// let report = run_puzzle_with(1, 1, None, solve, &options, &mut io::stdout()).unwrap();
+/// ```
+pub fn run_puzzle_with<F>(
+    day: i32,
+    part: i32,
+    input_path: Option<&str>,
+    solve: F,
+    options: &OutputOptions,
+    out: &mut impl Write,
+) -> Result<RunReport, AocError>
+where
+    F: Fn(&str) -> Answer,
+{
+    let path = resolve_input_path(day, part, input_path)?;
+
+    let input_start = Instant::now();
+    let input = read_input(&path)?;
+    let input_duration = input_start.elapsed();
+    check::validate_before_solving(day, &path, &input)?;
+    fetch::warn_if_stale(config::current(), day, &path, &input);
+
+    let report = execute_puzzle(day, part, &path, &input, input_duration, solve, None);
+    write_report(out, &report, options)?;
+
+    Ok(report)
+}
+
+/// Like [`run_puzzle`], but asynchronous, for driving many puzzles
+/// concurrently from a tokio runtime.
+///
+/// Plain input files are read with `tokio::fs`. Downloading a missing input,
+/// reading stdin, URLs and compressed inputs, validation, and the CPU-bound
+/// solver run on tokio's blocking pool, so the runtime's worker threads are
+/// never blocked. Like [`run_day`], nothing is printed.
+///
+/// Requires the `async` feature and must be awaited inside a tokio runtime.
+///
+/// # Parameters
+/// - `input_path`: Optional path of the input file, selected like in [`run_puzzle`].
+/// - `solve`: The solver, moved to the blocking pool.
+///
+/// # Returns
+/// The report of the run, or an [`AocError`] if the input could not be found,
+/// read or validated. A panic in the solver is caught like in [`run_puzzle`].
+///
+/// # Examples
+/// ```no_run
+/// use aoc2025::day01::Day01;
+/// use aoc2025::solver::{solve_part1, solve_part2};
+/// use aoc2025::utils::run_puzzle_async;
 ///
-/// // Use a specific input file
-/// let result = run_puzzle(1, 1, Some("inputs/day01_example.txt"), solve).unwrap();
+/// let runtime = tokio::runtime::Builder::new_current_thread().build()?;
+/// runtime.block_on(async {
+///     let part1 = tokio::spawn(run_puzzle_async(1, 1, None, solve_part1::<Day01>));
+///     let part2 = tokio::spawn(run_puzzle_async(1, 2, None, solve_part2::<Day01>));
+///     println!("{}", part1.await??.answer);
+///     println!("{}", part2.await??.answer);
+///     Ok::<(), Box<dyn std::error::Error>>(())
+/// })?;
+/// # Ok::<(), Box<dyn std::error::Error>>(())
 /// ```
-pub fn run_puzzle<F>(day: i32, part: i32, input_path: Option<&str>, solve: F) -> io::Result<String>
+#[cfg(feature = "async")]
+pub async fn run_puzzle_async<F>(
+    day: i32,
+    part: i32,
+    input_path: Option<String>,
+    solve: F,
+) -> Result<RunReport, AocError>
+where
+    F: Fn(&str) -> Answer + Send + 'static,
+{
+    let path = match locate_input_path(day, part, input_path.as_deref()) {
+        Ok(path) => path,
+        Err(_) => {
+            spawn_blocking(move || resolve_input_path(day, part, input_path.as_deref())).await?
+        }
+    };
+
+    let input_start = Instant::now();
+    let input = if is_plain_file(&path) {
+        tokio::fs::read_to_string(&path).await.inspect_err(|err| {
+            error!("Could not read input file '{}': {}", path, err);
+        })?
+    } else {
+        let path = path.clone();
+        spawn_blocking(move || read_input(&path)).await?
+    };
+    let input_duration = input_start.elapsed();
+
+    spawn_blocking(move || {
+        check::validate_before_solving(day, &path, &input)?;
+        fetch::warn_if_stale(config::current(), day, &path, &input);
+        Ok(execute_puzzle(
+            day,
+            part,
+            &path,
+            &input,
+            input_duration,
+            solve,
+            None,
+        ))
+    })
+    .await
+}
+
+/// Runs a blocking function on tokio's blocking pool, resuming its panic if it panics.
+#[cfg(feature = "async")]
+async fn spawn_blocking<T, F>(function: F) -> Result<T, AocError>
 where
-    F: Fn(&str) -> String,
+    F: FnOnce() -> Result<T, AocError> + Send + 'static,
+    T: Send + 'static,
 {
-    let use_color = supports_color();
+    match tokio::task::spawn_blocking(function).await {
+        Ok(result) => result,
+        Err(err) if err.is_panic() => std::panic::resume_unwind(err.into_panic()),
+        Err(err) => Err(AocError::Io(io::Error::other(err))),
+    }
+}
+
+/// Returns whether [`read_input`] would read `path` as an uncompressed local
+/// file, so it can be read without the blocking pool.
+#[cfg(feature = "async")]
+fn is_plain_file(path: &str) -> bool {
+    !path.starts_with(EXAMPLE_PATH)
+        && !path.starts_with(EMBEDDED_PATH)
+        && path != STDIN_PATH
+        && !path.contains("://")
+        && !path.ends_with(GZIP_EXTENSION)
+}
+
+/// Runs several parts of one day, reading every distinct input file only once.
+///
+/// Each part resolves its input path like [`run_puzzle`]. Parts that end up
+/// with the same file (usually the shared `inputs/day{day:02}.txt`) reuse the
+/// already loaded contents instead of reading the file again. Parts reusing
+/// an input report an input read time of zero, so summed timings reflect the
+/// I/O that actually happened.
+///
+/// Unlike [`run_puzzle`], nothing is printed. Use [`print_report`] or
+/// [`print_summary`] to display the returned reports.
+///
+/// # Parameters
+/// - `day`: The day number of the puzzle.
+/// - `parts`: The part numbers paired with their solver functions, executed in order.
+/// - `input_path`: Optional path of an input file used for all parts, or [`STDIN_PATH`].
+///   If `None`, automatic selection is used for every part.
+///
+/// # Returns
+/// The reports of all parts in the order of `parts`, or the first error encountered.
+///
+/// # Examples
+/// ```no_run
+/// use aoc2025::day01::Day01;
+/// use aoc2025::solver::{Answer, solve_part1, solve_part2};
+/// use aoc2025::utils::{OutputOptions, print_report, run_day};
+///
+/// let parts: [(i32, fn(&str) -> Answer); 2] = [(1, solve_part1::<Day01>), (2, solve_part2::<Day01>)];
+/// for report in run_day(1, &parts, None).unwrap() {
+///     print_report(&report, &OutputOptions::default());
+/// }
+/// ```
+pub fn run_day(
+    day: i32,
+    parts: &[(i32, Solver)],
+    input_path: Option<&str>,
+) -> Result<Vec<RunReport>, AocError> {
+    run_day_with(day, parts, input_path, &RunOptions::default())
+}
 
-    // Determine input file
-    let path = if let Some(p) = input_path {
-        p.to_string()
+/// Like [`run_day`], but executes the solvers according to `run_options`.
+///
+/// With [`iterations`](RunOptions::iterations), every solver runs once to
+/// warm up, which yields the answer, and is then timed that many times.
+///
+/// With a [`timeout`](RunOptions::timeout), each solver runs on its own thread. A solver exceeding the
+/// budget cannot be stopped; its thread is detached and keeps running in the
+/// background until it finishes or the process exits. Its report is marked as
+/// [`timed_out`](RunReport::timed_out) and the remaining parts are executed
+/// normally.
+///
+/// Once the [global cancel token](crate::cancel::global) is cancelled, the
+/// running solver's report is marked as [`cancelled`](RunReport::cancelled)
+/// and the remaining parts are skipped.
+///
+/// If `parts` are the registered solvers of both parts of the day and both
+/// parts read the same input, the input is parsed only once with [`run_both`].
+/// Timeouts and timed iterations need every part on its own, so they always
+/// run the parts separately.
+///
+/// # Parameters
+/// - `run_options`: The time budget and the number of timed iterations of every solver.
+pub fn run_day_with(
+    day: i32,
+    parts: &[(i32, Solver)],
+    input_path: Option<&str>,
+    run_options: &RunOptions,
+) -> Result<Vec<RunReport>, AocError> {
+    if let Some(solve) = shared_parse(day, parts, input_path, run_options)? {
+        if cancel::global().is_cancelled() {
+            return Ok(Vec::new());
+        }
+        return run_both(day, solve, input_path);
+    }
+
+    let mut loaded: Vec<(String, String)> = Vec::new();
+    let mut results: Vec<RunReport> = Vec::new();
+
+    for (part, solve) in parts {
+        if cancel::global().is_cancelled() {
+            break;
+        }
+        let path = resolve_input_path(day, *part, input_path)?;
+
+        let (index, input_duration) = match loaded
+            .iter()
+            .position(|(loaded_path, _)| *loaded_path == path)
+        {
+            Some(index) => (index, Duration::ZERO),
+            None => {
+                let input_start = Instant::now();
+                let input = read_input(&path)?;
+                let input_duration = input_start.elapsed();
+                check::validate_before_solving(day, &path, &input)?;
+                fetch::warn_if_stale(config::current(), day, &path, &input);
+                loaded.push((path.clone(), input));
+                (loaded.len() - 1, input_duration)
+            }
+        };
+
+        let (_, input) = &loaded[index];
+        results.push(match run_options.timeout {
+            Some(_) => execute_puzzle_with_timeout(
+                day,
+                *part,
+                &path,
+                input,
+                input_duration,
+                *solve,
+                run_options,
+            ),
+            None => execute_puzzle(
+                day,
+                *part,
+                &path,
+                input,
+                input_duration,
+                solve,
+                run_options.iterations,
+            ),
+        });
+    }
+
+    Ok(results)
+}
+
+/// Returns the solver of both parts of `day` if `parts` can share one parse,
+/// see [`run_day_with`].
+///
+/// Only the registered solvers of the day are replaced, so custom solvers
+/// passed for a registered day still run as given.
+fn shared_parse(
+    day: i32,
+    parts: &[(i32, Solver)],
+    input_path: Option<&str>,
+    run_options: &RunOptions,
+) -> Result<Option<BothSolver>, AocError> {
+    if run_options.timeout.is_some() || run_options.iterations.is_some() {
+        return Ok(None);
+    }
+    let [(1, part1), (2, part2)] = parts else {
+        return Ok(None);
+    };
+    let registered = |part: i32, solve: Solver| {
+        registry::solver(day, part)
+            .is_some_and(|registered| std::ptr::fn_addr_eq(registered, solve))
+    };
+    if !registered(1, *part1) || !registered(2, *part2) {
+        return Ok(None);
+    }
+    if resolve_input_path(day, 1, input_path)? != resolve_input_path(day, 2, input_path)? {
+        return Ok(None);
+    }
+    Ok(registry::both_parts(day))
+}
+
+/// Runs several days on up to `jobs` threads and returns their reports in order.
+///
+/// Every day is executed with [`run_day_with`], so parts of the same day
+/// still share their input, and usually its parse, and run one after another
+/// on the same thread. Different
+/// days run concurrently, which shortens the total wall-clock time when one
+/// day dominates the run. Solve durations are still measured per solver, but
+/// may be inflated when more jobs than CPU cores are used. After a
+/// cancellation, no further days are started.
+///
+/// # Parameters
+/// - `days`: The day numbers paired with their parts and solvers.
+/// - `input_path`: Optional path of an input file used for all parts, like in [`run_day`].
+/// - `jobs`: The maximum number of threads. `0` and `1` run everything on the current thread.
+/// - `run_options`: How every solver is executed, like in [`run_day_with`].
+///
+/// # Returns
+/// The reports of all days in the order of `days`, or the first error in that order.
+pub fn run_days(
+    days: &[(i32, Vec<(i32, Solver)>)],
+    input_path: Option<&str>,
+    jobs: usize,
+    run_options: &RunOptions,
+) -> Result<Vec<RunReport>, AocError> {
+    let mut outcomes: Vec<(usize, Result<Vec<RunReport>, AocError>)> = if jobs <= 1 {
+        days.iter()
+            .enumerate()
+            .map(|(index, (day, parts))| {
+                (index, run_day_with(*day, parts, input_path, run_options))
+            })
+            .collect()
     } else {
-        let primary_path = format!("inputs/day{:02}_part{}.txt", day, part);
-        let secondary_path = format!("inputs/day{:02}.txt", day);
+        let next = AtomicUsize::new(0);
+        thread::scope(|scope| {
+            let workers: Vec<_> = (0..jobs.min(days.len()))
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut finished = Vec::new();
+                        loop {
+                            let index = next.fetch_add(1, Ordering::Relaxed);
+                            let Some((day, parts)) = days.get(index) else {
+                                return finished;
+                            };
+                            if cancel::global().is_cancelled() {
+                                return finished;
+                            }
+                            finished
+                                .push((index, run_day_with(*day, parts, input_path, run_options)));
+                        }
+                    })
+                })
+                .collect();
+            workers
+                .into_iter()
+                .flat_map(|worker| {
+                    worker
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    };
+
+    outcomes.sort_by_key(|(index, _)| *index);
+    let mut reports: Vec<RunReport> = Vec::new();
+    for (_, outcome) in outcomes {
+        reports.extend(outcome?);
+    }
+    Ok(reports)
+}
 
-        if Path::new(&primary_path).exists() {
-            primary_path
-        } else if Path::new(&secondary_path).exists() {
-            secondary_path
+/// Runs both parts of one day on a single parse of the shared input.
+///
+/// The input is resolved like in [`run_puzzle`] and must be the same file for
+/// both parts. Parsing and each part are timed separately: the report of
+/// part 1 carries the input read and parse durations, and each report's
+/// `solve_duration` covers only its part. Allocations are not counted.
+///
+/// # Parameters
+/// - `day`: The day to run.
+/// - `solve`: The solver of both parts, see [`registry::both_parts`].
+/// - `input_path`: The input to use instead of the automatic selection.
+///
+/// # Returns
+/// The reports of part 1 and part 2, or an [`AocError::Io`] of kind
+/// `InvalidInput` if the parts have separate input files.
+///
+/// # Examples
+/// ```no_run
+/// use aoc2025::day02::Day02;
+/// use aoc2025::solver::solve_both_timed;
+/// use aoc2025::utils::run_both;
+///
+/// let reports = run_both(2, solve_both_timed::<Day02>, None).unwrap();
+/// println!("parsed in {:?}", reports[0].parse_duration);
+/// ```
+pub fn run_both(
+    day: i32,
+    solve: BothSolver,
+    input_path: Option<&str>,
+) -> Result<Vec<RunReport>, AocError> {
+    let path = resolve_input_path(day, 1, input_path)?;
+    let part2_path = resolve_input_path(day, 2, input_path)?;
+    if path != part2_path {
+        return Err(AocError::Io(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "Day {} has separate inputs for both parts ('{}' and '{}'), so they cannot share one parse",
+                day, path, part2_path
+            ),
+        )));
+    }
+
+    let input_start = Instant::now();
+    let input = read_input(&path)?;
+    let input_duration = input_start.elapsed();
+    check::validate_before_solving(day, &path, &input)?;
+    fetch::warn_if_stale(config::current(), day, &path, &input);
+
+    let _span = debug_span!("solve", day).entered();
+    let solve_start = Instant::now();
+    let (outcome, peak_rss) = memory::measure_peak_rss(|| panics::catch(|| solve(&input)));
+    debug!(?outcome, ?peak_rss, "solved both parts");
+
+    let (answers, panic) = match outcome {
+        Ok(answers) => (answers, None),
+        Err(panic) => {
+            warn!(day, %panic, "solver panicked");
+            let answers = TimedAnswers {
+                parse_duration: Duration::ZERO,
+                part1: (Answer::Text(String::new()), solve_start.elapsed()),
+                part2: (Answer::Text(String::new()), Duration::ZERO),
+            };
+            (answers, Some(panic))
+        }
+    };
+    let TimedAnswers {
+        parse_duration,
+        part1,
+        part2,
+    } = answers;
+    let cancelled = cancel::global().is_cancelled();
+    let report = |part: i32, (answer, solve_duration): (Answer, Duration)| RunReport {
+        day,
+        part,
+        input_path: path.clone(),
+        answer: if cancelled {
+            String::new()
         } else {
-            return Err(io::Error::new(
-                io::ErrorKind::NotFound,
-                format!(
-                    "Input file not found: tried '{}' and '{}'",
-                    primary_path, secondary_path
-                ),
+            answer.to_string()
+        },
+        input_bytes: input.len(),
+        input_lines: input.lines().count(),
+        input_hash: hash_input(&input),
+        input_duration: Duration::ZERO,
+        parse_duration: Duration::ZERO,
+        solve_duration,
+        samples: Vec::new(),
+        timed_out: false,
+        cancelled,
+        panic: panic.clone(),
+        allocations: None,
+        peak_rss,
+        environment: Some(Environment::current()),
+    };
+    Ok(vec![
+        RunReport {
+            input_duration,
+            parse_duration,
+            ..report(1, part1)
+        },
+        report(2, part2),
+    ])
+}
+
+/// Parses a human readable duration such as `"30s"`, `"500ms"`, or `"2m"`.
+///
+/// Supported units are `ms`, `s`, `m`, and `h`. A number without a unit is
+/// interpreted as seconds. Fractional values like `"1.5s"` are allowed.
+///
+/// # Returns
+/// The parsed duration, or a message describing why the value is invalid.
+///
+/// # Examples
+/// ```
+/// use std::time::Duration;
+/// use aoc2025::utils::parse_duration;
+///
+/// assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+/// assert_eq!(parse_duration("250ms"), Ok(Duration::from_millis(250)));
+/// assert!(parse_duration("soon").is_err());
+/// ```
+pub fn parse_duration(value: &str) -> Result<Duration, String> {
+    let value = value.trim();
+    let split = value
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(value.len());
+    let (number, unit) = value.split_at(split);
+
+    let number: f64 = number
+        .parse()
+        .map_err(|_| format!("invalid duration '{}'", value))?;
+    let seconds = match unit.trim() {
+        "ms" => number / 1000.0,
+        "" | "s" => number,
+        "m" => number * 60.0,
+        "h" => number * 3600.0,
+        other => {
+            return Err(format!(
+                "unknown duration unit '{}', use ms, s, m or h",
+                other
             ));
         }
     };
+    Duration::try_from_secs_f64(seconds).map_err(|_| format!("invalid duration '{}'", value))
+}
 
-    // Start timing (input read + solve)
-    let overall_start = Instant::now();
+/// Resolves and reads the input of a puzzle without running any solver.
+///
+/// The input path is selected like in [`run_puzzle`].
+///
+/// # Returns
+/// The resolved path together with the contents of the input.
+pub fn load_input(
+    day: i32,
+    part: i32,
+    input_path: Option<&str>,
+) -> Result<(String, String), AocError> {
+    let path = resolve_input_path(day, part, input_path)?;
+    let input = read_input(&path)?;
+    Ok((path, input))
+}
 
-    // Read input
-    let input_start = Instant::now();
-    let input = match fs::read_to_string(&path) {
-        Ok(content) => content,
-        Err(err) => {
-            if use_color {
-                eprintln!(
-                    "\x1b[31m[ERROR]\x1b[0m Could not read input file '{}': {}",
-                    path, err
-                );
-            } else {
-                eprintln!("[ERROR] Could not read input file '{}': {}", path, err);
-            }
-            return Err(err);
+/// Returns the input file that would be used for a puzzle by default.
+///
+/// # Returns
+/// The path of the first existing input file, or `None` if there is none.
+pub fn find_input(day: i32, part: i32) -> Option<String> {
+    locate_input_path(day, part, None).ok()
+}
+
+/// Determines which input file to use for a puzzle.
+///
+/// If `input_path` is given it is used as is, except for [`EXAMPLE_PATH`],
+/// which selects the example input. Otherwise the input configured for the
+/// day in `aoc.toml` is used, and without one
+/// `"inputs/{year}/day{day:02}_part{part}.txt"` is preferred over `"inputs/{year}/day{day:02}.txt"`.
+/// For 2025, inputs directly in `inputs` are found as well (see
+/// [`Config::input_dirs`](config::Config::input_dirs)). The `inputs` directory
+/// and the year can be changed in the configuration, see [`Config`](config::Config).
+///
+/// If the default input of a day is missing, the input compiled in with the
+/// `embed-inputs` feature is used (see [`EMBEDDED_PATH`]). Without one, the
+/// input is downloaded first if a session token is available, see
+/// [`fetch`](crate::fetch).
+///
+/// # Returns
+/// The selected path, or [`AocError::InputNotFound`] listing every tried path.
+fn resolve_input_path(day: i32, part: i32, input_path: Option<&str>) -> Result<String, AocError> {
+    let config = config::current();
+    match locate_input_path(day, part, input_path) {
+        Err(AocError::InputNotFound { tried, .. })
+            if input_path.is_none()
+                && config.day_input(day).is_none()
+                && (1..=25).contains(&day)
+                && aoc_client::has_session_token(config) =>
+        {
+            fetch::fetch_input(config, day, false)
+                .map(|(path, _)| path)
+                .map_err(|err| AocError::InputNotFound {
+                    tried,
+                    download: Some(err.to_string()),
+                })
         }
+        result => result,
+    }
+}
+
+/// Determines which existing input file to use for a puzzle, like
+/// [`resolve_input_path`] but without downloading missing inputs.
+fn locate_input_path(day: i32, part: i32, input_path: Option<&str>) -> Result<String, AocError> {
+    let config = config::current();
+    match input_path {
+        Some(EXAMPLE_PATH) => match select_input(&config.examples_dirs(), day, part) {
+            Err(err) if registry::example(day).is_some() => {
+                debug!(%err, "using the embedded example");
+                Ok(embedded_example_path(day))
+            }
+            result => result,
+        },
+        Some(p) => Ok(p.to_string()),
+        None => match config.day_input(day) {
+            Some(p) => Ok(p.to_string()),
+            None => select_input(&config.input_dirs(), day, part)
+                .or_else(|err| embedded_input_path(day, part).ok_or(err)),
+        },
+    }
+}
+
+/// Returns the pseudo path of the input of a puzzle compiled in with the
+/// `embed-inputs` feature, e.g. `"@embedded:day03.txt"`, or `None` without one.
+fn embedded_input_path(day: i32, part: i32) -> Option<String> {
+    embedded::input(day, part).map(|(name, _)| format!("{}:{}", EMBEDDED_PATH, name))
+}
+
+/// Returns the pseudo path under which the embedded example of a day is read,
+/// e.g. `"@example:day03"`.
+fn embedded_example_path(day: i32) -> String {
+    format!("{}:day{:02}", EXAMPLE_PATH, day)
+}
+
+/// Returns whether `--example` runs of a puzzle use the example embedded in
+/// its day module, because no example file exists.
+pub(crate) fn uses_embedded_example(day: i32, part: i32) -> bool {
+    locate_input_path(day, part, Some(EXAMPLE_PATH))
+        .is_ok_and(|path| path == embedded_example_path(day))
+}
+
+/// Selects the per-part or shared input file of a puzzle in the first of
+/// `dirs` that contains one.
+///
+/// Each file may also be stored gzip-compressed with [`GZIP_EXTENSION`]
+/// appended, e.g. `"inputs/2025/day05.txt.gz"`; an uncompressed file is preferred.
+fn select_input(dirs: &[String], day: i32, part: i32) -> Result<String, AocError> {
+    let candidates: Vec<String> = dirs
+        .iter()
+        .flat_map(|dir| {
+            [
+                format!("{}/day{:02}_part{}.txt", dir, day, part),
+                format!("{}/day{:02}.txt", dir, day),
+            ]
+        })
+        .collect();
+
+    let tried: Vec<String> = candidates
+        .iter()
+        .flat_map(|path| [path.clone(), format!("{}{}", path, GZIP_EXTENSION)])
+        .collect();
+    match tried.iter().find(|path| Path::new(path).exists()) {
+        Some(path) => Ok(path.clone()),
+        None => Err(AocError::InputNotFound {
+            tried,
+            download: None,
+        }),
+    }
+}
+
+/// Reads the whole input file, logging an error message if it cannot be read.
+///
+/// If `path` is [`STDIN_PATH`], the input is read from stdin until EOF, and
+/// the embedded example of a day or an input compiled in with
+/// `embed-inputs` is returned for its pseudo path. Inputs
+/// given as URL are downloaded (see [`open_input`]), and inputs ending in
+/// [`GZIP_EXTENSION`] are decompressed.
+/// The read is recorded in an `input` span.
+fn read_input(path: &str) -> Result<String, AocError> {
+    let _span = debug_span!("input", path).entered();
+
+    let embedded = path
+        .strip_prefix(EXAMPLE_PATH)
+        .and_then(|rest| rest.strip_prefix(":day"))
+        .and_then(|day| day.parse().ok())
+        .and_then(registry::example);
+
+    let content = if let Some(example) = embedded {
+        Ok(example.to_string())
+    } else if let Some(name) = path.strip_prefix(EMBEDDED_PATH) {
+        embedded::file(name.trim_start_matches(':'))
+            .map(str::to_string)
+            .ok_or_else(|| AocError::InputNotFound {
+                tried: vec![path.to_string()],
+                download: None,
+            })
+    } else if path == STDIN_PATH {
+        let mut buffer = String::new();
+        io::stdin()
+            .read_to_string(&mut buffer)
+            .map(|_| buffer)
+            .map_err(AocError::Io)
+    } else {
+        open_input(path).and_then(|reader| {
+            let mut reader = if path.ends_with(GZIP_EXTENSION) {
+                Box::new(MultiGzDecoder::new(reader))
+            } else {
+                reader
+            };
+            let mut buffer = String::new();
+            Ok(reader.read_to_string(&mut buffer).map(|_| buffer)?)
+        })
     };
-    let input_duration = input_start.elapsed();
 
-    // Execute solver
+    match content {
+        Ok(content) => {
+            debug!(bytes = content.len(), "input read");
+            Ok(content)
+        }
+        Err(err) => {
+            error!("Could not read input file '{}': {}", path, err);
+            Err(err)
+        }
+    }
+}
+
+/// Opens an input file, or starts downloading it if `path` is an
+/// `http://` or `https://` URL. `file://` URLs are opened as local files.
+fn open_input(path: &str) -> Result<Box<dyn Read>, AocError> {
+    if let Some(file) = path.strip_prefix("file://") {
+        return Ok(Box::new(fs::File::open(file)?));
+    }
+    if !is_url(path) {
+        return Ok(Box::new(fs::File::open(path)?));
+    }
+
+    debug!(url = path, "downloading input");
+    let agent = ureq::AgentBuilder::new()
+        .timeout(URL_TIMEOUT)
+        .user_agent("github.com/Shadow3xploit/AOC-2025")
+        .build();
+    match agent.get(path).call() {
+        Ok(response) => Ok(Box::new(response.into_reader())),
+        Err(ureq::Error::Status(status, _)) => Err(AocError::Network {
+            url: path.to_string(),
+            message: format!("HTTP {}", status),
+        }),
+        Err(err) => Err(AocError::Network {
+            url: path.to_string(),
+            message: err.to_string(),
+        }),
+    }
+}
+
+/// Returns whether an input path is an `http://` or `https://` URL.
+fn is_url(path: &str) -> bool {
+    path.starts_with("http://") || path.starts_with("https://")
+}
+
+/// Computes a 64-bit FNV-1a hash of the puzzle input.
+///
+/// Unlike [`std::hash::DefaultHasher`], the result is stable across Rust
+/// versions and platforms, so it can be stored and compared later to tell
+/// whether two runs used the same input.
+///
+/// # Examples
+/// ```
+/// use aoc2025::utils::hash_input;
+///
+/// assert_eq!(hash_input(""), 0xcbf29ce484222325);
+/// assert_ne!(hash_input("1-2"), hash_input("2-1"));
+/// ```
+pub fn hash_input(input: &str) -> u64 {
+    input.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+    })
+}
+
+/// Executes a solver on already loaded input and measures how long it takes.
+///
+/// The solver runs inside a `solve` span carrying the day and part, so events
+/// and spans emitted by the solver itself are attributed to the puzzle.
+///
+/// # Parameters
+/// - `input_duration`: How long reading the input took, stored in the report.
+/// - `iterations`: How often the solver is timed after the first run, see
+///   [`RunOptions::iterations`].
+///
+/// # Returns
+/// A [`RunReport`] with the result of the `solve` function and the timings.
+fn execute_puzzle<F>(
+    day: i32,
+    part: i32,
+    path: &str,
+    input: &str,
+    input_duration: Duration,
+    solve: F,
+    iterations: Option<NonZeroUsize>,
+) -> RunReport
+where
+    F: Fn(&str) -> Answer,
+{
+    let _span = debug_span!("solve", day, part).entered();
+
     let solve_start = Instant::now();
-    let result = solve(&input);
-    let solve_duration = solve_start.elapsed();
-    let overall_duration = overall_start.elapsed();
+    let (((outcome, parsed_in), allocations), peak_rss) = memory::measure_peak_rss(|| {
+        allocations::measure(|| {
+            progress::track(day, part, || {
+                solver::measure_parse(|| panics::catch(|| solve(input).to_string()))
+            })
+        })
+    });
+    let elapsed = solve_start.elapsed();
+    debug!(
+        ?elapsed,
+        ?parsed_in,
+        ?outcome,
+        ?allocations,
+        ?peak_rss,
+        "solved"
+    );
+    let (answer, panic) = split_outcome(day, part, outcome);
+    let (parse_samples, samples) = match panic {
+        None => time_iterations(input, &solve, iterations),
+        Some(_) => (Vec::new(), Vec::new()),
+    };
+    let (parse_duration, solve_duration) =
+        split_durations(elapsed, parsed_in, &parse_samples, &samples);
+    let cancelled = cancel::global().is_cancelled();
+    if cancelled {
+        warn!(day, part, ?solve_duration, "solver cancelled");
+    }
+
+    RunReport {
+        day,
+        part,
+        input_path: path.to_string(),
+        answer: if cancelled { String::new() } else { answer },
+        input_bytes: input.len(),
+        input_lines: input.lines().count(),
+        input_hash: hash_input(input),
+        input_duration,
+        parse_duration,
+        solve_duration,
+        samples,
+        timed_out: false,
+        cancelled,
+        panic,
+        allocations,
+        peak_rss,
+        environment: Some(Environment::current()),
+    }
+}
+
+/// Splits the outcome of a solver run with [`panics::catch`] into the answer
+/// and the panic, logging the panic.
+fn split_outcome(
+    day: i32,
+    part: i32,
+    outcome: Result<String, SolverPanic>,
+) -> (String, Option<SolverPanic>) {
+    match outcome {
+        Ok(answer) => (answer, None),
+        Err(panic) => {
+            warn!(day, part, %panic, "solver panicked");
+            (String::new(), Some(panic))
+        }
+    }
+}
+
+/// Times `iterations` further runs of a solver whose answer is already known.
+///
+/// Stops early once the [global cancel token](crate::cancel::global) is cancelled.
+///
+/// # Returns
+/// The parse durations of the iterations, if the solver records them (see
+/// [`measure_parse`](solver::measure_parse)), and the solve durations without
+/// parsing of every iteration; both are empty without iterations.
+fn time_iterations<F>(
+    input: &str,
+    solve: &F,
+    iterations: Option<NonZeroUsize>,
+) -> (Vec<Duration>, Vec<Duration>)
+where
+    F: Fn(&str) -> Answer,
+{
+    let Some(iterations) = iterations else {
+        return (Vec::new(), Vec::new());
+    };
+    let _span = debug_span!("iterations", iterations).entered();
+    let mut parse_samples: Vec<Duration> = Vec::with_capacity(iterations.get());
+    let mut samples: Vec<Duration> = Vec::with_capacity(iterations.get());
+    for _ in 0..iterations.get() {
+        if cancel::global().is_cancelled() {
+            break;
+        }
+        let start = Instant::now();
+        let (answer, parsed_in) = solver::measure_parse(|| solve(black_box(input)));
+        let elapsed = start.elapsed();
+        black_box(answer);
+        parse_samples.extend(parsed_in);
+        samples.push(elapsed.saturating_sub(parsed_in.unwrap_or_default()));
+    }
+    (parse_samples, samples)
+}
 
-    // --- Output ---
+/// Splits the time of the first run of a solver into parsing and solving.
+///
+/// # Parameters
+/// - `elapsed`: How long the first run took in total.
+/// - `parsed_in`: How much of it was spent parsing, if the solver records it.
+/// - `parse_samples`, `samples`: The durations of the timed iterations, see
+///   [`time_iterations`], whose medians are preferred over the first run.
+///
+/// # Returns
+/// The parse and the solve duration for the [`RunReport`].
+fn split_durations(
+    elapsed: Duration,
+    parsed_in: Option<Duration>,
+    parse_samples: &[Duration],
+    samples: &[Duration],
+) -> (Duration, Duration) {
+    let parsed_in = parsed_in.unwrap_or_default();
+    let parse_duration = Stats::from_samples(parse_samples).map_or(parsed_in, |stats| stats.median);
+    let solve_duration = Stats::from_samples(samples)
+        .map_or(elapsed.saturating_sub(parsed_in), |stats| stats.median);
+    (parse_duration, solve_duration)
+}
+
+/// Like [`execute_puzzle`], but gives up waiting for the solver after the
+/// [`timeout`](RunOptions::timeout) of `run_options`.
+///
+/// The solver runs on a separate thread with its own copy of the input. A
+/// panic in the solver is caught on that thread. The timeout covers the
+/// timed iterations as well; without one, the solver is awaited however long it takes.
+fn execute_puzzle_with_timeout(
+    day: i32,
+    part: i32,
+    path: &str,
+    input: &str,
+    input_duration: Duration,
+    solve: Solver,
+    run_options: &RunOptions,
+) -> RunReport {
+    let timeout = run_options.timeout.unwrap_or(Duration::MAX);
+    let iterations = run_options.iterations;
+    let (sender, receiver) = mpsc::channel();
+    let owned_input = input.to_string();
+    let worker = thread::spawn(move || {
+        let _span = debug_span!("solve", day, part).entered();
+        let solve_start = Instant::now();
+        let (((outcome, parsed_in), allocations), peak_rss) = memory::measure_peak_rss(|| {
+            allocations::measure(|| {
+                progress::track(day, part, || {
+                    solver::measure_parse(|| panics::catch(|| solve(&owned_input).to_string()))
+                })
+            })
+        });
+        let elapsed = solve_start.elapsed();
+        debug!(
+            ?elapsed,
+            ?parsed_in,
+            ?outcome,
+            ?allocations,
+            ?peak_rss,
+            "solved"
+        );
+        let (parse_samples, samples) = match outcome {
+            Ok(_) => time_iterations(&owned_input, &solve, iterations),
+            Err(_) => (Vec::new(), Vec::new()),
+        };
+        let durations = split_durations(elapsed, parsed_in, &parse_samples, &samples);
+        // The receiver is gone if the solver timed out, so the result is simply dropped.
+        let _ = sender.send((outcome, durations, samples, allocations, peak_rss));
+    });
+
+    let (answer, panic, durations, samples, timed_out, allocations, peak_rss) =
+        match receiver.recv_timeout(timeout) {
+            Ok((outcome, durations, samples, allocations, peak_rss)) => {
+                let (answer, panic) = split_outcome(day, part, outcome);
+                (
+                    answer,
+                    panic,
+                    durations,
+                    samples,
+                    false,
+                    allocations,
+                    peak_rss,
+                )
+            }
+            Err(RecvTimeoutError::Timeout) => {
+                warn!(day, part, ?timeout, "solver timed out");
+                let durations = (Duration::ZERO, timeout);
+                (String::new(), None, durations, Vec::new(), true, None, None)
+            }
+            Err(RecvTimeoutError::Disconnected) => match worker.join() {
+                Err(panic) => std::panic::resume_unwind(panic),
+                Ok(()) => unreachable!("the solver thread always sends its answer"),
+            },
+        };
+    let (parse_duration, solve_duration) = durations;
+    let cancelled = !timed_out && cancel::global().is_cancelled();
+    if cancelled {
+        warn!(day, part, ?solve_duration, "solver cancelled");
+    }
+
+    RunReport {
+        day,
+        part,
+        input_path: path.to_string(),
+        answer: if cancelled { String::new() } else { answer },
+        input_bytes: input.len(),
+        input_lines: input.lines().count(),
+        input_hash: hash_input(input),
+        input_duration,
+        parse_duration,
+        solve_duration,
+        samples,
+        timed_out,
+        cancelled,
+        panic,
+        allocations,
+        peak_rss,
+        environment: Some(Environment::current()),
+    }
+}
+
+/// Prints the metadata, timings, and result of a single run to stdout.
+///
+/// With [`Verbosity::Quiet`] only the answer is printed. Higher verbosity
+/// levels add input statistics and exact durations.
+///
+/// # Parameters
+/// - `report`: The report of the run to print.
+/// - `options`: Controls how much is printed.
+pub fn print_report(report: &RunReport, options: &OutputOptions) {
+    write_report(&mut io::stdout().lock(), report, options).expect("failed printing to stdout");
+}
+
+/// Writes the report printed by [`print_report`] to `out`.
+///
+/// # Returns
+/// An I/O error if writing to `out` failed.
+///
+/// # Examples
+/// ```
+/// use aoc2025::utils::{ColorChoice, OutputOptions, RunReport, Verbosity, write_report};
+///
+/// let report = RunReport {
+///     day: 1,
+///     part: 2,
+///     answer: "6".to_string(),
+///     ..RunReport::default()
+/// };
+/// let options = OutputOptions {
+///     verbosity: Verbosity::Quiet,
+///     color: ColorChoice::Never,
+///     max_width: None,
+/// };
+/// let mut out: Vec<u8> = Vec::new();
+/// write_report(&mut out, &report, &options).unwrap();
+/// assert_eq!(out, b"6\n");
+/// ```
+pub fn write_report(
+    out: &mut impl Write,
+    report: &RunReport,
+    options: &OutputOptions,
+) -> io::Result<()> {
+    if options.verbosity == Verbosity::Quiet {
+        writeln!(out, "{}", report.display_answer())?;
+        return Ok(());
+    }
+    let use_color = options.color.enabled();
+    let input: &str = if report.input_path == STDIN_PATH {
+        "<stdin>"
+    } else {
+        &report.input_path
+    };
+
+    let title = registry::metadata(report.day)
+        .map(|metadata| metadata.title.to_string())
+        .or_else(|| puzzle::title(config::current().year, report.day));
+    let day = match title {
+        Some(title) => format!("{} ({})", report.day, title),
+        None => report.day.to_string(),
+    };
+
+    if use_color {
+        writeln!(out, "\x1b[36m--- Advent of Code ---\x1b[0m")?;
+        writeln!(out, "\x1b[34mDay:\x1b[0m  {}", day)?;
+        writeln!(out, "\x1b[34mPart:\x1b[0m {}", report.part)?;
+        writeln!(out, "\x1b[34mInput:\x1b[0m {}", input)?;
+    } else {
+        writeln!(out, "--- Advent of Code ---")?;
+        writeln!(out, "Day:  {}", day)?;
+        writeln!(out, "Part: {}", report.part)?;
+        writeln!(out, "Input: {}", input)?;
+    }
+    if options.verbosity >= Verbosity::Verbose {
+        writeln!(
+            out,
+            "  Size:  {} bytes, {} lines",
+            report.input_bytes, report.input_lines
+        )?;
+    }
+    let mut timings = vec![("Input read", format_duration(report.input_duration))];
+    if !report.parse_duration.is_zero() {
+        timings.push(("Parse", format_duration(report.parse_duration)));
+    }
+    timings.push(match report.best_duration() {
+        Some(best) => (
+            "Solve",
+            format!(
+                "{} (median of {} runs, best {})",
+                format_duration(report.solve_duration),
+                report.samples.len(),
+                format_duration(best)
+            ),
+        ),
+        None => ("Solve", format_duration(report.solve_duration)),
+    });
+    timings.push(("Total", format_duration(report.total_duration())));
+    if let Some(throughput) = report.throughput() {
+        timings.push(("Throughput", format_throughput(throughput)));
+    }
+    if options.verbosity >= Verbosity::Debug {
+        timings.push((
+            "Exact",
+            format!(
+                "input read {:?}, parse {:?}, solve {:?}",
+                report.input_duration, report.parse_duration, report.solve_duration
+            ),
+        ));
+    }
+
+    let mut statistics = Vec::new();
+    if let Some(stats) = report.stats() {
+        statistics.push((
+            "Mean",
+            format!(
+                "{} ± {}",
+                format_duration(stats.mean),
+                format_duration(stats.std_dev)
+            ),
+        ));
+        statistics.push(("Median", format_duration(stats.median)));
+        statistics.push(("p95", format_duration(stats.p95)));
+        statistics.push((
+            "Range",
+            format!(
+                "{} .. {}",
+                format_duration(stats.min),
+                format_duration(stats.max)
+            ),
+        ));
+        statistics.push((
+            "Outliers",
+            format!("{} of {} runs", stats.outliers.len(), stats.count),
+        ));
+    }
+
+    let mut usage = Vec::new();
+    if let Some(peak_rss) = report.peak_rss {
+        usage.push(("Peak RSS", memory::format_bytes(peak_rss)));
+    }
+    if let Some(stats) = report.allocations {
+        usage.push(("Allocations", stats.allocations.to_string()));
+        usage.push(("Allocated", memory::format_bytes(stats.bytes)));
+    }
+
+    let mut build = Vec::new();
+    if let Some(environment) = report.environment
+        && options.verbosity >= Verbosity::Verbose
+    {
+        build.push(("Build", environment.to_string()));
+        if let Some(cpu) = &environment.cpu {
+            build.push(("CPU", cpu.clone()));
+        }
+    }
+
+    let mut panic = Vec::new();
+    if let Some(caught) = &report.panic {
+        panic.push((
+            "Location",
+            caught.location.as_deref().unwrap_or("unknown").to_string(),
+        ));
+        panic.push(("Message", caught.message.clone()));
+    }
+
+    let sections = [
+        Section {
+            title: "Solver panicked",
+            color: RED,
+            fields: panic,
+        },
+        Section {
+            title: "Timings",
+            color: YELLOW,
+            fields: timings,
+        },
+        Section {
+            title: "Statistics",
+            color: YELLOW,
+            fields: statistics,
+        },
+        Section {
+            title: "Memory",
+            color: YELLOW,
+            fields: usage,
+        },
+        Section {
+            title: "Environment",
+            color: YELLOW,
+            fields: build,
+        },
+    ];
+    write_sections(out, &sections, use_color)?;
+    writeln!(out)?;
     if use_color {
-        println!("\x1b[36m--- Advent of Code ---\x1b[0m");
-        println!("\x1b[34mDay:\x1b[0m  {}", day);
-        println!("\x1b[34mPart:\x1b[0m {}", part);
-        println!("\x1b[34mInput:\x1b[0m {}", path);
-        println!();
-        println!("\x1b[33mTimings:\x1b[0m");
-        println!("  Input read:  {:.3} ms", duration_ms(input_duration));
-        println!("  Solve:       {:.3} ms", duration_ms(solve_duration));
-        println!("  Total:       {:.3} ms", duration_ms(overall_duration));
-        println!();
-        println!("\x1b[32mResult:\x1b[0m {}", result);
+        let color = if report.panic.is_some() { RED } else { GREEN };
+        writeln!(out, "{}Result:\x1b[0m {}", color, report.display_answer())?;
     } else {
-        println!("--- Advent of Code ---");
-        println!("Day:  {}", day);
-        println!("Part: {}", part);
-        println!("Input: {}", path);
-        println!();
-        println!("Timings:");
-        println!("  Input read:  {:.3} ms", duration_ms(input_duration));
-        println!("  Solve:       {:.3} ms", duration_ms(solve_duration));
-        println!("  Total:       {:.3} ms", duration_ms(overall_duration));
-        println!();
-        println!("Result: {}", result);
+        writeln!(out, "Result: {}", report.display_answer())?;
     }
+    Ok(())
+}
+
+/// The escape sequences coloring report headings.
+const RED: &str = "\x1b[31m";
+const GREEN: &str = "\x1b[32m";
+const YELLOW: &str = "\x1b[33m";
+
+/// A titled block of `label: value` lines in a report, see [`write_sections`].
+struct Section {
+    title: &'static str,
+    color: &'static str,
+    fields: Vec<(&'static str, String)>,
+}
+
+/// Writes the non-empty sections of a report, each after an empty line.
+///
+/// The values of all sections start in the same column, two spaces after the
+/// longest label, so adding a field never requires realigning the others.
+fn write_sections(out: &mut impl Write, sections: &[Section], use_color: bool) -> io::Result<()> {
+    let label_width = sections
+        .iter()
+        .flat_map(|section| &section.fields)
+        .map(|(label, _)| label.len() + ":".len())
+        .max()
+        .unwrap_or(0);
 
-    Ok(result)
+    for section in sections.iter().filter(|section| !section.fields.is_empty()) {
+        writeln!(out)?;
+        if use_color {
+            writeln!(out, "{}{}:\x1b[0m", section.color, section.title)?;
+        } else {
+            writeln!(out, "{}:", section.title)?;
+        }
+        for (label, value) in &section.fields {
+            let label = format!("{}:", label);
+            writeln!(out, "  {:<label_width$}  {}", label, value)?;
+        }
+    }
+    Ok(())
 }
 
-/// Converts a `Duration` to milliseconds as a floating point number.
+/// Prints a summary table of several runs to stdout.
+///
+/// The table contains one row per report with the day, part, answer, and
+/// timings, followed by a row with the summed timings of all runs. Long
+/// answers are cut off to fit the table into [`OutputOptions::max_width`]. With
+/// [`Verbosity::Quiet`] only the answers are printed, one per line.
 ///
 /// # Parameters
-/// - `duration`: A `std::time::Duration` to convert.
+/// - `reports`: The reports to summarize, printed in the given order.
+/// - `options`: Controls how much is printed.
+pub fn print_summary(reports: &[RunReport], options: &OutputOptions) {
+    write_summary(&mut io::stdout().lock(), reports, options).expect("failed printing to stdout");
+}
+
+/// Writes the summary table printed by [`print_summary`] to `out`.
 ///
 /// # Returns
-/// A `f64` representing the duration in milliseconds.
+/// An I/O error if writing to `out` failed.
+pub fn write_summary(
+    out: &mut impl Write,
+    reports: &[RunReport],
+    options: &OutputOptions,
+) -> io::Result<()> {
+    if options.verbosity == Verbosity::Quiet {
+        for report in reports {
+            writeln!(out, "{}", report.display_answer())?;
+        }
+        return Ok(());
+    }
+
+    for line in format_summary(reports, options.max_width) {
+        writeln!(out, "{}", line)?;
+    }
+    Ok(())
+}
+
+/// Formats the summary table printed by [`print_summary`] into lines.
+///
+/// The total of every row is the sum of its input read, parse and solve
+/// columns. The answer column is cut off first if the table is wider than
+/// `max_width`.
+fn format_summary(reports: &[RunReport], max_width: Option<usize>) -> Vec<String> {
+    let mut table = Table::new(&[
+        ("Day", Align::Right),
+        ("Part", Align::Right),
+        ("Answer", Align::Left),
+        ("Input read", Align::Right),
+        ("Parse", Align::Right),
+        ("Solve", Align::Right),
+        ("Total", Align::Right),
+    ])
+    .flexible(2);
+
+    for report in reports {
+        table.row([
+            report.day.to_string(),
+            report.part.to_string(),
+            report.display_answer().to_string(),
+            format_duration(report.input_duration),
+            format_duration(report.parse_duration),
+            format_duration(report.solve_duration),
+            format_duration(report.total_duration()),
+        ]);
+    }
+
+    let input_total: Duration = reports.iter().map(|report| report.input_duration).sum();
+    let parse_total: Duration = reports.iter().map(|report| report.parse_duration).sum();
+    let solve_total: Duration = reports.iter().map(|report| report.solve_duration).sum();
+    let total: Duration = reports.iter().map(RunReport::total_duration).sum();
+    table.separator();
+    table.row([
+        String::new(),
+        String::new(),
+        "Total".to_string(),
+        format_duration(input_total),
+        format_duration(parse_total),
+        format_duration(solve_total),
+        format_duration(total),
+    ]);
+
+    table.render(max_width)
+}
+
+/// Formats a duration in the largest unit below it, with three decimals.
+///
+/// Durations under a microsecond are shown in whole nanoseconds, so fast
+/// solvers do not all show up as `"0.000 ms"`.
 ///
 /// # Examples
 /// ```
 /// use std::time::Duration;
-/// let d = Duration::from_millis(1234);
-/// assert_eq!(super::duration_ms(d), 1234.0);
+/// use aoc2025::utils::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_nanos(850)), "850 ns");
+/// assert_eq!(format_duration(Duration::from_nanos(12_345)), "12.345 µs");
+/// assert_eq!(format_duration(Duration::from_micros(1_500)), "1.500 ms");
+/// assert_eq!(format_duration(Duration::from_millis(2_250)), "2.250 s");
 /// ```
-fn duration_ms(duration: std::time::Duration) -> f64 {
-    duration.as_secs_f64() * 1000.0
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos < 1_000 {
+        format!("{} ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.3} µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.3} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.3} s", duration.as_secs_f64())
+    }
+}
+
+/// Formats the [`throughput`](RunReport::throughput) of a run, e.g.
+/// `"12.3 MB/s, 45678 lines/s"`.
+fn format_throughput((bytes_per_second, lines_per_second): (f64, f64)) -> String {
+    format!(
+        "{:.1} MB/s, {:.0} lines/s",
+        bytes_per_second / 1e6,
+        lines_per_second
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(day: i32, part: i32, answer: &str, solve_ms: u64) -> RunReport {
+        RunReport {
+            day,
+            part,
+            input_path: format!("inputs/day{:02}.txt", day),
+            answer: answer.to_string(),
+            input_bytes: 0,
+            input_lines: 0,
+            input_hash: 0,
+            input_duration: Duration::from_millis(1),
+            parse_duration: Duration::ZERO,
+            solve_duration: Duration::from_millis(solve_ms),
+            samples: Vec::new(),
+            timed_out: false,
+            cancelled: false,
+            panic: None,
+            allocations: None,
+            peak_rss: None,
+            environment: None,
+        }
+    }
+
+    #[test]
+    fn test_color_enabled_explicit_choice() {
+        assert!(color_enabled(ColorChoice::Always, Some("1"), None, false));
+        assert!(!color_enabled(ColorChoice::Never, None, Some("1"), true));
+    }
+
+    #[test]
+    fn test_color_enabled_auto() {
+        assert!(color_enabled(ColorChoice::Auto, None, None, true));
+        assert!(!color_enabled(ColorChoice::Auto, None, None, false));
+        assert!(!color_enabled(ColorChoice::Auto, Some("1"), None, true));
+        assert!(color_enabled(ColorChoice::Auto, Some(""), None, true));
+        assert!(color_enabled(ColorChoice::Auto, None, Some("1"), false));
+        assert!(!color_enabled(ColorChoice::Auto, None, Some("0"), false));
+        assert!(!color_enabled(
+            ColorChoice::Auto,
+            Some("1"),
+            Some("1"),
+            true
+        ));
+    }
+
+    #[test]
+    fn test_execute_puzzle() {
+        let length = |input: &str| Answer::from(input.len());
+        let result = execute_puzzle(3, 2, "in.txt", "abc", Duration::ZERO, length, None);
+        assert_eq!(result.day, 3);
+        assert_eq!(result.part, 2);
+        assert_eq!(result.input_path, "in.txt");
+        assert_eq!(result.answer, "3");
+        assert_eq!(result.input_bytes, 3);
+        assert_eq!(result.input_lines, 1);
+        assert_eq!(result.input_hash, hash_input("abc"));
+        assert!(result.samples.is_empty());
+
+        let iterations = NonZeroUsize::new(5);
+        let result = execute_puzzle(3, 2, "in.txt", "abc", Duration::ZERO, length, iterations);
+        assert_eq!(result.answer, "3");
+        assert_eq!(result.samples.len(), 5);
+        assert_eq!(result.solve_duration, result.stats().unwrap().median);
+        assert!(result.best_duration().unwrap() <= result.solve_duration);
+    }
+
+    #[test]
+    fn test_split_durations() {
+        let ms = Duration::from_millis;
+        assert_eq!(
+            split_durations(ms(10), Some(ms(4)), &[], &[]),
+            (ms(4), ms(6))
+        );
+        assert_eq!(split_durations(ms(10), None, &[], &[]), (ms(0), ms(10)));
+        assert_eq!(
+            split_durations(
+                ms(10),
+                Some(ms(4)),
+                &[ms(3), ms(2), ms(9)],
+                &[ms(1), ms(5), ms(2)]
+            ),
+            (ms(3), ms(2))
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn test_execute_puzzle_times_parse() {
+        use crate::day01::Day01;
+        use crate::solver::solve_part1;
+
+        let input = "R5\nL55\n".repeat(1000);
+        let input = input.trim_end();
+        let iterations = NonZeroUsize::new(3);
+        let report = execute_puzzle(
+            1,
+            1,
+            "in.txt",
+            input,
+            Duration::ZERO,
+            solve_part1::<Day01>,
+            iterations,
+        );
+        assert!(!report.parse_duration.is_zero());
+        assert_eq!(report.samples.len(), 3);
+
+        let report = execute_puzzle(1, 1, "in.txt", input, Duration::ZERO, |_| 1.into(), None);
+        assert_eq!(report.parse_duration, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_execute_puzzle_catches_panic() {
+        fn broken(input: &str) -> Answer {
+            input.parse::<i64>().expect("not a number").into()
+        }
+
+        let iterations = NonZeroUsize::new(3);
+        let report = execute_puzzle(3, 1, "in.txt", "abc", Duration::ZERO, broken, iterations);
+        let panic = report.panic.as_ref().unwrap();
+        assert!(panic.message.starts_with("not a number"));
+        assert!(
+            panic
+                .location
+                .as_ref()
+                .unwrap()
+                .starts_with("src/utils.rs:")
+        );
+        assert_eq!(report.answer, "");
+        assert_eq!(report.display_answer(), "panicked");
+        assert!(!report.finished());
+        assert!(report.samples.is_empty());
+
+        let run_options = RunOptions {
+            timeout: Some(Duration::from_secs(5)),
+            iterations,
+        };
+        let report = execute_puzzle_with_timeout(
+            3,
+            1,
+            "in.txt",
+            "abc",
+            Duration::ZERO,
+            broken,
+            &run_options,
+        );
+        assert!(!report.timed_out);
+        assert!(report.panic.is_some());
+        assert_eq!(report.display_answer(), "panicked");
+    }
+
+    #[test]
+    fn test_run_days_keeps_order() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025-run-days-{}.txt", std::process::id()));
+        fs::write(&path, "abc").unwrap();
+
+        fn length(input: &str) -> Answer {
+            input.len().into()
+        }
+        fn slow(input: &str) -> Answer {
+            thread::sleep(Duration::from_millis(20));
+            input.to_uppercase().into()
+        }
+        let days: Vec<(i32, Vec<(i32, Solver)>)> = vec![
+            (1, vec![(1, slow), (2, length)]),
+            (2, vec![(1, length)]),
+            (3, vec![(2, slow)]),
+        ];
+
+        for jobs in [1, 3, 8] {
+            let reports = run_days(&days, path.to_str(), jobs, &RunOptions::default()).unwrap();
+            let summary: Vec<(i32, i32, &str)> = reports
+                .iter()
+                .map(|report| (report.day, report.part, report.answer.as_str()))
+                .collect();
+            assert_eq!(
+                summary,
+                vec![(1, 1, "ABC"), (1, 2, "3"), (2, 1, "3"), (3, 2, "ABC")]
+            );
+        }
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn test_run_day_parses_once() {
+        let parts = registry::parts(1);
+        let reports = run_day(1, &parts, Some(EXAMPLE_PATH)).unwrap();
+        let answers: Vec<(i32, &str)> = reports
+            .iter()
+            .map(|report| (report.part, report.answer.as_str()))
+            .collect();
+        assert_eq!(answers, vec![(1, "3"), (2, "6")]);
+        assert_eq!(reports[1].parse_duration, Duration::ZERO);
+
+        let run_options = RunOptions {
+            timeout: None,
+            iterations: NonZeroUsize::new(2),
+        };
+        let reports = run_day_with(1, &parts, Some(EXAMPLE_PATH), &run_options).unwrap();
+        assert_eq!(reports[1].samples.len(), 2);
+    }
+
+    #[test]
+    #[cfg(feature = "day01")]
+    fn test_run_both() {
+        use crate::day01::Day01;
+        use crate::solver::solve_both_timed;
+
+        let reports = run_both(1, solve_both_timed::<Day01>, Some(EXAMPLE_PATH)).unwrap();
+        let answers: Vec<(i32, &str)> = reports
+            .iter()
+            .map(|report| (report.part, report.answer.as_str()))
+            .collect();
+        assert_eq!(answers, vec![(1, "3"), (2, "6")]);
+        assert_eq!(reports[1].input_duration, Duration::ZERO);
+        assert_eq!(reports[1].parse_duration, Duration::ZERO);
+        assert_eq!(reports[0].input_hash, reports[1].input_hash);
+    }
+
+    #[test]
+    fn test_execute_puzzle_with_timeout() {
+        fn fast(input: &str) -> Answer {
+            input.len().into()
+        }
+        fn slow(_: &str) -> Answer {
+            thread::sleep(Duration::from_secs(2));
+            "late".into()
+        }
+
+        let run_options = RunOptions {
+            timeout: Some(Duration::from_secs(5)),
+            iterations: NonZeroUsize::new(3),
+        };
+        let report =
+            execute_puzzle_with_timeout(1, 1, "in.txt", "abc", Duration::ZERO, fast, &run_options);
+        assert!(!report.timed_out);
+        assert_eq!(report.display_answer(), "3");
+        assert_eq!(report.samples.len(), 3);
+
+        let timeout = Duration::from_millis(10);
+        let run_options = RunOptions {
+            timeout: Some(timeout),
+            iterations: None,
+        };
+        let report =
+            execute_puzzle_with_timeout(1, 2, "in.txt", "abc", Duration::ZERO, slow, &run_options);
+        assert!(report.timed_out);
+        assert_eq!(report.answer, "");
+        assert_eq!(report.display_answer(), "timed out");
+        assert_eq!(report.solve_duration, timeout);
+        assert_eq!(report.input_bytes, 3);
+        assert!(matches!(
+            report.into_result(),
+            Err(AocError::Timeout { day: 1, part: 2, budget }) if budget == timeout
+        ));
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30s"), Ok(Duration::from_secs(30)));
+        assert_eq!(parse_duration("15"), Ok(Duration::from_secs(15)));
+        assert_eq!(parse_duration("1.5s"), Ok(Duration::from_millis(1500)));
+        assert_eq!(parse_duration("2m"), Ok(Duration::from_secs(120)));
+        assert_eq!(parse_duration("1h"), Ok(Duration::from_secs(3600)));
+        assert_eq!(parse_duration("100ms"), Ok(Duration::from_millis(100)));
+        assert!(parse_duration("").is_err());
+        assert!(parse_duration("5d").is_err());
+        assert!(parse_duration("s").is_err());
+    }
+
+    #[test]
+    fn test_resolve_input_path_explicit() {
+        let result = resolve_input_path(1, 1, Some("custom.txt")).unwrap();
+        assert_eq!(result, "custom.txt");
+    }
+
+    #[test]
+    fn test_resolve_input_path_stdin() {
+        let result = resolve_input_path(1, 1, Some(STDIN_PATH)).unwrap();
+        assert_eq!(result, STDIN_PATH);
+    }
+
+    #[test]
+    fn test_resolve_input_path_example_missing() {
+        let result = resolve_input_path(99, 1, Some(EXAMPLE_PATH)).unwrap_err();
+        assert!(matches!(result, AocError::InputNotFound { .. }));
+        assert!(
+            result
+                .to_string()
+                .contains("inputs/examples/day99_part1.txt")
+        );
+    }
+
+    #[cfg(all(feature = "async", feature = "day03"))]
+    #[test]
+    fn test_run_puzzle_async() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025-run-async-{}.txt", std::process::id()));
+        fs::write(&path, "abc").unwrap();
+        let path = path.to_str().unwrap().to_string();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let (file, example, missing) = runtime.block_on(async {
+            let file = tokio::spawn(run_puzzle_async(
+                99,
+                1,
+                Some(path.clone()),
+                |input: &str| Answer::from(input.len()),
+            ));
+            let example = tokio::spawn(run_puzzle_async(
+                3,
+                1,
+                Some(EXAMPLE_PATH.to_string()),
+                crate::solver::solve_part1::<crate::day03::Day03>,
+            ));
+            let missing = run_puzzle_async(99, 1, Some("missing.txt".to_string()), |_: &str| {
+                Answer::from(0)
+            })
+            .await;
+            (file.await.unwrap(), example.await.unwrap(), missing)
+        });
+        fs::remove_file(&path).unwrap();
+
+        let file = file.unwrap();
+        assert_eq!(
+            (file.answer.as_str(), file.input_path.as_str()),
+            ("3", path.as_str())
+        );
+        assert_eq!(file.input_hash, hash_input("abc"));
+        assert_eq!(
+            example.unwrap().answer,
+            registry::example_answer(3, 1).unwrap()
+        );
+        assert!(matches!(missing, Err(AocError::Io(err)) if err.kind() == io::ErrorKind::NotFound));
+    }
+
+    #[test]
+    #[cfg(feature = "day03")]
+    fn test_resolve_input_path_embedded_example() {
+        let path = resolve_input_path(3, 2, Some(EXAMPLE_PATH)).unwrap();
+        assert_eq!(path, "@example:day03");
+        assert_eq!(read_input(&path).unwrap(), registry::example(3).unwrap());
+        assert!(uses_embedded_example(3, 2));
+    }
+
+    #[test]
+    fn test_read_gzip_input() {
+        use flate2::Compression;
+        use flate2::write::GzEncoder;
+        use std::io::Write;
+
+        let dir = std::env::temp_dir().join(format!("aoc2025-gzip-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"3-5\n10-14\n\n1\n5").unwrap();
+        fs::write(dir.join("day05.txt.gz"), encoder.finish().unwrap()).unwrap();
+
+        let dirs = [dir.to_str().unwrap().to_string()];
+        let path = select_input(&dirs, 5, 1).unwrap();
+        let input = read_input(&path);
+        fs::write(dir.join("day05.txt"), "plain").unwrap();
+        let preferred = select_input(&dirs, 5, 1).unwrap();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert!(path.ends_with("day05.txt.gz"));
+        assert_eq!(input.unwrap(), "3-5\n10-14\n\n1\n5");
+        assert!(preferred.ends_with("day05.txt"));
+    }
+
+    #[test]
+    fn test_select_input_dirs() {
+        let dir = std::env::temp_dir().join(format!("aoc2025-dirs-{}", std::process::id()));
+        fs::create_dir_all(dir.join("2025")).unwrap();
+        fs::write(dir.join("day02.txt"), "legacy").unwrap();
+        let dirs = [
+            dir.join("2025").to_str().unwrap().to_string(),
+            dir.to_str().unwrap().to_string(),
+        ];
+        let legacy = select_input(&dirs, 2, 1).unwrap();
+        fs::write(dir.join("2025/day02.txt"), "current").unwrap();
+        let current = select_input(&dirs, 2, 1).unwrap();
+        let missing = select_input(&dirs, 3, 1).unwrap_err();
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(legacy, format!("{}/day02.txt", dirs[1]));
+        assert_eq!(current, format!("{}/day02.txt", dirs[0]));
+        assert!(
+            missing
+                .to_string()
+                .contains(&format!("{}/day03.txt", dirs[1]))
+        );
+    }
+
+    #[test]
+    fn test_read_file_url() {
+        let path = std::env::temp_dir().join(format!("aoc2025-url-{}.txt", std::process::id()));
+        fs::write(&path, "L68\nR48").unwrap();
+        let input = read_input(&format!("file://{}", path.display()));
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(input.unwrap(), "L68\nR48");
+        assert!(is_url("https://gist.githubusercontent.com/x/raw/day02.txt"));
+        assert!(!is_url("inputs/http.txt"));
+    }
+
+    #[test]
+    fn test_resolve_input_path_missing() {
+        let result = resolve_input_path(99, 1, None).unwrap_err();
+        let AocError::InputNotFound { tried, download } = result else {
+            panic!("expected a missing input, got {:?}", result);
+        };
+        assert!(tried.contains(&"inputs/2025/day99_part1.txt.gz".to_string()));
+        assert_eq!(download, None);
+    }
+
+    #[test]
+    fn test_format_summary() {
+        let reports = [
+            RunReport {
+                parse_duration: Duration::from_millis(4),
+                ..report(1, 1, "3", 2)
+            },
+            report(1, 2, "1234567", 3),
+        ];
+        assert_eq!(
+            format_summary(&reports, None),
+            vec![
+                "Day  Part  Answer   Input read     Parse     Solve      Total",
+                "-------------------------------------------------------------",
+                "  1     1  3          1.000 ms  4.000 ms  2.000 ms   7.000 ms",
+                "  1     2  1234567    1.000 ms      0 ns  3.000 ms   4.000 ms",
+                "-------------------------------------------------------------",
+                "           Total      2.000 ms  4.000 ms  5.000 ms  11.000 ms",
+            ]
+        );
+
+        // Only the answers are cut off to fit the width.
+        let narrow = format_summary(&reports, Some(56));
+        assert_eq!(
+            narrow[0],
+            "Day  Part  Answer  Input read     Parse     Solve      Total"
+        );
+        assert_eq!(
+            narrow[3],
+            "  1     2  12345…    1.000 ms      0 ns  3.000 ms   4.000 ms"
+        );
+    }
+
+    #[test]
+    fn test_throughput() {
+        let measured = RunReport {
+            input_bytes: 2_000_000,
+            input_lines: 4_000,
+            parse_duration: Duration::from_millis(250),
+            ..report(1, 1, "42", 750)
+        };
+        assert_eq!(measured.throughput(), Some((2e6, 4e3)));
+        assert_eq!(
+            format_throughput(measured.throughput().unwrap()),
+            "2.0 MB/s, 4000 lines/s"
+        );
+
+        assert_eq!(report(1, 1, "42", 750).throughput(), None);
+        let timed_out = RunReport {
+            timed_out: true,
+            ..measured
+        };
+        assert_eq!(timed_out.throughput(), None);
+    }
+
+    #[test]
+    fn test_write_report() {
+        let options = OutputOptions {
+            verbosity: Verbosity::Verbose,
+            color: ColorChoice::Never,
+            max_width: None,
+        };
+        let mut out: Vec<u8> = Vec::new();
+        write_report(&mut out, &report(99, 2, "42", 5), &options).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "--- Advent of Code ---\n\
+             Day:  99\n\
+             Part: 2\n\
+             Input: inputs/day99.txt\n  \
+             Size:  0 bytes, 0 lines\n\
+             \n\
+             Timings:\n  \
+             Input read:  1.000 ms\n  \
+             Solve:       5.000 ms\n  \
+             Total:       6.000 ms\n\
+             \n\
+             Result: 42\n"
+        );
+
+        let mut out: Vec<u8> = Vec::new();
+        let repeated = RunReport {
+            samples: [4, 5, 6, 5, 20].map(Duration::from_millis).to_vec(),
+            ..report(99, 2, "42", 5)
+        };
+        write_report(&mut out, &repeated, &options).unwrap();
+        assert!(String::from_utf8(out).unwrap().contains(
+            "Statistics:\n  \
+             Mean:        8.000 ms ± 6.745 ms\n  \
+             Median:      5.000 ms\n  \
+             p95:         17.200 ms\n  \
+             Range:       4.000 ms .. 20.000 ms\n  \
+             Outliers:    1 of 5 runs\n"
+        ));
+
+        let mut out: Vec<u8> = Vec::new();
+        let panicked = RunReport {
+            answer: String::new(),
+            panic: Some(SolverPanic {
+                message: "boom".to_string(),
+                location: Some("src/day99/part2.rs:3:5".to_string()),
+            }),
+            ..report(99, 2, "42", 5)
+        };
+        write_report(&mut out, &panicked, &options).unwrap();
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains(
+            "Solver panicked:\n  \
+             Location:    src/day99/part2.rs:3:5\n  \
+             Message:     boom\n\
+             \n\
+             Timings:\n"
+        ));
+        assert!(output.ends_with("Result: panicked\n"));
+
+        let mut out: Vec<u8> = Vec::new();
+        let reports = [report(1, 1, "3", 2), report(1, 2, "6", 3)];
+        let quiet = OutputOptions {
+            verbosity: Verbosity::Quiet,
+            ..options
+        };
+        write_summary(&mut out, &reports, &quiet).unwrap();
+        assert_eq!(out, b"3\n6\n");
+    }
+
+    #[test]
+    fn test_run_puzzle_returns_report() {
+        let path =
+            std::env::temp_dir().join(format!("aoc2025-run-puzzle-{}.txt", std::process::id()));
+        fs::write(&path, "abc\ndef").unwrap();
+        let options = OutputOptions {
+            verbosity: Verbosity::Normal,
+            color: ColorChoice::Never,
+            max_width: None,
+        };
+
+        let mut out: Vec<u8> = Vec::new();
+        let report = run_puzzle_with(
+            99,
+            1,
+            path.to_str(),
+            |input| input.len().into(),
+            &options,
+            &mut out,
+        )
+        .unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!((report.day, report.part), (99, 1));
+        assert_eq!(report.answer, "7");
+        assert_eq!(report.input_lines, 2);
+        let output = String::from_utf8(out).unwrap();
+        assert!(output.contains(&format!("Input: {}\n", path.display())));
+        assert!(output.ends_with("Result: 7\n"));
+    }
 }
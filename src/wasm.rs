@@ -0,0 +1,39 @@
+use wasm_bindgen::prelude::*;
+
+/// Solves one puzzle from JavaScript, like [`crate::solve`].
+///
+/// Exported as `solve(day, part, input)`. The input is validated first, so
+/// a malformed input throws instead of trapping the module.
+///
+/// # Returns
+/// The answer as string, or an `Error` carrying the message of the
+/// [`Error`](crate::Error) for an unknown puzzle or a malformed input.
+#[wasm_bindgen]
+pub fn solve(day: i32, part: i32, input: &str) -> Result<String, JsError> {
+    crate::solve(day, part, input)
+        .map(|answer| answer.to_string())
+        .map_err(|err| JsError::new(&err.to_string()))
+}
+
+/// Returns every solvable puzzle as flat `[day, part, day, part, ...]` pairs,
+/// like [`crate::puzzles`].
+#[wasm_bindgen]
+pub fn puzzles() -> Vec<i32> {
+    crate::puzzles()
+        .into_iter()
+        .flat_map(|(day, part)| [day, part])
+        .collect()
+}
+
+/// Returns the example input of a day, or `undefined` if the day is not implemented.
+#[wasm_bindgen]
+pub fn example(day: i32) -> Option<String> {
+    crate::registry::example(day).map(str::to_string)
+}
+
+/// Returns the input of a puzzle compiled in with the `embed-inputs` feature,
+/// or `undefined` if there is none, see [`crate::embedded::input`].
+#[wasm_bindgen]
+pub fn embedded_input(day: i32, part: i32) -> Option<String> {
+    crate::embedded::input(day, part).map(|(_, input)| input.to_string())
+}
@@ -0,0 +1,113 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Returns the most recent modification time of the given files and directories.
+///
+/// Directories are searched recursively. Paths that do not exist or cannot be
+/// read are skipped, so a file that is deleted and recreated while editing is
+/// picked up again once it reappears.
+///
+/// # Parameters
+/// - `paths`: The files and directories to inspect.
+///
+/// # Returns
+/// The latest modification time, or `None` if none of the paths could be read.
+pub fn latest_modification(paths: &[PathBuf]) -> Option<SystemTime> {
+    paths
+        .iter()
+        .filter_map(|path| modified_recursive(path))
+        .max()
+}
+
+/// Returns the latest modification time of a file or of any file below a directory.
+fn modified_recursive(path: &Path) -> Option<SystemTime> {
+    let metadata = fs::metadata(path).ok()?;
+    if !metadata.is_dir() {
+        return metadata.modified().ok();
+    }
+
+    // The directory's own timestamp changes when files are added or removed.
+    let children = fs::read_dir(path)
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| modified_recursive(&entry.path()));
+    children.chain(metadata.modified().ok()).max()
+}
+
+/// Calls `on_change` once immediately and then every time one of the watched
+/// paths is modified.
+///
+/// The paths are polled every `interval`. This function only returns once
+/// `on_change` returns `false`.
+///
+/// # Parameters
+/// - `paths`: The files and directories to watch.
+/// - `interval`: How long to sleep between two checks.
+/// - `on_change`: Callback executed on every change. Returning `false` stops watching.
+pub fn watch<F>(paths: &[PathBuf], interval: Duration, mut on_change: F)
+where
+    F: FnMut() -> bool,
+{
+    let mut last_seen = latest_modification(paths);
+    if !on_change() {
+        return;
+    }
+
+    loop {
+        thread::sleep(interval);
+        let current = latest_modification(paths);
+        if current != last_seen {
+            last_seen = current;
+            if !on_change() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("aoc2025-watch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_latest_modification_missing() {
+        let result = latest_modification(&[PathBuf::from("does/not/exist.txt")]);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn test_latest_modification_directory() {
+        let dir = temp_dir("directory");
+        fs::create_dir_all(dir.join("nested")).unwrap();
+        fs::write(dir.join("nested/file.txt"), "a").unwrap();
+
+        let file_time = fs::metadata(dir.join("nested/file.txt"))
+            .unwrap()
+            .modified()
+            .unwrap();
+        let result = latest_modification(std::slice::from_ref(&dir)).unwrap();
+        assert!(result >= file_time);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_watch_stops_when_callback_returns_false() {
+        let mut calls = 0;
+        watch(&[], Duration::ZERO, || {
+            calls += 1;
+            false
+        });
+        assert_eq!(calls, 1);
+    }
+}